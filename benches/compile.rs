@@ -0,0 +1,103 @@
+//! Compares evaluating a rule with [`Expr::eval`] (walking the tree, looking variables up by name)
+//! against [`CompiledExpr::eval`](bool_ext::expr::CompiledExpr::eval) (running the compiled
+//! program, reading inputs by slot) and
+//! [`CompiledExpr::eval_bitsliced`](bool_ext::expr::CompiledExpr::eval_bitsliced) (evaluating 64
+//! rows per word over packed columns).
+//!
+//! Run with `cargo bench --bench compile`.
+
+use bool_ext::{expr::Expr, BoolVec};
+use std::{collections::HashMap, hint::black_box, time::Instant};
+
+/// A rule of typical size and shape.
+const RULE: &str = "(premium && !suspended && (verified || trial)) || admin \
+                    || (staff && (region.eu -> gdpr_consent) && !(beta ^ legacy))";
+/// Number of evaluations timed per evaluator.
+const ITERATIONS: u32 = 1_000_000;
+/// Number of distinct inputs cycled through.
+const INPUTS: u32 = 1_024;
+
+fn main() {
+    let rule = RULE.parse::<Expr>().expect("benchmark rule should parse");
+    let compiled = rule.compile();
+    let width = compiled.variables().len();
+    let inputs = (0..INPUTS)
+        .map(|iteration| {
+            (0..width)
+                .map(|slot| iteration.rotate_left(u32::try_from(slot).unwrap_or(0)) % 3 == 0)
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let envs = inputs
+        .iter()
+        .map(|inputs| {
+            compiled
+                .variables()
+                .iter()
+                .map(String::as_str)
+                .zip(inputs.iter().copied())
+                .collect::<HashMap<_, _>>()
+        })
+        .collect::<Vec<_>>();
+
+    let tree = measure("Expr::eval (HashMap)", |index| {
+        rule.eval(envs.get(index).expect("index is in range"))
+    });
+    let slots = measure("Expr::eval (slot closure)", |index| {
+        let inputs = inputs.get(index).expect("index is in range");
+        rule.eval(&|name: &str| {
+            compiled
+                .slot(name)
+                .and_then(|slot| inputs.get(slot).copied())
+        })
+    });
+    let flat = measure("CompiledExpr::eval", |index| {
+        compiled.eval(inputs.get(index).expect("index is in range").as_slice())
+    });
+    println!(
+        "speedup: {:.1}x over HashMap, {:.1}x over slot closure",
+        tree / flat,
+        slots / flat
+    );
+
+    let columns = (0..width)
+        .map(|slot| {
+            (0..ITERATIONS)
+                .map(|iteration| {
+                    let index = usize::try_from(iteration % INPUTS).unwrap_or(0);
+                    inputs
+                        .get(index)
+                        .and_then(|inputs| inputs.get(slot))
+                        .copied()
+                        .unwrap_or_default()
+                })
+                .collect::<BoolVec>()
+        })
+        .collect::<Vec<_>>();
+    let start = Instant::now();
+    let mask = black_box(compiled.eval_bitsliced(black_box(&columns)))
+        .expect("every variable has a column");
+    let sliced = start.elapsed().as_secs_f64() * 1e9 / f64::from(ITERATIONS);
+    println!(
+        "{:<28} {sliced:>8.2} ns/row ({} true)",
+        "CompiledExpr::eval_bitsliced",
+        mask.count_ones()
+    );
+    println!("speedup: {:.1}x over CompiledExpr::eval", flat / sliced);
+}
+
+/// Runs `eval` over the inputs `ITERATIONS` times, reports the mean time per evaluation and
+/// returns it (in nanoseconds).
+fn measure<E, F: Fn(usize) -> Result<bool, E>>(name: &str, eval: F) -> f64 {
+    let start = Instant::now();
+    let mut count = 0_u32;
+    for iteration in 0..ITERATIONS {
+        let index = usize::try_from(iteration % INPUTS).unwrap_or(0);
+        count = count.wrapping_add(u32::from(
+            black_box(eval(black_box(index))).is_ok_and(|value| value),
+        ));
+    }
+    let nanos = start.elapsed().as_secs_f64() * 1e9 / f64::from(ITERATIONS);
+    println!("{name:<28} {nanos:>8.1} ns/eval ({count} true)");
+    nanos
+}
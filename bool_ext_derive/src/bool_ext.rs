@@ -0,0 +1,158 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    Data, DataEnum, DataStruct, DeriveInput, Error, Fields, Ident, LitBool, Member, PathArguments,
+    Result, Type,
+};
+
+/// Generates the conversions between `input` and `bool`, `Not`, and (through
+/// `bool_ext::__impl_bool_ext!`) the implementation of `bool_ext::BoolExt`.
+pub fn expand(input: &DeriveInput) -> Result<TokenStream> {
+    let ident = &input.ident;
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &input.generics,
+            "`BoolExt` cannot be derived for generic types",
+        ));
+    }
+    let (from_bool, to_bool) = match &input.data {
+        Data::Struct(data) => newtype(data, ident)?,
+        Data::Enum(data) => two_variants(data, ident)?,
+        Data::Union(_) => {
+            return Err(Error::new_spanned(
+                ident,
+                "`BoolExt` can only be derived for single-`bool` structs and two-variant enums",
+            ))
+        }
+    };
+    Ok(quote! {
+        #[automatically_derived]
+        impl ::core::convert::From<bool> for #ident {
+            #[inline]
+            fn from(value: bool) -> Self {
+                #from_bool
+            }
+        }
+
+        #[automatically_derived]
+        impl ::core::convert::From<#ident> for bool {
+            #[inline]
+            fn from(value: #ident) -> Self {
+                #to_bool
+            }
+        }
+
+        #[automatically_derived]
+        impl ::core::ops::Not for #ident {
+            type Output = Self;
+
+            #[inline]
+            fn not(self) -> Self {
+                ::core::convert::From::from(!::core::convert::Into::<bool>::into(self))
+            }
+        }
+
+        ::bool_ext::__impl_bool_ext!(#ident);
+    })
+}
+
+/// Generates the conversions from and to `bool` for a struct with a single (`bool`) field.
+fn newtype(data: &DataStruct, ident: &Ident) -> Result<(TokenStream, TokenStream)> {
+    let mut fields = data.fields.iter();
+    let (Some(field), None) = (fields.next(), fields.next()) else {
+        return Err(Error::new_spanned(
+            ident,
+            "`BoolExt` can only be derived for structs with a single (`bool`) field",
+        ));
+    };
+    if !is_bool(&field.ty) {
+        return Err(Error::new_spanned(
+            &field.ty,
+            "`BoolExt` can only be derived for structs whose single field is a `bool`",
+        ));
+    }
+    let member = field
+        .ident
+        .clone()
+        .map_or_else(|| Member::from(0), Member::Named);
+    Ok((quote!(Self { #member: value }), quote!(value.#member)))
+}
+
+/// Whether `ty` names `bool`, as `bool` or by a path ending in it (such as
+/// `core::primitive::bool`).  A type alias of `bool` cannot be recognised.
+fn is_bool(ty: &Type) -> bool {
+    match ty {
+        Type::Group(group) => is_bool(&group.elem),
+        Type::Paren(paren) => is_bool(&paren.elem),
+        Type::Path(path) => {
+            path.qself.is_none()
+                && path.path.segments.last().is_some_and(|segment| {
+                    segment.ident == "bool" && matches!(segment.arguments, PathArguments::None)
+                })
+        }
+        _ => false,
+    }
+}
+
+/// Generates the conversions from and to `bool` for an enum of two unit variants, one of which
+/// is marked `#[bool_ext(true)]` (or the other `#[bool_ext(false)]`).
+fn two_variants(data: &DataEnum, ident: &Ident) -> Result<(TokenStream, TokenStream)> {
+    let mut variants = data.variants.iter();
+    let (Some(first), Some(second), None) = (variants.next(), variants.next(), variants.next())
+    else {
+        return Err(Error::new_spanned(
+            ident,
+            "`BoolExt` can only be derived for enums with exactly two variants",
+        ));
+    };
+    let mut marks = [None, None];
+    for (variant, mark) in [first, second].into_iter().zip(&mut marks) {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(Error::new_spanned(
+                &variant.fields,
+                "`BoolExt` can only be derived for enums of unit variants",
+            ));
+        }
+        for attr in variant
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("bool_ext"))
+        {
+            if mark.is_some() {
+                return Err(Error::new_spanned(attr, "duplicate `#[bool_ext(...)]`"));
+            }
+            *mark = Some(attr.parse_args::<LitBool>()?);
+        }
+    }
+    let first_is_true = match marks {
+        [Some(first), Some(second)] if first.value == second.value => {
+            return Err(Error::new_spanned(
+                second,
+                "the variants must be marked with different values",
+            ))
+        }
+        [Some(mark), _] => mark.value,
+        [None, Some(mark)] => !mark.value,
+        [None, None] => {
+            return Err(Error::new_spanned(
+                ident,
+                "mark the variant meaning `true` with `#[bool_ext(true)]`",
+            ))
+        }
+    };
+    let (yes, no) = if first_is_true {
+        (&first.ident, &second.ident)
+    } else {
+        (&second.ident, &first.ident)
+    };
+    Ok((
+        quote! {
+            if value {
+                Self::#yes
+            } else {
+                Self::#no
+            }
+        },
+        quote!(::core::matches!(value, #ident::#yes)),
+    ))
+}
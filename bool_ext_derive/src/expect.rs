@@ -0,0 +1,209 @@
+use proc_macro2::{Delimiter, LineColumn, Span, TokenStream, TokenTree};
+use quote::{quote, ToTokens};
+use std::mem;
+use syn::{
+    parse::{Parse, ParseStream},
+    BinOp, Expr, ExprBinary, Ident, Result, Token, UnOp,
+};
+
+/// The arguments of `bool_expect!`: a condition, then an optional `format!`-style message.
+pub struct Expectation {
+    condition: Expr,
+    message: Option<TokenStream>,
+}
+
+impl Parse for Expectation {
+    fn parse(input: ParseStream<'_>) -> Result<Self> {
+        let condition = input.parse()?;
+        let message = if input.is_empty() {
+            None
+        } else {
+            input.parse::<Token![,]>()?;
+            let message = input.parse::<TokenStream>()?;
+            (!message.is_empty()).then_some(message)
+        };
+        Ok(Self { condition, message })
+    }
+}
+
+/// Generates the evaluation of `expectation`, recording each operand of its condition.
+pub fn expand(expectation: &Expectation) -> TokenStream {
+    // Resolved at the definition site, so that they cannot capture (or be shadowed by) the caller's
+    // names
+    let recorder = Ident::new("recorder", Span::mixed_site());
+    let value = Ident::new("value", Span::mixed_site());
+    let node = node(&expectation.condition, &recorder);
+    let message = expectation.message.as_ref().map_or_else(
+        || quote!(::core::option::Option::None),
+        |message| {
+            quote!(::core::option::Option::Some(
+                ::bool_ext::__format!(#message)
+            ))
+        },
+    );
+    quote! {
+        {
+            let mut #recorder = ::bool_ext::__Recorder::new();
+            let #value = #node;
+            if #value {
+                ::core::result::Result::Ok(())
+            } else {
+                ::core::result::Result::Err(
+                    #recorder.fail(#message, ::core::panic::Location::caller())
+                )
+            }
+        }
+    }
+}
+
+/// Generates the evaluation of the operand `expr`, recorded as a node.
+fn node(expr: &Expr, recorder: &Ident) -> TokenStream {
+    let index = Ident::new("index", Span::mixed_site());
+    let value = Ident::new("value", Span::mixed_site());
+    let source = source(expr);
+    let evaluation = decompose(expr, recorder);
+    quote! {
+        {
+            let #index = #recorder.enter(#source);
+            let #value = #evaluation;
+            #recorder.exit(#index, #value)
+        }
+    }
+}
+
+/// Generates the evaluation of `expr`, recording the operands of its top-level `&&` and `||`
+/// operators, and of those of parenthesized sub-conditions and `!` negations.
+fn decompose(expr: &Expr, recorder: &Ident) -> TokenStream {
+    match expr {
+        Expr::Binary(ExprBinary {
+            op: op @ (BinOp::And(_) | BinOp::Or(_)),
+            ..
+        }) => {
+            let mut operands = chain(expr, op)
+                .into_iter()
+                .map(|operand| node(operand, recorder));
+            let first = operands.next();
+            quote!(#first #(#op #operands)*)
+        }
+        Expr::Paren(paren) => decompose(&paren.expr, recorder),
+        Expr::Group(group) => decompose(&group.expr, recorder),
+        Expr::Unary(unary) if matches!(unary.op, UnOp::Not(_)) => {
+            let operand = node(&unary.expr, recorder);
+            quote!(!#operand)
+        }
+        _ => quote!(::core::convert::identity::<bool>(#expr)),
+    }
+}
+
+/// Returns the operands of the chain of `op` (`&&` or `||`) operators at the top of `expr`, which
+/// parses as left-associative.
+fn chain<'a>(expr: &'a Expr, op: &BinOp) -> Vec<&'a Expr> {
+    let mut operands = Vec::new();
+    let mut rest = expr;
+    while let Expr::Binary(binary) = rest {
+        if mem::discriminant(&binary.op) != mem::discriminant(op) {
+            break;
+        }
+        operands.push(&*binary.right);
+        rest = &binary.left;
+    }
+    operands.push(rest);
+    operands.reverse();
+    operands
+}
+
+/// Generates the source text of `expr`, with each run of whitespace between its tokens reduced to a
+/// single space.  Parsing loses the spacing of punctuation (so that `stringify!` would render `!c`
+/// as `! c`), which is instead recovered from the tokens' locations, where those are known.
+fn source(mut expr: &Expr) -> TokenStream {
+    // An operand passed as a macro fragment (`$e:expr`) needs no parentheses of its own
+    while let Expr::Group(group) = expr {
+        expr = &group.expr;
+    }
+    let tokens = expr.to_token_stream();
+    let located = tokens
+        .clone()
+        .into_iter()
+        .next()
+        .is_some_and(|token| token.span().start().line > 0);
+    if located {
+        let mut text = String::new();
+        write_tokens(&mut text, &mut None, tokens);
+        quote!(#text)
+    } else {
+        quote!(::core::stringify!(#expr))
+    }
+}
+
+/// Appends `tokens` to `text`, preceded by a space wherever the source had whitespace since `end`,
+/// the end of the previous token.
+fn write_tokens(text: &mut String, end: &mut Option<LineColumn>, tokens: TokenStream) {
+    for token in tokens {
+        match token {
+            // A macro fragment (`$e:expr`) is grouped invisibly, and with an unreliable span; its
+            // grouping is made visible around its own tokens
+            TokenTree::Group(group)
+                if group.delimiter() == Delimiter::None
+                    && group.stream().into_iter().nth(1).is_some() =>
+            {
+                let start = group
+                    .stream()
+                    .into_iter()
+                    .next()
+                    .map(|first| first.span().start());
+                if let Some(start) = start {
+                    write_token(text, end, "(", start, start);
+                }
+                write_tokens(text, end, group.stream());
+                if let Some(stop) = *end {
+                    write_token(text, end, ")", stop, stop);
+                }
+            }
+            TokenTree::Group(group) => {
+                // A parsed group may have lost the spans of its delimiters, but keeps its own
+                let (start, close_end) = (group.span().start(), group.span().end());
+                let open_end = LineColumn {
+                    column: start.column.saturating_add(1),
+                    ..start
+                };
+                let close_start = LineColumn {
+                    column: close_end.column.saturating_sub(1),
+                    ..close_end
+                };
+                let delimiters = match group.delimiter() {
+                    Delimiter::Parenthesis => Some(('(', ')')),
+                    Delimiter::Brace => Some(('{', '}')),
+                    Delimiter::Bracket => Some(('[', ']')),
+                    Delimiter::None => None,
+                };
+                if let Some((open, _)) = delimiters {
+                    write_token(text, end, &open.to_string(), start, open_end);
+                }
+                write_tokens(text, end, group.stream());
+                if let Some((_, close)) = delimiters {
+                    write_token(text, end, &close.to_string(), close_start, close_end);
+                }
+            }
+            token => {
+                let span = token.span();
+                write_token(text, end, &token.to_string(), span.start(), span.end());
+            }
+        }
+    }
+}
+
+/// Appends `token`, found between `start` and `stop` in the source, to `text` (see
+/// `write_tokens`).
+fn write_token(
+    text: &mut String,
+    end: &mut Option<LineColumn>,
+    token: &str,
+    start: LineColumn,
+    stop: LineColumn,
+) {
+    if end.is_some_and(|end| end != start) {
+        text.push(' ');
+    }
+    text.push_str(token);
+    *end = Some(stop);
+}
@@ -0,0 +1,212 @@
+#![warn(
+    clippy::all,
+    clippy::nursery,
+    clippy::pedantic,
+    missing_docs,
+    rust_2018_idioms
+)]
+// Safety-critical application lints
+#![deny(
+    clippy::pedantic,
+    clippy::float_cmp_const,
+    clippy::indexing_slicing,
+    clippy::arithmetic_side_effects,
+    clippy::unwrap_used
+)]
+#![allow(clippy::implicit_return, clippy::module_name_repetitions)]
+// To use the `unsafe` keyword, change to `#![allow(unsafe_code)]` (do not remove); aids auditing.
+#![forbid(unsafe_code)]
+#![forbid(bare_trait_objects)]
+//! `bool_ext_derive` provides the procedural macros of the `bool_ext` crate.  Use them through
+//! `bool_ext` (the derive macros through its `derive` feature) rather than depending on this crate
+//! directly.
+
+mod bool_ext;
+mod expect;
+mod validate;
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+/// ## Derives `bool_ext::BoolExt` (with `Not` and conversions from and to `bool`) for a
+/// single-`bool` struct or a two-variant enum
+///
+/// Domain types such as `struct IsAdmin(bool)` or `enum Visibility { Public, Private }` avoid
+/// "boolean blindness" at the cost of the `BoolExt` API; this restores it.  The derive
+/// implements `From<bool>` for the type, `From<Type>` for `bool` (and so `Into<bool>`), `Not`,
+/// and `BoolExt` (by converting to `bool`).
+///
+/// A struct must have exactly one field, a `bool`.  An enum must have exactly two unit variants,
+/// of which the one meaning `true` is marked `#[bool_ext(true)]` (or the one meaning `false` is
+/// marked `#[bool_ext(false)]`).
+/// ### Examples:
+/// ```
+/// use assert2::assert;
+/// use bool_ext::BoolExt;
+///
+/// #[derive(BoolExt, Clone, Copy, Debug, PartialEq)]
+/// struct IsAdmin(bool);
+///
+/// #[derive(BoolExt, Clone, Copy, Debug, PartialEq)]
+/// enum Visibility {
+///     #[bool_ext(true)]
+///     Public,
+///     Private,
+/// }
+///
+/// let admin = IsAdmin(true);
+/// assert!(admin.some("dashboard") == Some("dashboard"));
+/// assert!((!admin).or_ok(()).is_ok());
+///
+/// let visibility = Visibility::from(false);
+/// assert!(visibility == Visibility::Private);
+/// assert!(!visibility == Visibility::Public);
+/// assert!(visibility.map("hidden", "shown") == "hidden");
+/// assert!(bool::from(Visibility::Public));
+/// ```
+///
+/// A struct whose field is not a `bool` is rejected, the error pointing at the field's type:
+/// ```compile_fail
+/// use bool_ext::BoolExt;
+///
+/// #[derive(BoolExt)]
+/// struct Count(u8);
+/// ```
+#[proc_macro_derive(BoolExt, attributes(bool_ext))]
+pub fn derive_bool_ext(input: TokenStream) -> TokenStream {
+    bool_ext::expand(&parse_macro_input!(input as DeriveInput))
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// ## Derives `bool_ext::Validate` from `#[check(...)]` rules on a struct and its fields
+///
+/// Each rule is a Boolean condition; `validate()` checks every rule (it does not stop at the
+/// first broken one) and returns `Err(bool_ext::Failures)` listing each broken rule with its
+/// path (the field it was given on, if any) and the location of its `#[check(...)]` attribute.
+///
+/// Rules on a field:
+/// - `len_between(min, max)`: `min <= field.len() <= max` (for `String` and `str`, `len()` is in
+///   bytes).
+/// - `custom = path`: `path(&field)`, where `path` names a function returning `bool`.
+/// - `expr = "condition"`: `condition`, a Rust expression in which the names of the struct's
+///   fields refer to those fields.
+/// - `nested`: the field's own `Validate` rules, with their paths prefixed by the field's name.
+///
+/// Rules on the struct (typically relating several fields) are `custom = path` (called with
+/// `&self`) and `expr = "condition"`.
+///
+/// An attribute may give several rules, and `message = "..."` to replace the default message of
+/// each.
+/// ### Examples:
+/// ```
+/// use assert2::assert;
+/// use bool_ext::Validate;
+///
+/// #[derive(Validate)]
+/// #[check(expr = "start <= end", message = "must not end before it starts")]
+/// struct Booking {
+///     #[check(len_between(1, 64))]
+///     name: String,
+///     #[check(custom = is_slug, message = "must be lowercase letters and `-`")]
+///     slug: String,
+///     start: u32,
+///     end: u32,
+///     #[check(nested)]
+///     guests: Vec<Guest>,
+/// }
+///
+/// #[derive(Validate)]
+/// struct Guest {
+///     #[check(expr = "age >= 18")]
+///     age: u8,
+/// }
+///
+/// fn is_slug(slug: &str) -> bool {
+///     slug.chars().all(|c| c.is_ascii_lowercase() || c == '-')
+/// }
+///
+/// let booking = Booking {
+///     name: String::new(),
+///     slug: "Team-Offsite".to_string(),
+///     start: 9,
+///     end: 5,
+///     guests: vec![Guest { age: 30 }, Guest { age: 12 }],
+/// };
+/// let failures = booking.validate().unwrap_err();
+///
+/// let summary = failures.iter().map(|failure| (failure.path(), failure.message()));
+/// assert!(summary.eq([
+///     ("", "must not end before it starts"),
+///     ("name", "length must be between 1 and 64"),
+///     ("slug", "must be lowercase letters and `-`"),
+///     ("guests.1.age", "`age >= 18` was false"),
+/// ]));
+/// ```
+///
+/// In an `expr` condition, a closure parameter, `let`, `match` arm, `if let`, `while let` or `for`
+/// binding named like a field refers to the binding, not the field, while it is in scope:
+/// ```
+/// use assert2::assert;
+/// use bool_ext::Validate;
+///
+/// #[derive(Validate)]
+/// #[check(expr = "match items.first() { Some(len) => *len <= 100, None => len == 0 }")]
+/// #[check(expr = "if let Some(len) = items.last() { *len > 0 } else { len == 0 }")]
+/// #[check(expr = "{ let len = len * 10; items.iter().sum::<usize>() <= len }")]
+/// struct Batch {
+///     #[check(expr = "items.iter().all(|len| *len > 0)")]
+///     #[check(expr = "{ let mut ok = true; for len in &items { ok &= *len != len_limit; } ok }")]
+///     items: Vec<usize>,
+///     len: usize,
+///     len_limit: usize,
+/// }
+///
+/// let batch = Batch { items: vec![3, 4], len: 2, len_limit: 5 };
+/// assert!(batch.validate().is_ok());
+///
+/// let batch = Batch { items: vec![0, 5], len: 2, len_limit: 5 };
+/// let failures = batch.validate().unwrap_err();
+/// assert!(failures.iter().map(|failure| failure.path()).eq(["items", "items"]));
+/// ```
+#[proc_macro_derive(Validate, attributes(check))]
+pub fn derive_validate(input: TokenStream) -> TokenStream {
+    validate::expand(&parse_macro_input!(input as DeriveInput))
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// ## Evaluates a Boolean condition, returning `Ok(())` if it is `true`, otherwise a
+/// `bool_ext::AssertionFailure` recording the value of each evaluated operand
+///
+/// The condition is decomposed into the operands of its top-level `&&` and `||` operators (and,
+/// recursively, those of parenthesized sub-conditions and `!` negations), which are evaluated with
+/// Rust's usual short-circuiting.  The condition is parsed with Rust's precedence, so in
+/// `!a == b` the `!` applies to `a` alone and the comparison is a single operand.  An optional
+/// message, with `format!`-style arguments, may follow the condition; it is formatted only if the
+/// condition is `false`.
+///
+/// Requires `bool_ext`'s `assert` feature.
+/// ### Examples:
+/// ```
+/// use assert2::assert;
+/// use bool_ext::bool_expect;
+///
+/// let (premium, verified, suspended) = (true, false, false);
+/// let mut formatted = 0;
+///
+/// assert!(bool_expect!(premium && !suspended, "{}", { formatted += 1; "suspended" }).is_ok());
+/// assert!(formatted == 0);
+///
+/// let failure = bool_expect!(premium && verified, "cannot post").unwrap_err();
+/// assert!(failure.to_string() == "\
+/// cannot post
+/// assertion failed: premium && verified
+///   premium = true
+///   verified = false
+/// ");
+/// ```
+#[proc_macro]
+pub fn bool_expect(input: TokenStream) -> TokenStream {
+    expect::expand(&parse_macro_input!(input as expect::Expectation)).into()
+}
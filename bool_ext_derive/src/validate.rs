@@ -0,0 +1,328 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned, ToTokens};
+use syn::{
+    parenthesized, parse_quote_spanned,
+    spanned::Spanned,
+    visit_mut::{self, VisitMut},
+    Arm, Attribute, Block, Data, DeriveInput, Error, Expr, ExprClosure, ExprForLoop, ExprIf,
+    ExprLet, ExprWhile, Ident, LitStr, Member, Pat, Path, Result, Stmt, Token,
+};
+
+/// A rule given by a `#[check(...)]` attribute.
+// Only a handful exist, during expansion
+#[allow(clippy::large_enum_variant)]
+enum Rule {
+    /// `len_between(min, max)`
+    LenBetween(Expr, Expr),
+    /// `custom = path`
+    Custom(Path),
+    /// `expr = "condition"`
+    Expr(LitStr),
+    /// `nested`
+    Nested,
+}
+
+/// A rule, with the message replacing its default and the span its failures are reported at.
+struct Check {
+    rule: Rule,
+    message: Option<LitStr>,
+    span: Span,
+}
+
+impl Check {
+    /// Parses the rules of the `#[check(...)]` attributes among `attrs`, in order.
+    fn parse_all(attrs: &[Attribute]) -> Result<Vec<Self>> {
+        let mut checks = Vec::new();
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("check")) {
+            let mut rules = Vec::new();
+            let mut message = None::<LitStr>;
+            attr.parse_nested_meta(|meta| {
+                let span = meta.path.span();
+                let rule = if meta.path.is_ident("len_between") {
+                    let bounds;
+                    parenthesized!(bounds in meta.input);
+                    let min = bounds.parse()?;
+                    bounds.parse::<Token![,]>()?;
+                    let max = bounds.parse()?;
+                    Rule::LenBetween(min, max)
+                } else if meta.path.is_ident("custom") {
+                    Rule::Custom(meta.value()?.parse()?)
+                } else if meta.path.is_ident("expr") {
+                    Rule::Expr(meta.value()?.parse()?)
+                } else if meta.path.is_ident("nested") {
+                    Rule::Nested
+                } else if meta.path.is_ident("message") {
+                    if message.is_some() {
+                        return Err(meta.error("duplicate `message`"));
+                    }
+                    message = Some(meta.value()?.parse()?);
+                    return Ok(());
+                } else {
+                    return Err(meta.error(
+                        "unknown rule; expected `len_between`, `custom`, `expr`, `nested` or \
+                         `message`",
+                    ));
+                };
+                rules.push((rule, span));
+                Ok(())
+            })?;
+            if rules.is_empty() {
+                return Err(Error::new_spanned(attr, "expected a rule"));
+            }
+            if let (Some(message), true) = (
+                &message,
+                rules.iter().all(|(rule, _)| matches!(rule, Rule::Nested)),
+            ) {
+                return Err(Error::new_spanned(
+                    message,
+                    "`message` does not apply to `nested`",
+                ));
+            }
+            checks.extend(rules.into_iter().map(|(rule, span)| Self {
+                rule,
+                message: message.clone(),
+                span,
+            }));
+        }
+        Ok(checks)
+    }
+
+    /// Generates the statement checking the rule against `target` (`self` or one of its fields),
+    /// in a struct whose fields are named `fields`.
+    fn expand(&self, target: &TokenStream, fields: &[Ident]) -> Result<TokenStream> {
+        let (condition, default_message) = match &self.rule {
+            Rule::LenBetween(min, max) => (
+                quote!((#min..=#max).contains(&#target.len())),
+                format!(
+                    "length must be between {} and {}",
+                    min.to_token_stream(),
+                    max.to_token_stream()
+                ),
+            ),
+            Rule::Custom(path) => (
+                quote!(#path(&#target)),
+                format!(
+                    "failed `{}`",
+                    path.to_token_stream().to_string().replace(' ', "")
+                ),
+            ),
+            Rule::Expr(source) => {
+                let mut condition = source.parse::<Expr>()?;
+                FieldsToSelf::new(fields).visit_expr_mut(&mut condition);
+                (
+                    condition.into_token_stream(),
+                    format!("`{}` was false", source.value()),
+                )
+            }
+            Rule::Nested => {
+                return Ok(quote_spanned! {self.span=>
+                    ::bool_ext::Validate::validate_into(&#target, checks);
+                })
+            }
+        };
+        let message = self
+            .message
+            .as_ref()
+            .map_or_else(|| LitStr::new(&default_message, self.span), Clone::clone);
+        // Spanned so that the failure's location is that of the rule
+        Ok(quote_spanned! {self.span=>
+            checks.check(#condition, #message);
+        })
+    }
+}
+
+/// Rewrites references to the struct's fields by name (`start`) as references through `self`
+/// (`self.start`), except where a closure parameter, `let`, `match` arm, `if let`, `while let` or
+/// `for` binding of the same name is in scope.
+struct FieldsToSelf<'a> {
+    fields: &'a [Ident],
+    // Bindings in scope, innermost last
+    bindings: Vec<Ident>,
+}
+
+impl<'a> FieldsToSelf<'a> {
+    const fn new(fields: &'a [Ident]) -> Self {
+        Self {
+            fields,
+            bindings: Vec::new(),
+        }
+    }
+
+    /// Visits `scope` with the bindings of `pat` in scope.
+    fn with_bindings(&mut self, pat: &Pat, scope: impl FnOnce(&mut Self)) {
+        let len = self.bindings.len();
+        bindings(pat, &mut self.bindings);
+        scope(self);
+        self.bindings.truncate(len);
+    }
+}
+
+impl VisitMut for FieldsToSelf<'_> {
+    fn visit_expr_mut(&mut self, expr: &mut Expr) {
+        if let Expr::Path(path) = expr {
+            if let (None, Some(ident)) = (&path.qself, path.path.get_ident()) {
+                if self.fields.contains(ident) && !self.bindings.contains(ident) {
+                    let ident = ident.clone();
+                    *expr = parse_quote_spanned!(ident.span()=> self.#ident);
+                    return;
+                }
+            }
+        }
+        visit_mut::visit_expr_mut(self, expr);
+    }
+
+    fn visit_expr_closure_mut(&mut self, closure: &mut ExprClosure) {
+        let len = self.bindings.len();
+        for input in &closure.inputs {
+            bindings(input, &mut self.bindings);
+        }
+        self.visit_expr_mut(&mut closure.body);
+        self.bindings.truncate(len);
+    }
+
+    fn visit_block_mut(&mut self, block: &mut Block) {
+        // A `let` binding is in scope for the rest of its block
+        let len = self.bindings.len();
+        for stmt in &mut block.stmts {
+            if let Stmt::Local(local) = stmt {
+                if let Some(init) = &mut local.init {
+                    self.visit_expr_mut(&mut init.expr);
+                    if let Some((_, diverge)) = &mut init.diverge {
+                        self.visit_expr_mut(diverge);
+                    }
+                }
+                bindings(&local.pat, &mut self.bindings);
+            } else {
+                self.visit_stmt_mut(stmt);
+            }
+        }
+        self.bindings.truncate(len);
+    }
+
+    fn visit_arm_mut(&mut self, arm: &mut Arm) {
+        self.with_bindings(&arm.pat, |visitor| {
+            if let Some((_, guard)) = &mut arm.guard {
+                visitor.visit_expr_mut(guard);
+            }
+            visitor.visit_expr_mut(&mut arm.body);
+        });
+    }
+
+    fn visit_expr_let_mut(&mut self, expr: &mut ExprLet) {
+        // Its bindings are in scope for the rest of the enclosing `if` or `while`, which removes
+        // them
+        self.visit_expr_mut(&mut expr.expr);
+        bindings(&expr.pat, &mut self.bindings);
+    }
+
+    fn visit_expr_if_mut(&mut self, expr: &mut ExprIf) {
+        let len = self.bindings.len();
+        self.visit_expr_mut(&mut expr.cond);
+        self.visit_block_mut(&mut expr.then_branch);
+        self.bindings.truncate(len);
+        if let Some((_, else_branch)) = &mut expr.else_branch {
+            self.visit_expr_mut(else_branch);
+        }
+    }
+
+    fn visit_expr_while_mut(&mut self, expr: &mut ExprWhile) {
+        let len = self.bindings.len();
+        self.visit_expr_mut(&mut expr.cond);
+        self.visit_block_mut(&mut expr.body);
+        self.bindings.truncate(len);
+    }
+
+    fn visit_expr_for_loop_mut(&mut self, expr: &mut ExprForLoop) {
+        self.visit_expr_mut(&mut expr.expr);
+        self.with_bindings(&expr.pat, |visitor| visitor.visit_block_mut(&mut expr.body));
+    }
+}
+
+/// Appends the names bound by `pat` to `names`.
+fn bindings(pat: &Pat, names: &mut Vec<Ident>) {
+    match pat {
+        Pat::Ident(pat) => {
+            names.push(pat.ident.clone());
+            if let Some((_, subpat)) = &pat.subpat {
+                bindings(subpat, names);
+            }
+        }
+        Pat::Or(pat) => pat.cases.iter().for_each(|case| bindings(case, names)),
+        Pat::Paren(pat) => bindings(&pat.pat, names),
+        Pat::Reference(pat) => bindings(&pat.pat, names),
+        Pat::Slice(pat) => pat.elems.iter().for_each(|elem| bindings(elem, names)),
+        Pat::Struct(pat) => pat
+            .fields
+            .iter()
+            .for_each(|field| bindings(&field.pat, names)),
+        Pat::Tuple(pat) => pat.elems.iter().for_each(|elem| bindings(elem, names)),
+        Pat::TupleStruct(pat) => pat.elems.iter().for_each(|elem| bindings(elem, names)),
+        Pat::Type(pat) => bindings(&pat.pat, names),
+        _ => (),
+    }
+}
+
+/// Generates the implementation of `bool_ext::Validate` for `input`.
+pub fn expand(input: &DeriveInput) -> Result<TokenStream> {
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "`Validate` can only be derived for structs",
+        ));
+    };
+    let fields = data
+        .fields
+        .iter()
+        .filter_map(|field| field.ident.clone())
+        .collect::<Vec<_>>();
+
+    let mut body = TokenStream::new();
+    for check in Check::parse_all(&input.attrs)? {
+        if matches!(check.rule, Rule::Nested) {
+            return Err(Error::new(check.span, "`nested` applies only to fields"));
+        }
+        body.extend(check.expand(&quote!(self), &fields)?);
+    }
+    for (index, field) in data.fields.iter().enumerate() {
+        let checks = Check::parse_all(&field.attrs)?;
+        if checks.is_empty() {
+            continue;
+        }
+        let member = field
+            .ident
+            .clone()
+            .map_or_else(|| Member::from(index), Member::Named);
+        let name = match &member {
+            Member::Named(ident) => ident.to_string().trim_start_matches("r#").to_string(),
+            Member::Unnamed(index) => index.index.to_string(),
+        };
+        let target = quote!(self.#member);
+        let checks = checks
+            .iter()
+            .map(|check| check.expand(&target, &fields))
+            .collect::<Result<Vec<_>>>()?;
+        body.extend(quote! {
+            {
+                let mut scope = checks.scope(#name);
+                let checks: &mut ::bool_ext::Checks = &mut scope;
+                #(#checks)*
+            }
+        });
+    }
+
+    let checks = if body.is_empty() {
+        quote!(_checks)
+    } else {
+        quote!(checks)
+    };
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics ::bool_ext::Validate for #ident #ty_generics #where_clause {
+            fn validate_into(&self, #checks: &mut ::bool_ext::Checks) {
+                #body
+            }
+        }
+    })
+}
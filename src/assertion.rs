@@ -0,0 +1,283 @@
+#![expect(
+    clippy::arbitrary_source_item_ordering,
+    reason = "fields and accessors follow the report's layout, and helpers sit beside their callers"
+)]
+
+use alloc::{string::String, vec::Vec};
+use core::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    panic::Location,
+};
+
+/// One operand of an assertion decomposed by
+#[cfg_attr(
+    not(feature = "no-panic"),
+    doc = "[`bool_assert!`](crate::bool_assert) or"
+)]
+#[cfg_attr(feature = "no-panic", doc = "`bool_assert!` or")]
+/// [`bool_expect!`](crate::bool_expect).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Operand {
+    /// The operand's source text.
+    source: &'static str,
+    /// The operand's value.
+    value: bool,
+    /// The operand's nesting depth.
+    depth: usize,
+}
+
+impl Operand {
+    /// Source text of the operand.
+    ///
+    /// Where the compiler reports the layout of the source, each run of whitespace is reduced to a
+    /// single space.  Otherwise, the text is as rendered by `stringify!`, which may wrap it over
+    /// several lines; the [`AssertionFailure`] report joins them with single spaces.
+    #[must_use]
+    #[inline]
+    pub const fn source(&self) -> &'static str {
+        self.source
+    }
+
+    /// Value of the operand.
+    #[must_use]
+    #[inline]
+    pub const fn value(&self) -> bool {
+        self.value
+    }
+
+    /// Nesting depth of the operand (`0` for the whole condition, `1` for its operands, and so
+    /// on).
+    #[must_use]
+    #[inline]
+    pub const fn depth(&self) -> usize {
+        self.depth
+    }
+}
+
+/// Error produced by [`bool_expect!`](crate::bool_expect) when its condition is `false`,
+/// recording the value of each evaluated operand.
+///
+/// `Display` renders the condition, then each evaluated operand (indented by depth) with its
+/// value; operands skipped by short-circuiting are omitted.
+/// ### Examples:
+/// ```
+/// use assert2::assert;
+/// use bool_ext::bool_expect;
+///
+/// let (a, b, c) = (Vec::<i32>::new(), 2, false);
+/// let failure = bool_expect!(a.is_empty() && (b > 3 || c) && !c).unwrap_err();
+///
+/// assert!(failure.to_string() == "\
+/// assertion failed: a.is_empty() && (b > 3 || c) && !c
+///   a.is_empty() = true
+///   (b > 3 || c) = false
+///     b > 3 = false
+///     c = false
+/// ");
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct AssertionFailure {
+    /// In evaluation order; the first is the whole condition.
+    operands: Vec<Operand>,
+    /// The custom message, if any.
+    message: Option<String>,
+    /// The location of the assertion.
+    location: &'static Location<'static>,
+}
+
+impl AssertionFailure {
+    /// ## Returns the evaluated operands, in evaluation order (the first is the whole condition)
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::bool_expect;
+    ///
+    /// let (admin, owner, banned) = (false, false, false);
+    /// let failure = bool_expect!(admin || owner).unwrap_err();
+    ///
+    /// assert!(failure.operands()[1].source() == "admin");
+    /// assert!(failure.operands().len() == 3);
+    ///
+    /// let roles = ["editor", "author", "reviewer", "moderator", "publisher", "translator"];
+    /// let failure = bool_expect!(
+    ///     !banned
+    ///         && (admin
+    ///             || owner
+    ///             || roles.iter().any(|role| role.len() > 10)
+    ///             || roles.contains(&"maintainer")
+    ///             || roles.contains(&"releaser")
+    ///             || roles.contains(&"auditor"))
+    /// )
+    /// .unwrap_err();
+    ///
+    /// assert!(failure.operands()[1].source() == "!banned");
+    /// assert!(failure.operands()[3].source() == "(admin || owner || roles.iter().any(|role| \
+    ///     role.len() > 10) || roles.contains(&\"maintainer\") || roles.contains(&\"releaser\") || \
+    ///     roles.contains(&\"auditor\"))");
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn operands(&self) -> &[Operand] {
+        &self.operands
+    }
+
+    /// ## Returns the message given to the macro, if any
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::bool_expect;
+    ///
+    /// let posts = 11;
+    /// let failure = bool_expect!(posts <= 10, "{posts} posts exceeds the limit").unwrap_err();
+    ///
+    /// assert!(failure.message() == Some("11 posts exceeds the limit"));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+
+    /// Source location of the failing assertion.
+    #[must_use]
+    #[inline]
+    pub const fn location(&self) -> &'static Location<'static> {
+        self.location
+    }
+}
+
+impl Display for AssertionFailure {
+    #[expect(clippy::question_mark_used, reason = "the report is written piecewise")]
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if let Some(message) = self.message.as_deref() {
+            writeln!(f, "{message}")?;
+        }
+        let mut operands = self.operands.iter();
+        if let Some(condition) = operands.next() {
+            f.write_str("assertion failed: ")?;
+            write_source(f, condition.source)?;
+            writeln!(f)?;
+        }
+        for operand in operands {
+            for _ in 0..operand.depth {
+                f.write_str("  ")?;
+            }
+            write_source(f, operand.source)?;
+            writeln!(f, " = {}", operand.value)?;
+        }
+        Ok(())
+    }
+}
+
+/// Writes `source` on a single line (`stringify!`, used where the layout of the source is unknown,
+/// wraps long conditions).
+#[expect(
+    clippy::question_mark_used,
+    reason = "the source is written line by line"
+)]
+fn write_source(f: &mut Formatter<'_>, source: &str) -> FmtResult {
+    for (index, line) in source.lines().enumerate() {
+        if index > 0 {
+            f.write_str(" ")?;
+        }
+        f.write_str(line.trim())?;
+    }
+    Ok(())
+}
+
+#[expect(
+    clippy::missing_trait_methods,
+    reason = "an assertion failure has no underlying cause"
+)]
+impl Error for AssertionFailure {}
+
+/// Records the operands of an assertion as they are evaluated.  Used by the expansions of
+/// [`bool_assert!`](crate::bool_assert) and [`bool_expect!`](crate::bool_expect); not public API.
+#[doc(hidden)]
+#[derive(Debug, Default)]
+pub struct Recorder {
+    operands: Vec<Operand>,
+    depth: usize,
+}
+
+impl Recorder {
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            operands: Vec::new(),
+            depth: 0,
+        }
+    }
+
+    /// Starts evaluating the operand `source`, returning its index.
+    #[inline]
+    pub fn enter(&mut self, source: &'static str) -> usize {
+        self.operands.push(Operand {
+            source,
+            value: false,
+            depth: self.depth,
+        });
+        self.depth = self.depth.saturating_add(1);
+        self.operands.len().saturating_sub(1)
+    }
+
+    /// Finishes evaluating the operand at `index`, which took `value`, and returns `value`.
+    #[inline]
+    pub fn exit(&mut self, index: usize, value: bool) -> bool {
+        if let Some(operand) = self.operands.get_mut(index) {
+            operand.value = value;
+        }
+        self.depth = self.depth.saturating_sub(1);
+        value
+    }
+
+    /// Describes the failure of the condition recorded, with `message`, at `location`.  Called
+    /// only once the condition is known to be `false`, so that the message is formatted only then.
+    #[must_use]
+    #[inline]
+    pub fn fail(
+        self,
+        message: Option<String>,
+        location: &'static Location<'static>,
+    ) -> AssertionFailure {
+        AssertionFailure {
+            operands: self.operands,
+            message,
+            location,
+        }
+    }
+}
+
+/// ## Panics if a Boolean condition is `false`, reporting the value of each evaluated operand
+///
+/// The condition is decomposed and evaluated as by [`bool_expect!`](crate::bool_expect), whose
+/// [`AssertionFailure`] is the panic message.  An optional message, with `format!`-style
+/// arguments, may follow the condition.  Requires the `assert` feature.
+/// ### Examples:
+/// ```
+/// use assert2::assert;
+/// use bool_ext::bool_assert;
+///
+/// let (a, b, c) = (Vec::<i32>::new(), 5, false);
+/// bool_assert!(a.is_empty() && b > 3 && !c);
+///
+/// let res = std::panic::catch_unwind(|| bool_assert!(a.is_empty() && b > 5 && !c));
+/// let message = res.unwrap_err().downcast::<String>().unwrap();
+/// assert!(*message == "\
+/// assertion failed: a.is_empty() && b > 5 && !c
+///   a.is_empty() = true
+///   b > 5 = false
+/// ");
+/// ```
+#[cfg(not(feature = "no-panic"))]
+#[macro_export]
+macro_rules! bool_assert {
+    ($($tokens:tt)+) => {
+        if let ::core::result::Result::Err(failure) = $crate::bool_expect!($($tokens)+) {
+            ::core::panic!("{}", failure);
+        }
+    };
+}
@@ -0,0 +1,177 @@
+use core::future::Future;
+
+/// `AsyncBoolExt` trait defines asynchronous counterparts of the side-effecting [`BoolExt`]
+/// combinators, for use when the side-effect must be `.await`ed.
+///
+/// Each method takes an `FnOnce() -> impl Future` and returns an unboxed `Future`; the closure is
+/// only invoked (and its `Future` only polled) if the corresponding branch is taken.  No executor
+/// is required or assumed (the examples drive their futures with `pollster::block_on`).
+///
+/// [`BoolExt`]: crate::BoolExt
+#[expect(
+    clippy::arbitrary_source_item_ordering,
+    reason = "the combinators are paired as in `BoolExt`"
+)]
+pub trait AsyncBoolExt {
+    /// ## Perform asynchronous side-effect if `true`, otherwise do nothing
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::AsyncBoolExt;
+    /// use pollster::block_on;
+    ///
+    /// let mut vec = vec![1, 2, 3];
+    /// let found = block_on(vec.contains(&2).and_do_async(|| async {
+    ///     vec.iter_mut().for_each(|el| *el = -*el);
+    /// }));
+    ///
+    /// assert!(found);
+    /// assert!(vec.eq(&[-1, -2, -3]));
+    /// ```
+    fn and_do_async<F: FnOnce() -> Fut, Fut: Future<Output = ()>>(
+        self,
+        t: F,
+    ) -> impl Future<Output = bool>;
+
+    /// ## Perform asynchronous side-effect if `false`, otherwise do nothing
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::AsyncBoolExt;
+    /// use pollster::block_on;
+    ///
+    /// let mut vec = vec![1, 2, 3];
+    /// let found = block_on(vec.contains(&4).or_do_async(|| async { vec.push(4) }));
+    ///
+    /// assert!(!found);
+    /// assert!(vec.eq(&[1, 2, 3, 4]));
+    /// ```
+    fn or_do_async<F: FnOnce() -> Fut, Fut: Future<Output = ()>>(
+        self,
+        f: F,
+    ) -> impl Future<Output = bool>;
+
+    /// ## Perform fallible asynchronous side-effect if `true`, otherwise do nothing
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::AsyncBoolExt;
+    /// use pollster::block_on;
+    ///
+    /// #[derive(Clone, Debug, PartialEq)]
+    /// struct SomeError;
+    ///
+    /// let vec = vec![1, 2, 3];
+    /// let res = block_on(vec.contains(&2).and_try_do_async(|| async { Err(SomeError) }));
+    ///
+    /// assert!(res == Err(SomeError));
+    /// ```
+    fn and_try_do_async<F: FnOnce() -> Fut, Fut: Future<Output = Result<(), E>>, E>(
+        self,
+        t: F,
+    ) -> impl Future<Output = Result<bool, E>>;
+
+    /// ## Perform fallible asynchronous side-effect if `false`, otherwise do nothing
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::AsyncBoolExt;
+    /// use pollster::block_on;
+    ///
+    /// #[derive(Clone, Debug, PartialEq)]
+    /// enum SomeError {}
+    ///
+    /// let mut vec = vec![1, 2, 3];
+    /// let res = block_on(vec.contains(&4).or_try_do_async(|| async {
+    ///     vec.push(4);
+    ///     Ok::<_, SomeError>(())
+    /// }));
+    ///
+    /// assert!(res == Ok(false));
+    /// assert!(vec.eq(&[1, 2, 3, 4]));
+    /// ```
+    fn or_try_do_async<F: FnOnce() -> Fut, Fut: Future<Output = Result<(), E>>, E>(
+        self,
+        f: F,
+    ) -> impl Future<Output = Result<bool, E>>;
+
+    /// ## Transforms `true` => `T`, `false` => `T`, asynchronously evaluated
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::AsyncBoolExt;
+    /// use pollster::block_on;
+    ///
+    /// async fn fetch_cached() -> &'static str { "cached" }
+    /// async fn fetch_remote() -> &'static str { "remote" }
+    ///
+    /// let cache = vec![1, 2, 3];
+    ///
+    /// let hit = cache.contains(&2).map_or_else_async(fetch_remote, fetch_cached);
+    /// let miss = cache.contains(&4).map_or_else_async(fetch_remote, fetch_cached);
+    ///
+    /// assert!(block_on(hit) == "cached");
+    /// assert!(block_on(miss) == "remote");
+    /// ```
+    fn map_or_else_async<F, G, FutT, FutF, T>(self, f: G, t: F) -> impl Future<Output = T>
+    where
+        F: FnOnce() -> FutT,
+        G: FnOnce() -> FutF,
+        FutT: Future<Output = T>,
+        FutF: Future<Output = T>;
+}
+
+// `use_self` would have the `bool` return types spelled `Self`: through the `async fn` desugaring it
+// misses that the signatures are the trait's.
+#[expect(
+    clippy::arbitrary_source_item_ordering,
+    clippy::use_self,
+    reason = "ordered as in the trait; see above"
+)]
+impl AsyncBoolExt for bool {
+    #[inline]
+    async fn and_do_async<F: FnOnce() -> Fut, Fut: Future<Output = ()>>(self, t: F) -> bool {
+        if self {
+            t().await;
+        }
+        self
+    }
+
+    #[inline]
+    async fn or_do_async<F: FnOnce() -> Fut, Fut: Future<Output = ()>>(self, f: F) -> bool {
+        !(!self).and_do_async(f).await
+    }
+
+    #[inline]
+    async fn and_try_do_async<F: FnOnce() -> Fut, Fut: Future<Output = Result<(), E>>, E>(
+        self,
+        t: F,
+    ) -> Result<bool, E> {
+        match self {
+            true => t().await.map(|()| self),
+            false => Ok(self),
+        }
+    }
+
+    #[inline]
+    async fn or_try_do_async<F: FnOnce() -> Fut, Fut: Future<Output = Result<(), E>>, E>(
+        self,
+        f: F,
+    ) -> Result<bool, E> {
+        (!self).and_try_do_async(f).await.map(|_| self)
+    }
+
+    #[inline]
+    async fn map_or_else_async<F, G, FutT, FutF, T>(self, f: G, t: F) -> T
+    where
+        F: FnOnce() -> FutT,
+        G: FnOnce() -> FutF,
+        FutT: Future<Output = T>,
+        FutF: Future<Output = T>,
+    {
+        match self {
+            true => t().await,
+            false => f().await,
+        }
+    }
+}
@@ -0,0 +1,749 @@
+//! Reduced ordered binary decision diagrams.
+//!
+//! A [`Manager`] owns a forest of shared BDD nodes over an ordered set of named variables; a
+//! [`Bdd`] is a handle to one node of that forest.  As every Boolean function has exactly one
+//! reduced ordered BDD for a given variable order, two handles from the same manager are equal if
+//! and only if they represent equivalent functions, and tautology and satisfiability checks take
+//! constant time.  Unlike a [`TruthTable`](crate::expr::TruthTable), a BDD's size is not
+//! exponential in the number of variables for most practical rules.
+//! ### Examples:
+//! ```
+//! use assert2::assert;
+//! use bool_ext::{bdd::Manager, expr::Expr};
+//!
+//! let old_rule = "!(beta && internal) && (beta || internal)".parse::<Expr>().unwrap();
+//! let new_rule = "beta ^ internal".parse::<Expr>().unwrap();
+//!
+//! let mut manager = Manager::new();
+//! let old_rule = manager.from_expr(&old_rule);
+//! let new_rule = manager.from_expr(&new_rule);
+//!
+//! assert!(old_rule == new_rule);
+//! ```
+#![expect(
+    clippy::arbitrary_source_item_ordering,
+    reason = "the operators follow those of `Expr`, and the manager's methods run from construction through the operations to the queries, each helper after its callers"
+)]
+
+use crate::expr::Expr;
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    string::String,
+    vec::Vec,
+};
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+/// Binary operator applied to two BDDs by [`Manager::apply`].
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[expect(
+    clippy::exhaustive_enums,
+    reason = "the operators are the standard connectives, matched exhaustively when applied"
+)]
+pub enum Op {
+    /// Logical conjunction.
+    And,
+    /// Logical (inclusive) disjunction.
+    Or,
+    /// Exclusive disjunction.
+    Xor,
+    /// Material implication.
+    Implies,
+    /// Logical equivalence.
+    Iff,
+    /// Negated conjunction.
+    Nand,
+    /// Negated disjunction.
+    Nor,
+}
+
+impl Op {
+    /// ## Applies the operator to two `bool`s
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::bdd::Op;
+    ///
+    /// assert!(Op::Implies.eval(false, false));
+    /// assert!(!Op::Nor.eval(false, true));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn eval(self, lhs: bool, rhs: bool) -> bool {
+        match self {
+            Self::And => lhs && rhs,
+            Self::Or => lhs || rhs,
+            Self::Xor => lhs ^ rhs,
+            Self::Implies => !lhs || rhs,
+            Self::Iff => lhs == rhs,
+            Self::Nand => !(lhs && rhs),
+            Self::Nor => !(lhs || rhs),
+        }
+    }
+}
+
+/// Handle to a node of a [`Manager`]'s BDD forest.
+///
+/// Each handle records the manager which created it (handles from different managers are never
+/// equal, except for [`Bdd::FALSE`] and [`Bdd::TRUE`]).
+/// ### Panics
+/// Each method of [`Manager`] taking a `Bdd` panics if the `Bdd` is from another manager
+/// (including one cloned from, or into, this manager).
+/// ### Examples:
+/// ```should_panic
+/// use bool_ext::bdd::Manager;
+///
+/// let mut manager = Manager::new();
+/// let admin = manager.var("admin");
+///
+/// let mut other = Manager::new();
+/// other.not(admin);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Bdd {
+    /// Id of the owning manager; `0` (shared by every manager) for the terminals.
+    manager: usize,
+    /// Index of the node within the manager (or, for a terminal, its value).
+    node: usize,
+}
+
+impl Bdd {
+    /// The constant `false` function, in every manager.
+    pub const FALSE: Self = Self {
+        manager: 0,
+        node: 0,
+    };
+    /// The constant `true` function, in every manager.
+    pub const TRUE: Self = Self {
+        manager: 0,
+        node: 1,
+    };
+}
+
+/// Source of manager ids (`0` being reserved for the terminals).
+static NEXT_MANAGER: AtomicUsize = AtomicUsize::new(1);
+
+/// A decision node: `if variable { high } else { low }`.
+#[derive(Clone, Copy, Debug)]
+struct Node {
+    /// Position of the node's variable in the order; `usize::MAX` for the terminals.
+    level: usize,
+    /// The function where the variable is `false`.
+    low: Bdd,
+    /// The function where the variable is `true`.
+    high: Bdd,
+}
+
+/// Node standing for either terminal, below every variable in the order.
+const TERMINAL: Node = Node {
+    level: usize::MAX,
+    low: Bdd::FALSE,
+    high: Bdd::FALSE,
+};
+
+/// Cache key for the negation of a node, distinct from every binary operation's key.
+const NOT: Option<Op> = None;
+
+/// `Manager` owns a forest of shared reduced ordered BDD nodes.
+///
+/// Variables are ordered by first use, unless ordered in advance with
+/// [`with_order`](Self::with_order).  The order is fixed thereafter; BDD size can depend
+/// heavily on it, so related variables are best placed close together.
+///
+/// A clone is a separate manager, whose handles are distinct from the original's.
+#[derive(Debug)]
+pub struct Manager {
+    /// Id stamped on the manager's handles.
+    id: usize,
+    /// The variables, in order.
+    variables: Vec<String>,
+    /// The level of each variable.
+    levels: BTreeMap<String, usize>,
+    /// The nodes, indexed by [`Bdd::node`].
+    nodes: Vec<Node>,
+    /// The unique table, ensuring that each `(level, low, high)` node exists at most once.
+    unique: BTreeMap<(usize, Bdd, Bdd), Bdd>,
+    /// Results of earlier operations, keyed by operator ([`NOT`] for negation) and operands.
+    computed: BTreeMap<(Option<Op>, Bdd, Bdd), Bdd>,
+}
+
+impl Manager {
+    /// ## Constructs a `Manager` with no variables
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::bdd::Manager;
+    ///
+    /// assert!(Manager::new().order().is_empty());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            id: NEXT_MANAGER.fetch_add(1, Ordering::Relaxed),
+            variables: Vec::new(),
+            levels: BTreeMap::new(),
+            nodes: alloc::vec![TERMINAL, TERMINAL],
+            unique: BTreeMap::new(),
+            computed: BTreeMap::new(),
+        }
+    }
+
+    /// ## Constructs a `Manager` whose variable order begins with `variables`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::bdd::Manager;
+    ///
+    /// let mut manager = Manager::with_order(["b", "a"]);
+    /// manager.var("c");
+    ///
+    /// assert!(manager.order() == ["b", "a", "c"]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn with_order<I: IntoIterator<Item = S>, S: Into<String>>(variables: I) -> Self {
+        let mut manager = Self::new();
+        variables.into_iter().for_each(|name| {
+            manager.level(name.into());
+        });
+        manager
+    }
+
+    /// ## Returns the variable names, in order
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::bdd::Manager;
+    ///
+    /// let mut manager = Manager::new();
+    /// manager.var("b");
+    /// manager.var("a");
+    ///
+    /// assert!(manager.order() == ["b", "a"]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn order(&self) -> &[String] {
+        &self.variables
+    }
+
+    /// ## Returns the constant function `value`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::bdd::{Bdd, Manager};
+    ///
+    /// assert!(Manager::constant(true) == Bdd::TRUE);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn constant(value: bool) -> Bdd {
+        match value {
+            true => Bdd::TRUE,
+            false => Bdd::FALSE,
+        }
+    }
+
+    /// ## Returns the function which is `true` exactly when variable `name` is, appending `name`
+    /// to the variable order if it is new.
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::bdd::Manager;
+    ///
+    /// let mut manager = Manager::new();
+    /// let admin = manager.var("admin");
+    ///
+    /// assert!(manager.is_satisfiable(admin));
+    /// assert!(!manager.is_tautology(admin));
+    /// ```
+    #[inline]
+    pub fn var(&mut self, name: &str) -> Bdd {
+        let level = self.level(String::from(name));
+        self.node(level, Bdd::FALSE, Bdd::TRUE)
+    }
+
+    /// ## Returns the negation of `f`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::bdd::Manager;
+    ///
+    /// let mut manager = Manager::new();
+    /// let admin = manager.var("admin");
+    /// let not_admin = manager.not(admin);
+    ///
+    /// assert!(manager.not(not_admin) == admin);
+    /// ```
+    #[inline]
+    pub fn not(&mut self, f: Bdd) -> Bdd {
+        if let Some(value) = Self::terminal_value(f) {
+            return Self::constant(!value);
+        }
+        if let Some(&result) = self.computed.get(&(NOT, f, f)) {
+            return result;
+        }
+        let node = self.get(f);
+        let low = self.not(node.low);
+        let high = self.not(node.high);
+        let result = self.node(node.level, low, high);
+        self.computed.insert((NOT, f, f), result);
+        result
+    }
+
+    /// ## Returns `f op g`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::bdd::{Bdd, Manager, Op};
+    ///
+    /// let mut manager = Manager::new();
+    /// let a = manager.var("a");
+    /// let not_a = manager.not(a);
+    ///
+    /// assert!(manager.apply(Op::Or, a, not_a) == Bdd::TRUE);
+    /// assert!(manager.apply(Op::Iff, a, not_a) == Bdd::FALSE);
+    /// ```
+    #[inline]
+    pub fn apply(&mut self, op: Op, f: Bdd, g: Bdd) -> Bdd {
+        if let Some(result) = self.apply_terminal(op, f, g) {
+            return result;
+        }
+        if let Some(&result) = self.computed.get(&(Some(op), f, g)) {
+            return result;
+        }
+        let level = self.get(f).level.min(self.get(g).level);
+        let (f_low, f_high) = self.cofactors(f, level);
+        let (g_low, g_high) = self.cofactors(g, level);
+        let low = self.apply(op, f_low, g_low);
+        let high = self.apply(op, f_high, g_high);
+        let result = self.node(level, low, high);
+        self.computed.insert((Some(op), f, g), result);
+        result
+    }
+
+    /// ## Returns `f` with variable `name` fixed to `value` (the cofactor of `f`)
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::{bdd::Manager, expr::Expr};
+    ///
+    /// let mut manager = Manager::new();
+    /// let rule = manager.from_expr(&"admin || premium && verified".parse::<Expr>().unwrap());
+    /// let non_admin = manager.restrict(rule, "admin", false);
+    ///
+    /// assert!(non_admin == manager.from_expr(&"premium && verified".parse::<Expr>().unwrap()));
+    /// ```
+    #[inline]
+    pub fn restrict(&mut self, f: Bdd, name: &str, value: bool) -> Bdd {
+        self.check(f);
+        match self.levels.get(name) {
+            Some(&level) => self.restrict_level(f, level, value, &mut BTreeMap::new()),
+            None => f,
+        }
+    }
+
+    /// ## Returns `f` with variable `name` existentially quantified (`true` where `f` is `true`
+    /// for some value of `name`).
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::{bdd::{Bdd, Manager}, expr::Expr};
+    ///
+    /// let mut manager = Manager::new();
+    /// let rule = manager.from_expr(&"admin && !suspended || !admin && premium".parse::<Expr>().unwrap());
+    /// let reachable = manager.exists(rule, "admin");
+    ///
+    /// assert!(reachable == manager.from_expr(&"!suspended || premium".parse::<Expr>().unwrap()));
+    /// ```
+    #[inline]
+    pub fn exists(&mut self, f: Bdd, name: &str) -> Bdd {
+        let low = self.restrict(f, name, false);
+        let high = self.restrict(f, name, true);
+        self.apply(Op::Or, low, high)
+    }
+
+    /// ## Returns `f` with variable `name` universally quantified (`true` where `f` is `true`
+    /// for both values of `name`).
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::{bdd::{Bdd, Manager}, expr::Expr};
+    ///
+    /// let mut manager = Manager::new();
+    /// let rule = manager.from_expr(&"admin || premium".parse::<Expr>().unwrap());
+    /// let regardless = manager.forall(rule, "admin");
+    ///
+    /// assert!(regardless == manager.var("premium"));
+    /// ```
+    #[inline]
+    pub fn forall(&mut self, f: Bdd, name: &str) -> Bdd {
+        let low = self.restrict(f, name, false);
+        let high = self.restrict(f, name, true);
+        self.apply(Op::And, low, high)
+    }
+
+    /// ## Returns the BDD of `expr`, appending any new variables to the variable order in order
+    /// of first appearance.
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::{bdd::Manager, expr::Expr};
+    ///
+    /// let mut manager = Manager::new();
+    /// let lhs = manager.from_expr(&"a -> b".parse::<Expr>().unwrap());
+    /// let rhs = manager.from_expr(&"!b -> !a".parse::<Expr>().unwrap());
+    ///
+    /// assert!(lhs == rhs);
+    /// ```
+    #[expect(
+        clippy::pattern_type_mismatch,
+        reason = "`ref_patterns` rejects the `ref` bindings of the alternative"
+    )]
+    #[inline]
+    pub fn from_expr(&mut self, expr: &Expr) -> Bdd {
+        let (op, lhs, rhs) = match expr {
+            Expr::Var(name) => return self.var(name),
+            Expr::Const(value) => return Self::constant(*value),
+            Expr::Not(operand) => {
+                let f = self.from_expr(operand);
+                return self.not(f);
+            }
+            Expr::And(lhs, rhs) => (Op::And, lhs, rhs),
+            Expr::Or(lhs, rhs) => (Op::Or, lhs, rhs),
+            Expr::Xor(lhs, rhs) => (Op::Xor, lhs, rhs),
+            Expr::Implies(lhs, rhs) => (Op::Implies, lhs, rhs),
+            Expr::Iff(lhs, rhs) => (Op::Iff, lhs, rhs),
+        };
+        let f = self.from_expr(lhs);
+        let g = self.from_expr(rhs);
+        self.apply(op, f, g)
+    }
+
+    /// ## Transforms `f` is `true` under every assignment => `true`, otherwise `false`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::{bdd::Manager, expr::Expr};
+    ///
+    /// let mut manager = Manager::new();
+    /// let rule = manager.from_expr(&"(a -> b) || (b -> a)".parse::<Expr>().unwrap());
+    ///
+    /// assert!(manager.is_tautology(rule));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn is_tautology(&self, f: Bdd) -> bool {
+        self.check(f) == Bdd::TRUE
+    }
+
+    /// ## Transforms `f` is `true` under some assignment => `true`, otherwise `false`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::{bdd::Manager, expr::Expr};
+    ///
+    /// let mut manager = Manager::new();
+    /// let rule = manager.from_expr(&"beta && !beta".parse::<Expr>().unwrap());
+    ///
+    /// assert!(!manager.is_satisfiable(rule));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn is_satisfiable(&self, f: Bdd) -> bool {
+        self.check(f) != Bdd::FALSE
+    }
+
+    /// ## Returns the number of assignments of all of the manager's variables under which `f` is
+    /// `true` (saturating at `u128::MAX`).
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::{bdd::Manager, expr::Expr};
+    ///
+    /// let mut manager = Manager::new();
+    /// let rule = manager.from_expr(&"a && (b || c)".parse::<Expr>().unwrap());
+    ///
+    /// assert!(manager.sat_count(rule) == 3);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn sat_count(&self, f: Bdd) -> u128 {
+        let count = self.sat_count_below(f, &mut BTreeMap::new());
+        count.saturating_mul(pow2(self.level_of(f)))
+    }
+
+    /// ## Returns an assignment of all of the manager's variables under which `f` is `true`, or
+    /// `None` if `f` is unsatisfiable.
+    ///
+    /// Variables on which the result does not depend are assigned `false`.  The assignment is
+    /// itself an [`Env`](crate::expr::Env).
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::{bdd::Manager, expr::Expr};
+    ///
+    /// let rule = "admin || !suspended".parse::<Expr>().unwrap();
+    /// let mut manager = Manager::new();
+    /// let f = manager.from_expr(&rule);
+    /// manager.var("verified");
+    ///
+    /// let witness = manager.any_sat(f).unwrap();
+    /// assert!(rule.eval(&witness) == Ok(true));
+    /// assert!(witness.keys().eq(["admin", "suspended", "verified"]));
+    /// assert!(witness["verified"] == false);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn any_sat(&self, f: Bdd) -> Option<BTreeMap<String, bool>> {
+        let mut assignment = self
+            .variables
+            .iter()
+            .map(|name| (name.clone(), false))
+            .collect::<BTreeMap<_, _>>();
+        let mut g = self.check(f);
+        while Self::terminal_value(g).is_none() {
+            let node = self.get(g);
+            let value = node.low == Bdd::FALSE;
+            if let Some(name) = self.variables.get(node.level) {
+                assignment.insert(name.clone(), value);
+            }
+            g = match value {
+                true => node.high,
+                false => node.low,
+            };
+        }
+        (g == Bdd::TRUE).then_some(assignment)
+    }
+
+    /// ## Returns the number of nodes of `f`, including terminals
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::{bdd::Manager, expr::Expr};
+    ///
+    /// let mut manager = Manager::new();
+    /// let rule = manager.from_expr(&"a ^ b ^ c".parse::<Expr>().unwrap());
+    ///
+    /// assert!(manager.size(rule) == 7);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn size(&self, f: Bdd) -> usize {
+        let mut seen = BTreeSet::new();
+        let mut stack = alloc::vec![self.check(f)];
+        while let Some(g) = stack.pop() {
+            if seen.insert(g) && Self::terminal_value(g).is_none() {
+                let node = self.get(g);
+                stack.extend([node.low, node.high]);
+            }
+        }
+        seen.len()
+    }
+
+    /// Returns the level of variable `name`, appending it to the order if it is new.
+    fn level(&mut self, name: String) -> usize {
+        let next = self.variables.len();
+        *self.levels.entry(name).or_insert_with_key(|key| {
+            self.variables.push(key.clone());
+            next
+        })
+    }
+
+    /// Returns `f`, after checking that it is a terminal or one of this manager's nodes.
+    #[inline]
+    fn check(&self, f: Bdd) -> Bdd {
+        self.get(f);
+        f
+    }
+
+    /// Returns the node of `f`.
+    ///
+    /// ### Panics
+    /// Panics if `f` belongs to another manager.
+    #[expect(
+        clippy::panic,
+        reason = "mixing managers is a logic error, like indexing out of bounds"
+    )]
+    #[inline]
+    fn get(&self, f: Bdd) -> Node {
+        match (f.manager, self.nodes.get(f.node)) {
+            (0, _) => TERMINAL,
+            (manager, Some(&node)) if manager == self.id => node,
+            _ => panic!("`Bdd` from another `Manager`"),
+        }
+    }
+
+    /// Returns the value of `f` if it is a terminal, otherwise `None`.
+    #[inline]
+    const fn terminal_value(f: Bdd) -> Option<bool> {
+        match f {
+            Bdd::FALSE => Some(false),
+            Bdd::TRUE => Some(true),
+            Bdd { .. } => None,
+        }
+    }
+
+    /// Level of `f`'s variable, or the number of variables if `f` is a terminal.
+    fn level_of(&self, f: Bdd) -> usize {
+        self.get(f).level.min(self.variables.len())
+    }
+
+    /// Returns the node `(level, low, high)`, reusing an existing node if possible, and
+    /// eliminating it if `low == high`.
+    fn node(&mut self, level: usize, low: Bdd, high: Bdd) -> Bdd {
+        if low == high {
+            return low;
+        }
+        let next = Bdd {
+            manager: self.id,
+            node: self.nodes.len(),
+        };
+        let nodes = &mut self.nodes;
+        *self.unique.entry((level, low, high)).or_insert_with(|| {
+            nodes.push(Node { level, low, high });
+            next
+        })
+    }
+
+    /// Returns the cofactors of `f` with respect to the variable at `level`, which must not be
+    /// below `f`'s own variable.
+    fn cofactors(&self, f: Bdd, level: usize) -> (Bdd, Bdd) {
+        let node = self.get(f);
+        match node.level == level {
+            true => (node.low, node.high),
+            false => (f, f),
+        }
+    }
+
+    /// Returns `f op g` if it is decided by a terminal operand.
+    fn apply_terminal(&mut self, op: Op, f: Bdd, g: Bdd) -> Option<Bdd> {
+        match (Self::terminal_value(f), Self::terminal_value(g)) {
+            (Some(lhs), Some(rhs)) => Some(Self::constant(op.eval(lhs, rhs))),
+            (Some(lhs), None) => {
+                Some(self.apply_partial(op.eval(lhs, false), op.eval(lhs, true), g))
+            }
+            (None, Some(rhs)) => {
+                Some(self.apply_partial(op.eval(false, rhs), op.eval(true, rhs), f))
+            }
+            (None, None) => None,
+        }
+    }
+
+    /// Returns the function which is `if_false` where `f` is `false` and `if_true` where `f` is
+    /// `true`.
+    fn apply_partial(&mut self, if_false: bool, if_true: bool, f: Bdd) -> Bdd {
+        match (if_false, if_true) {
+            (false, true) => f,
+            (true, false) => self.not(f),
+            (value, _) => Self::constant(value),
+        }
+    }
+
+    /// Returns `f` with the variable at `level` fixed to `value`, memoized in `computed` (per call, since it depends on `level` and `value`).
+    fn restrict_level(
+        &mut self,
+        f: Bdd,
+        level: usize,
+        value: bool,
+        computed: &mut BTreeMap<Bdd, Bdd>,
+    ) -> Bdd {
+        let node = self.get(f);
+        if node.level > level {
+            return f;
+        }
+        if node.level == level {
+            return match value {
+                true => node.high,
+                false => node.low,
+            };
+        }
+        if let Some(&result) = computed.get(&f) {
+            return result;
+        }
+        let low = self.restrict_level(node.low, level, value, computed);
+        let high = self.restrict_level(node.high, level, value, computed);
+        let result = self.node(node.level, low, high);
+        computed.insert(f, result);
+        result
+    }
+
+    /// Number of satisfying assignments of the variables at or below `f`'s level.
+    fn sat_count_below(&self, f: Bdd, computed: &mut BTreeMap<Bdd, u128>) -> u128 {
+        if let Some(value) = Self::terminal_value(f) {
+            return u128::from(value);
+        }
+        if let Some(&count) = computed.get(&f) {
+            return count;
+        }
+        let node = self.get(f);
+        let level = self.level_of(f);
+        let count = [node.low, node.high]
+            .into_iter()
+            .map(|child| {
+                let skipped = self.level_of(child).saturating_sub(level).saturating_sub(1);
+                self.sat_count_below(child, computed)
+                    .saturating_mul(pow2(skipped))
+            })
+            .fold(u128::MIN, u128::saturating_add);
+        computed.insert(f, count);
+        count
+    }
+}
+
+/// The clone is given a new id, so its handles (including those of nodes it shares with the
+/// original) are distinct from the original's.
+#[expect(
+    clippy::missing_trait_methods,
+    reason = "the provided `clone_from` gives the clone a new id, as `clone` does"
+)]
+impl Clone for Manager {
+    #[inline]
+    fn clone(&self) -> Self {
+        let id = NEXT_MANAGER.fetch_add(1, Ordering::Relaxed);
+        let rehome = |f: Bdd| match f.manager {
+            0 => f,
+            _ => Bdd { manager: id, ..f },
+        };
+        Self {
+            id,
+            variables: self.variables.clone(),
+            levels: self.levels.clone(),
+            nodes: self
+                .nodes
+                .iter()
+                .map(|node| Node {
+                    low: rehome(node.low),
+                    high: rehome(node.high),
+                    ..*node
+                })
+                .collect(),
+            unique: self
+                .unique
+                .iter()
+                .map(|(&(level, low, high), &f)| ((level, rehome(low), rehome(high)), rehome(f)))
+                .collect(),
+            computed: self
+                .computed
+                .iter()
+                .map(|(&(op, f, g), &result)| ((op, rehome(f), rehome(g)), rehome(result)))
+                .collect(),
+        }
+    }
+}
+
+impl Default for Manager {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns `2^exponent`, saturating at `u128::MAX`.
+#[inline]
+fn pow2(exponent: usize) -> u128 {
+    u32::try_from(exponent)
+        .ok()
+        .and_then(|bits| u128::checked_shl(1, bits))
+        .unwrap_or(u128::MAX)
+}
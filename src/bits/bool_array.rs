@@ -0,0 +1,283 @@
+use super::{impl_bitset_common, impl_bitset_ops, locate, set, tail_mask, words_for};
+use crate::BoolExt as _;
+use core::ops::Not;
+
+/// `BoolArray` is a fixed-size, stack-allocated, packed array of `BITS` `bool`s, stored in
+/// `WORDS` 64-bit words.
+///
+/// `WORDS` must be `BITS.div_ceil(64)` (checked at compile time).  A single parameter, as in
+/// `BoolArray<N>`, would need the unstable `generic_const_exprs` feature to derive the length of
+/// the word array; [`bool_array!`](crate::bool_array) names the type from `BITS` alone.
+/// ### Examples:
+/// ```
+/// use assert2::assert;
+/// use bool_ext::{BoolArray, OptionBoolExt};
+///
+/// let mut flags = BoolArray::<10, 1>::new();
+/// assert!(flags.try_set(3, true).is_some());
+///
+/// assert!(flags.len() == 10);
+/// assert!(flags.get(3).and_some("set") == Some("set"));
+/// assert!((!flags).count_ones() == 9);
+/// ```
+///
+/// A mismatched `WORDS` is rejected at compile time:
+/// ```compile_fail
+/// let flags = bool_ext::BoolArray::<65, 1>::new();
+/// ```
+///
+/// [`bool_array!`](crate::bool_array) computes `WORDS`:
+/// ```
+/// use assert2::assert;
+/// use bool_ext::{bool_array, BoolArray};
+///
+/// let flags: bool_array!(65) = BoolArray::new();
+/// assert!(flags.words().len() == 2);
+/// ```
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+pub struct BoolArray<const BITS: usize, const WORDS: usize> {
+    /// The flags, least significant bit first; bits at or beyond `BITS` are always `0`.
+    words: [u64; WORDS],
+}
+
+impl<const BITS: usize, const WORDS: usize> BoolArray<BITS, WORDS> {
+    /// Number of flags held by a `BoolArray<BITS, WORDS>`.
+    pub const LEN: usize = BITS;
+
+    /// Evaluated by every constructor, so that a mismatched `WORDS` fails to compile.
+    const WORDS_FIT_BITS: () = assert!(
+        WORDS == words_for(BITS),
+        "`BoolArray<BITS, WORDS>` requires `WORDS == BITS.div_ceil(64)`"
+    );
+
+    /// ## Constructs a `BoolArray` with all flags `false`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::BoolArray;
+    ///
+    /// assert!(BoolArray::<100, 2>::new().none());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        let () = Self::WORDS_FIT_BITS;
+        Self { words: [0; WORDS] }
+    }
+
+    /// ## Constructs a `BoolArray` from its packed representation (bit `i % 64` of word `i / 64`
+    /// holds flag `i`).
+    ///
+    /// Bits at or beyond `BITS` are discarded.
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::BoolArray;
+    ///
+    /// let flags = BoolArray::<10, 1>::from_words([0b1_0000_1010 | 1 << 10]);
+    ///
+    /// assert!(flags.true_indices().eq([1, 3, 8]));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn from_words(words: [u64; WORDS]) -> Self {
+        let () = Self::WORDS_FIT_BITS;
+        let mut array = Self { words };
+        array.mask_tail();
+        array
+    }
+
+    /// ## Returns the packed representation (bit `i % 64` of word `i / 64` holds flag `i`)
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::BoolArray;
+    ///
+    /// let mut flags = BoolArray::<64, 1>::new();
+    /// assert!(flags.try_set(1, true).is_some());
+    ///
+    /// assert!(flags.words() == &[0b10]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn words(&self) -> &[u64] {
+        &self.words
+    }
+
+    /// ## Returns the number of flags (always [`Self::LEN`])
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::BoolArray;
+    ///
+    /// assert!(BoolArray::<100, 2>::new().len() == 100);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn len(&self) -> usize {
+        Self::LEN
+    }
+
+    /// ## Transforms zero-length `BoolArray` => `true`, otherwise `false`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::BoolArray;
+    ///
+    /// assert!(BoolArray::<0, 0>::new().is_empty());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        BITS == 0
+    }
+
+    /// ## Sets the flag at `index` to `value`
+    /// ### Panics
+    /// Panics if `index` is out of bounds.  Not available with the `no-panic` feature; use
+    /// [`try_set`](Self::try_set) instead.
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::BoolArray;
+    ///
+    /// let mut flags = BoolArray::<70, 2>::new();
+    /// flags.set(69, true);
+    ///
+    /// assert!(flags[69]);
+    /// ```
+    #[cfg(not(feature = "no-panic"))]
+    #[expect(
+        clippy::panic,
+        reason = "mirrors slice indexing; `try_set` is the non-panicking form"
+    )]
+    #[inline]
+    pub fn set(&mut self, index: usize, value: bool) {
+        self.try_set(index, value).unwrap_or_else(|| {
+            panic!(
+                "index out of bounds: the len is {} but the index is {}",
+                Self::LEN,
+                index
+            )
+        });
+    }
+
+    /// ## Sets the flag at `index` to `value`, returning `None` (and leaving the flags unchanged)
+    /// if `index` is out of bounds.
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::BoolArray;
+    ///
+    /// let mut flags = BoolArray::<70, 2>::new();
+    ///
+    /// assert!(flags.try_set(69, true) == Some(()));
+    /// assert!(flags.try_set(70, true) == None);
+    /// assert!(flags.get(69) == Some(true));
+    /// ```
+    #[inline]
+    pub fn try_set(&mut self, index: usize, value: bool) -> Option<()> {
+        set(&mut self.words, Self::LEN, index, value)
+    }
+
+    /// ## Sets all flags to `value`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::BoolArray;
+    ///
+    /// let mut flags = BoolArray::<10, 1>::new();
+    /// flags.fill(true);
+    ///
+    /// assert!(flags.all());
+    /// assert!(flags.words() == &[0b11_1111_1111]);
+    /// ```
+    #[inline]
+    pub fn fill(&mut self, value: bool) {
+        self.words = [value.map(0, u64::MAX); WORDS];
+        self.mask_tail();
+    }
+
+    /// Clears the bits of the final word at or beyond `BITS`, which must always be `0`.
+    #[inline]
+    const fn mask_tail(&mut self) {
+        if let Some(last) = self.words.last_mut() {
+            *last &= tail_mask(BITS);
+        }
+    }
+
+    /// Applies `op` to each word and the matching word of `rhs`.
+    #[inline]
+    fn zip_words_mut(&mut self, rhs: &Self, op: impl Fn(&mut u64, u64)) {
+        self.words
+            .iter_mut()
+            .zip(rhs.words)
+            .for_each(|(word, other)| op(word, other));
+    }
+}
+
+impl_bitset_common!(
+    BoolArray<const BITS: usize, const WORDS: usize>,
+    "use bool_ext::BoolArray;\n\nlet flags = BoolArray::<10, 1>::from_words([0b1010]);"
+);
+
+impl_bitset_ops!(BoolArray<const BITS: usize, const WORDS: usize>);
+
+impl<const BITS: usize, const WORDS: usize> Default for BoolArray<BITS, WORDS> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const BITS: usize, const WORDS: usize> Not for BoolArray<BITS, WORDS> {
+    type Output = Self;
+
+    #[inline]
+    fn not(mut self) -> Self {
+        self.words.iter_mut().for_each(|word| *word = !*word);
+        self.mask_tail();
+        self
+    }
+}
+
+impl<const BITS: usize, const WORDS: usize> FromIterator<bool> for BoolArray<BITS, WORDS> {
+    /// Collects up to [`Self::LEN`] flags; any further items are ignored and any missing flags
+    /// are `false`.
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = bool>>(iter: I) -> Self {
+        let mut array = Self::new();
+        iter.into_iter()
+            .take(Self::LEN)
+            .enumerate()
+            .filter(|&(_, value)| value)
+            .for_each(|(index, _)| {
+                let (slot, mask) = locate(index);
+                if let Some(word) = array.words.get_mut(slot) {
+                    *word |= mask;
+                }
+            });
+        array
+    }
+}
+
+/// ## Names the type of a [`BoolArray`] of `BITS` flags, computing its `WORDS`
+///
+/// `BITS` must be a constant expression of type `usize` which does not depend on a generic
+/// parameter.
+/// ### Examples:
+/// ```
+/// use assert2::assert;
+/// use bool_ext::{bool_array, BoolArray};
+///
+/// const PERMISSIONS: usize = 130;
+///
+/// let flags = <bool_array!(PERMISSIONS)>::new();
+/// assert!(flags == BoolArray::<130, 3>::new());
+/// ```
+#[macro_export]
+macro_rules! bool_array {
+    ($bits:expr) => {
+        $crate::BoolArray<{ $bits }, { ::core::primitive::usize::div_ceil($bits, 64) }>
+    };
+}
@@ -0,0 +1,403 @@
+use super::{impl_bitset_common, impl_bitset_ops, locate, set, tail_mask, words_for, WORD_BITS};
+use crate::BoolExt as _;
+use alloc::vec::Vec;
+use core::{iter, ops::Not};
+
+/// `BoolVec` is a growable, heap-allocated, packed vector of `bool`s.
+///
+/// Word-wise operations between `BoolVec`s of differing lengths treat the shorter operand as if
+/// it were padded with `false`; the result has the length of the longer operand.
+/// ### Examples:
+/// ```
+/// use assert2::assert;
+/// use bool_ext::{BoolVec, OptionBoolExt};
+///
+/// let admins = [false, true, true].into_iter().collect::<BoolVec>();
+/// let active = [true, true, false].into_iter().collect::<BoolVec>();
+///
+/// let active_admins = admins & &active;
+/// assert!(active_admins.true_indices().eq([1]));
+/// assert!(active_admins.get(1).and_some("admin") == Some("admin"));
+/// ```
+#[derive(Clone, Default, Eq, Hash, PartialEq)]
+pub struct BoolVec {
+    /// The flags, least significant bit first.  Invariant: bits at or beyond `len` are always
+    /// `0`.
+    words: Vec<u64>,
+    /// The number of flags.
+    len: usize,
+}
+
+impl BoolVec {
+    /// ## Constructs an empty `BoolVec`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::BoolVec;
+    ///
+    /// assert!(BoolVec::new().is_empty());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            words: Vec::new(),
+            len: 0,
+        }
+    }
+
+    /// ## Constructs an empty `BoolVec` with room for at least `capacity` flags
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::BoolVec;
+    ///
+    /// assert!(BoolVec::with_capacity(100).capacity() >= 100);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            words: Vec::with_capacity(words_for(capacity)),
+            len: 0,
+        }
+    }
+
+    /// ## Constructs a `BoolVec` of `len` copies of `value`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::BoolVec;
+    ///
+    /// let flags = BoolVec::repeat(true, 100);
+    ///
+    /// assert!(flags.len() == 100);
+    /// assert!(flags.all());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn repeat(value: bool, len: usize) -> Self {
+        let mut vec = Self {
+            words: alloc::vec![value.map(0, u64::MAX); words_for(len)],
+            len,
+        };
+        vec.mask_tail();
+        vec
+    }
+
+    /// ## Constructs a `BoolVec` of `len` flags from its packed representation (bit `i % 64` of
+    /// word `i / 64` holds flag `i`).
+    ///
+    /// Missing words are taken as `0`; surplus words, and bits at or beyond `len`, are discarded.
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::BoolVec;
+    ///
+    /// let flags = BoolVec::from_words(vec![0b1010, u64::MAX], 66);
+    ///
+    /// assert!(flags.len() == 66);
+    /// assert!(flags.true_indices().eq([1, 3, 64, 65]));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn from_words(mut words: Vec<u64>, len: usize) -> Self {
+        words.resize(words_for(len), 0);
+        let mut vec = Self { words, len };
+        vec.mask_tail();
+        vec
+    }
+
+    /// ## Returns the packed representation (bit `i % 64` of word `i / 64` holds flag `i`)
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::BoolVec;
+    ///
+    /// let flags = [false, true].into_iter().collect::<BoolVec>();
+    ///
+    /// assert!(flags.words() == &[0b10]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn words(&self) -> &[u64] {
+        self.words.as_slice()
+    }
+
+    /// ## Returns the number of flags
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::BoolVec;
+    ///
+    /// assert!(BoolVec::repeat(false, 3).len() == 3);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// ## Transforms zero-length `BoolVec` => `true`, otherwise `false`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::BoolVec;
+    ///
+    /// assert!(BoolVec::new().is_empty());
+    /// assert!(!BoolVec::repeat(false, 1).is_empty());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// ## Returns the number of flags the `BoolVec` can hold without reallocating
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::BoolVec;
+    ///
+    /// assert!(BoolVec::new().capacity() == 0);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        self.words.capacity().saturating_mul(WORD_BITS)
+    }
+
+    /// ## Appends `value`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::BoolVec;
+    ///
+    /// let mut flags = BoolVec::new();
+    /// flags.push(true);
+    ///
+    /// assert!(flags.get(0) == Some(true));
+    /// ```
+    #[inline]
+    pub fn push(&mut self, value: bool) {
+        let (slot, mask) = locate(self.len);
+        if slot == self.words.len() {
+            self.words.push(0);
+        }
+        if let Some(word) = self.words.get_mut(slot) {
+            *word |= value.map(0, mask);
+        }
+        // Every 64 flags hold a word, so memory is exhausted long before `len` could overflow
+        self.len = self.len.saturating_add(1);
+    }
+
+    /// ## Removes and returns the last value, or `None` if empty
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::BoolVec;
+    ///
+    /// let mut flags = [false, true].into_iter().collect::<BoolVec>();
+    ///
+    /// assert!(flags.pop() == Some(true));
+    /// assert!(flags.pop() == Some(false));
+    /// assert!(flags.pop() == None);
+    /// ```
+    #[inline]
+    pub fn pop(&mut self) -> Option<bool> {
+        // `usize::MAX` if empty, which `get` and `truncate` both ignore
+        let last = self.len.wrapping_sub(1);
+        let value = self.get(last);
+        self.truncate(last);
+        value
+    }
+
+    /// ## Shortens the `BoolVec` to `len` flags; has no effect if already shorter
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::BoolVec;
+    ///
+    /// let mut flags = BoolVec::repeat(true, 100);
+    /// flags.truncate(10);
+    ///
+    /// assert!(flags.len() == 10);
+    /// assert!(flags.count_ones() == 10);
+    /// ```
+    #[inline]
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.len {
+            self.words.truncate(words_for(len));
+            self.len = len;
+            self.mask_tail();
+        }
+    }
+
+    /// ## Removes all flags
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::BoolVec;
+    ///
+    /// let mut flags = BoolVec::repeat(true, 100);
+    /// flags.clear();
+    ///
+    /// assert!(flags.is_empty());
+    /// ```
+    #[inline]
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// ## Sets the flag at `index` to `value`
+    /// ### Panics
+    /// Panics if `index` is out of bounds.  Not available with the `no-panic` feature; use
+    /// [`try_set`](Self::try_set) instead.
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::BoolVec;
+    ///
+    /// let mut flags = BoolVec::repeat(false, 3);
+    /// flags.set(2, true);
+    ///
+    /// assert!(flags[2]);
+    /// ```
+    #[cfg(not(feature = "no-panic"))]
+    #[expect(
+        clippy::panic,
+        reason = "mirrors slice indexing; `try_set` is the non-panicking form"
+    )]
+    #[inline]
+    pub fn set(&mut self, index: usize, value: bool) {
+        self.try_set(index, value).unwrap_or_else(|| {
+            panic!(
+                "index out of bounds: the len is {} but the index is {}",
+                self.len, index
+            )
+        });
+    }
+
+    /// ## Sets the flag at `index` to `value`, returning `None` (and leaving the flags unchanged)
+    /// if `index` is out of bounds.
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::BoolVec;
+    ///
+    /// let mut flags = BoolVec::repeat(false, 3);
+    ///
+    /// assert!(flags.try_set(2, true) == Some(()));
+    /// assert!(flags.try_set(3, true) == None);
+    /// assert!(flags.get(2) == Some(true));
+    /// ```
+    #[inline]
+    pub fn try_set(&mut self, index: usize, value: bool) -> Option<()> {
+        set(&mut self.words, self.len, index, value)
+    }
+
+    /// ## Sets all flags to `value`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::BoolVec;
+    ///
+    /// let mut flags = BoolVec::repeat(false, 3);
+    /// flags.fill(true);
+    ///
+    /// assert!(flags.all());
+    /// ```
+    #[inline]
+    pub fn fill(&mut self, value: bool) {
+        self.words.fill(value.map(0, u64::MAX));
+        self.mask_tail();
+    }
+
+    /// Restores the invariant that bits at or beyond `len` are `0`.
+    #[inline]
+    fn mask_tail(&mut self) {
+        if let Some(last) = self.words.last_mut() {
+            *last &= tail_mask(self.len);
+        }
+    }
+
+    /// Applies `op` to each word and the matching word of `rhs` (`0` past its end), first
+    /// extending `self` to the length of `rhs`.
+    #[inline]
+    fn zip_words_mut(&mut self, rhs: &Self, op: impl Fn(&mut u64, u64)) {
+        if self.words.len() < rhs.words.len() {
+            self.words.resize(rhs.words.len(), 0);
+        }
+        self.len = self.len.max(rhs.len);
+        self.words
+            .iter_mut()
+            .zip(rhs.words.iter().copied().chain(iter::repeat(0)))
+            .for_each(|(word, other)| op(word, other));
+    }
+}
+
+impl_bitset_common!(
+    BoolVec,
+    "use bool_ext::BoolVec;\n\nlet flags = [false, true, false, true, false].into_iter().collect::<BoolVec>();"
+);
+
+impl_bitset_ops!(BoolVec);
+
+impl Not for BoolVec {
+    type Output = Self;
+
+    #[inline]
+    fn not(mut self) -> Self {
+        self.words.iter_mut().for_each(|word| *word = !*word);
+        self.mask_tail();
+        self
+    }
+}
+
+impl Not for &BoolVec {
+    type Output = BoolVec;
+
+    #[inline]
+    fn not(self) -> BoolVec {
+        !self.clone()
+    }
+}
+
+#[expect(
+    clippy::missing_trait_methods,
+    reason = "`extend_one` and `extend_reserve` are unstable"
+)]
+impl Extend<bool> for BoolVec {
+    #[inline]
+    fn extend<I: IntoIterator<Item = bool>>(&mut self, iter: I) {
+        let values = iter.into_iter();
+        self.words.reserve(words_for(values.size_hint().0));
+        values.for_each(|value| self.push(value));
+    }
+}
+
+impl FromIterator<bool> for BoolVec {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = bool>>(iter: I) -> Self {
+        let mut vec = Self::new();
+        vec.extend(iter);
+        vec
+    }
+}
+
+impl From<&[bool]> for BoolVec {
+    #[inline]
+    fn from(values: &[bool]) -> Self {
+        values.iter().copied().collect()
+    }
+}
+
+impl<const BITS: usize, const WORDS: usize> From<super::BoolArray<BITS, WORDS>> for BoolVec {
+    #[inline]
+    fn from(array: super::BoolArray<BITS, WORDS>) -> Self {
+        Self {
+            words: array.words().to_vec(),
+            len: array.len(),
+        }
+    }
+}
@@ -0,0 +1,454 @@
+//! Packed collections of `bool`s, stored one bit per flag.
+//!
+//! [`BoolArray`] is a fixed-size, stack-allocated bitset; [`BoolVec`] (requires the `alloc`
+//! feature) is its growable, heap-allocated counterpart.  Both support word-wise `&`, `|`, `^`
+//! and `!`, and `get` returns a single element as an `Option<bool>`, to which every
+//! [`OptionBoolExt`](crate::OptionBoolExt) combinator applies.  Unless the `no-panic` feature is
+//! enabled, they also index to a plain `bool`, and `set` complements `try_set` (both panicking
+//! if out of bounds).
+
+#![expect(
+    clippy::arbitrary_source_item_ordering,
+    reason = "the word helpers come first, then the iterators and macros built on them; fields and \
+              methods are in logical order"
+)]
+#![expect(
+    clippy::mod_module_files,
+    reason = "`self_named_module_files` rejects the alternative, `bits.rs`"
+)]
+#![expect(
+    clippy::pub_use,
+    reason = "each bitset has a private module of its own"
+)]
+
+/// The fixed-size bitset.
+mod bool_array;
+/// The growable bitset.
+#[cfg(feature = "alloc")]
+mod bool_vec;
+
+pub use bool_array::BoolArray;
+#[cfg(feature = "alloc")]
+pub use bool_vec::BoolVec;
+
+use core::{iter::FusedIterator, slice};
+
+/// Number of bits in each word.
+const WORD_BITS: usize = 64;
+
+/// Shift dividing a bit index by [`WORD_BITS`], giving the index of its word.
+const WORD_SHIFT: u32 = WORD_BITS.trailing_zeros();
+
+/// Mask giving the remainder of a bit index by [`WORD_BITS`], its offset within its word.
+const OFFSET_MASK: usize = !(usize::MAX << WORD_SHIFT);
+
+/// Returns the index of the word holding bit `index`, and the mask selecting it within that word.
+#[inline]
+const fn locate(index: usize) -> (usize, u64) {
+    (index >> WORD_SHIFT, 1 << (index & OFFSET_MASK))
+}
+
+/// Converts a number of bits within a word (at most [`WORD_BITS`]) to `usize`.
+#[inline]
+fn bit_offset(bits: u32) -> usize {
+    usize::try_from(bits).unwrap_or(WORD_BITS)
+}
+
+/// Number of words needed to hold `len` bits.
+#[inline]
+const fn words_for(len: usize) -> usize {
+    len.div_ceil(WORD_BITS)
+}
+
+/// Mask selecting the bits of the final word which are in use, given `len` bits in total.
+#[inline]
+const fn tail_mask(len: usize) -> u64 {
+    match len & OFFSET_MASK {
+        0 => u64::MAX,
+        used => !(u64::MAX << used),
+    }
+}
+
+/// Returns bit `index` of the first `len` bits of `words`, or `None` if out of bounds.
+#[inline]
+fn get(words: &[u64], len: usize, index: usize) -> Option<bool> {
+    let (slot, mask) = locate(index);
+    (index < len)
+        .then(|| words.get(slot))
+        .flatten()
+        .map(|word| word & mask != 0)
+}
+
+/// Sets bit `index` of the first `len` bits of `words`, or returns `None` if out of bounds.
+#[inline]
+fn set(words: &mut [u64], len: usize, index: usize, value: bool) -> Option<()> {
+    let (slot, mask) = locate(index);
+    (index < len)
+        .then(|| words.get_mut(slot))
+        .flatten()
+        .map(|word| match value {
+            true => *word |= mask,
+            false => *word &= !mask,
+        })
+}
+
+/// Number of bits set in `words`.
+#[inline]
+fn count_ones(words: &[u64]) -> usize {
+    words
+        .iter()
+        .map(|&word| bit_offset(word.count_ones()))
+        .sum()
+}
+
+/// Iterator over the values of a [`BoolVec`] or [`BoolArray`], in index order.
+#[derive(Clone, Debug)]
+pub struct Iter<'words> {
+    /// The words of the collection.
+    words: &'words [u64],
+    /// The index of the next value.
+    index: usize,
+    /// The number of values in the collection.
+    len: usize,
+}
+
+impl<'words> Iter<'words> {
+    /// Creates an iterator over the first `len` bits of `words`.
+    #[inline]
+    const fn new(words: &'words [u64], len: usize) -> Self {
+        Self {
+            words,
+            index: 0,
+            len,
+        }
+    }
+}
+
+#[expect(
+    clippy::missing_trait_methods,
+    reason = "the provided methods are built on `next` and `size_hint`"
+)]
+impl Iterator for Iter<'_> {
+    type Item = bool;
+
+    #[inline]
+    fn next(&mut self) -> Option<bool> {
+        get(self.words, self.len, self.index).inspect(|_| self.index = self.index.wrapping_add(1))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.len.saturating_sub(self.index);
+        (remaining, Some(remaining))
+    }
+}
+
+#[expect(
+    clippy::missing_trait_methods,
+    reason = "`len` is taken from `size_hint`"
+)]
+impl ExactSizeIterator for Iter<'_> {}
+
+impl FusedIterator for Iter<'_> {}
+
+/// Iterator over the indices of the `true` values of a [`BoolVec`] or [`BoolArray`], in
+/// ascending order.
+#[derive(Clone, Debug)]
+pub struct TrueIndices<'words> {
+    /// The words after the current one.
+    words: slice::Iter<'words, u64>,
+    /// The bits of the current word not yet yielded.
+    current: u64,
+    /// The index of the current word's first bit.
+    base: usize,
+}
+
+impl<'words> TrueIndices<'words> {
+    /// Creates an iterator over the set bits of `words`.
+    #[inline]
+    fn new(words: &'words [u64]) -> Self {
+        let mut iter = words.iter();
+        let current = iter.next().copied().unwrap_or_default();
+        Self {
+            words: iter,
+            current,
+            base: 0,
+        }
+    }
+}
+
+#[expect(
+    clippy::missing_trait_methods,
+    reason = "the provided methods are built on `next`"
+)]
+impl Iterator for TrueIndices<'_> {
+    type Item = usize;
+
+    #[expect(
+        clippy::question_mark_used,
+        reason = "`question_mark` rejects the `match` alternative"
+    )]
+    #[inline]
+    fn next(&mut self) -> Option<usize> {
+        while self.current == 0 {
+            self.current = *self.words.next()?;
+            self.base = self.base.wrapping_add(WORD_BITS);
+        }
+        let offset = bit_offset(self.current.trailing_zeros());
+        // Clear the lowest set bit
+        self.current &= self.current.wrapping_sub(1);
+        Some(self.base.wrapping_add(offset))
+    }
+}
+
+impl FusedIterator for TrueIndices<'_> {}
+
+/// Implements the read-only bitset API shared by [`BoolArray`] and [`BoolVec`] in terms of the
+/// type's `words()` and `len()`.
+macro_rules! impl_bitset_common {
+    ($ty:ident$(<$(const $n:ident: usize),+>)?, $example:literal) => {
+        impl$(<$(const $n: usize),+>)? $ty$(<$($n),+>)? {
+            /// ## Returns the value at `index`, or `None` if `index` is out of bounds
+            /// ### Examples:
+            /// ```
+            /// use assert2::assert;
+            #[doc = $example]
+            ///
+            /// assert!(flags.get(1) == Some(true));
+            /// assert!(flags.get(1_000) == None);
+            /// ```
+            #[must_use]
+            #[inline]
+            pub fn get(&self, index: usize) -> Option<bool> {
+                super::get(self.words(), self.len(), index)
+            }
+
+            /// ## Returns the number of `true` values
+            /// ### Examples:
+            /// ```
+            /// use assert2::assert;
+            #[doc = $example]
+            ///
+            /// assert!(flags.count_ones() == 2);
+            /// ```
+            #[must_use]
+            #[inline]
+            pub fn count_ones(&self) -> usize {
+                super::count_ones(self.words())
+            }
+
+            /// ## Returns the number of `false` values
+            /// ### Examples:
+            /// ```
+            /// use assert2::assert;
+            #[doc = $example]
+            ///
+            /// assert!(flags.count_zeros() == flags.len() - 2);
+            /// ```
+            #[must_use]
+            #[inline]
+            pub fn count_zeros(&self) -> usize {
+                self.len().saturating_sub(self.count_ones())
+            }
+
+            /// ## Returns the index of the first `true` value, if any
+            /// ### Examples:
+            /// ```
+            /// use assert2::assert;
+            #[doc = $example]
+            ///
+            /// assert!(flags.first_true() == Some(1));
+            /// ```
+            #[must_use]
+            #[inline]
+            pub fn first_true(&self) -> Option<usize> {
+                self.true_indices().next()
+            }
+
+            /// ## Returns an iterator over all values, in index order
+            /// ### Examples:
+            /// ```
+            /// use assert2::assert;
+            #[doc = $example]
+            ///
+            /// assert!(flags.iter().take(4).eq([false, true, false, true]));
+            /// ```
+            #[inline]
+            pub const fn iter(&self) -> super::Iter<'_> {
+                super::Iter::new(self.words(), self.len())
+            }
+
+            /// ## Returns an iterator over the indices of all `true` values, in ascending order
+            /// ### Examples:
+            /// ```
+            /// use assert2::assert;
+            #[doc = $example]
+            ///
+            /// assert!(flags.true_indices().eq([1, 3]));
+            /// ```
+            #[inline]
+            pub fn true_indices(&self) -> super::TrueIndices<'_> {
+                super::TrueIndices::new(self.words())
+            }
+
+            /// ## Transforms all values `true` => `true`, otherwise `false`
+            /// ### Examples:
+            /// ```
+            /// use assert2::assert;
+            #[doc = $example]
+            ///
+            /// assert!(!flags.all());
+            /// ```
+            #[must_use]
+            #[inline]
+            pub fn all(&self) -> bool {
+                self.count_ones() == self.len()
+            }
+
+            /// ## Transforms any value `true` => `true`, otherwise `false`
+            /// ### Examples:
+            /// ```
+            /// use assert2::assert;
+            #[doc = $example]
+            ///
+            /// assert!(flags.any());
+            /// ```
+            #[must_use]
+            #[inline]
+            pub fn any(&self) -> bool {
+                self.words().iter().any(|word| *word != 0)
+            }
+
+            /// ## Transforms no value `true` => `true`, otherwise `false`
+            /// ### Examples:
+            /// ```
+            /// use assert2::assert;
+            #[doc = $example]
+            ///
+            /// assert!(!flags.none());
+            /// ```
+            #[must_use]
+            #[inline]
+            pub fn none(&self) -> bool {
+                !self.any()
+            }
+
+            /// ## Perform side-effect if all values are `true`, otherwise do nothing
+            /// ### Examples:
+            /// ```
+            /// use assert2::assert;
+            #[doc = $example]
+            ///
+            /// let mut ready = false;
+            /// assert!(!flags.all_do(|| ready = true));
+            /// assert!(!ready);
+            /// ```
+            #[inline]
+            pub fn all_do<F: FnOnce()>(&self, t: F) -> bool {
+                self.all().and_do(t)
+            }
+
+            /// ## Perform side-effect if any value is `true`, otherwise do nothing
+            /// ### Examples:
+            /// ```
+            /// use assert2::assert;
+            #[doc = $example]
+            ///
+            /// let mut pending = false;
+            /// assert!(flags.any_do(|| pending = true));
+            /// assert!(pending);
+            /// ```
+            #[inline]
+            pub fn any_do<F: FnOnce()>(&self, t: F) -> bool {
+                self.any().and_do(t)
+            }
+        }
+
+        /// Not available with the `no-panic` feature, as it panics if `index` is out of bounds; use
+        /// `get` instead.
+        #[cfg(not(feature = "no-panic"))]
+        impl$(<$(const $n: usize),+>)? core::ops::Index<usize> for $ty$(<$($n),+>)? {
+            type Output = bool;
+
+            #[inline]
+            fn index(&self, index: usize) -> &bool {
+                match self.get(index) {
+                    Some(true) => &true,
+                    Some(false) => &false,
+                    None => panic!(
+                        "index out of bounds: the len is {} but the index is {}",
+                        self.len(),
+                        index
+                    ),
+                }
+            }
+        }
+
+        impl<'bits$($(, const $n: usize)+)?> IntoIterator for &'bits $ty$(<$($n),+>)? {
+            type IntoIter = super::Iter<'bits>;
+            type Item = bool;
+
+            #[inline]
+            fn into_iter(self) -> super::Iter<'bits> {
+                self.iter()
+            }
+        }
+
+        impl$(<$(const $n: usize),+>)? core::fmt::Debug for $ty$(<$($n),+>)? {
+            #[inline]
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.debug_list().entries(self.iter()).finish()
+            }
+        }
+    };
+}
+
+use impl_bitset_common;
+
+/// Implements word-wise `&`, `|`, `^` (and their assigning forms) between two bitsets of type
+/// `$ty`, in terms of a `zip_words_mut(&mut self, &Self, impl Fn(&mut u64, u64))` method.
+macro_rules! impl_bitset_ops {
+    ($ty:ident$(<$(const $n:ident: usize),+>)?) => {
+        impl_bitset_ops!(@op $ty$(<$($n),+>)?, BitAnd, bitand, BitAndAssign, bitand_assign, &=);
+        impl_bitset_ops!(@op $ty$(<$($n),+>)?, BitOr, bitor, BitOrAssign, bitor_assign, |=);
+        impl_bitset_ops!(@op $ty$(<$($n),+>)?, BitXor, bitxor, BitXorAssign, bitxor_assign, ^=);
+    };
+    (@op $ty:ident$(<$($n:ident),+>)?, $op:ident, $op_fn:ident, $op_assign:ident,
+        $op_assign_fn:ident, $assign:tt) => {
+        impl$(<$(const $n: usize),+>)? core::ops::$op_assign<&Self> for $ty$(<$($n),+>)? {
+            #[inline]
+            fn $op_assign_fn(&mut self, rhs: &Self) {
+                self.zip_words_mut(rhs, |lhs, rhs| *lhs $assign rhs);
+            }
+        }
+
+        impl$(<$(const $n: usize),+>)? core::ops::$op_assign for $ty$(<$($n),+>)? {
+            #[inline]
+            fn $op_assign_fn(&mut self, rhs: Self) {
+                core::ops::$op_assign::$op_assign_fn(self, &rhs);
+            }
+        }
+
+        impl$(<$(const $n: usize),+>)? core::ops::$op<&Self> for $ty$(<$($n),+>)? {
+            type Output = Self;
+
+            #[inline]
+            fn $op_fn(mut self, rhs: &Self) -> Self {
+                core::ops::$op_assign::$op_assign_fn(&mut self, rhs);
+                self
+            }
+        }
+
+        impl$(<$(const $n: usize),+>)? core::ops::$op for $ty$(<$($n),+>)? {
+            type Output = Self;
+
+            #[inline]
+            fn $op_fn(mut self, rhs: Self) -> Self {
+                core::ops::$op_assign::$op_assign_fn(&mut self, &rhs);
+                self
+            }
+        }
+    };
+}
+
+use impl_bitset_ops;
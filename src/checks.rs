@@ -0,0 +1,379 @@
+#![expect(
+    clippy::arbitrary_source_item_ordering,
+    reason = "fields and accessors follow the rendered `path: message at location`, and each check \
+              method precedes its variants"
+)]
+
+use crate::BoolExt as _;
+use alloc::{
+    string::{String, ToString as _},
+    vec::{self, Vec},
+};
+use core::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    ops::{Deref, DerefMut},
+    panic::Location,
+    slice,
+};
+
+/// `Checks` accumulates the outcome of many conditions ("soft assertions"), so that validation
+/// code can report every failed condition rather than stopping at the first.
+///
+/// Conditions may be grouped into (nested) [`scope`](Self::scope)s, which name the part of the
+/// input being checked; each failure records the path of the scopes enclosing it and the source
+/// location of its check.  [`finish`](Self::finish) produces the [`Failures`], if any.
+/// ### Examples:
+/// ```
+/// use assert2::assert;
+/// use bool_ext::{Checks, Failures};
+///
+/// struct Address<'a> { street: &'a str, zip: &'a str }
+/// struct User<'a> { name: &'a str, age: u32, address: Address<'a> }
+///
+/// fn validate(user: &User<'_>) -> Result<(), Failures> {
+///     let mut checks = Checks::new();
+///     checks.check(!user.name.is_empty(), "name is required");
+///     checks.check_with(user.age >= 18, || format!("{} is under 18", user.age));
+///     {
+///         let mut address = checks.scope("address");
+///         address.check(!user.address.street.trim().is_empty(), "street is required");
+///         address.scope("zip").check(user.address.zip.len() == 5, "must be 5 digits");
+///     }
+///     checks.finish()
+/// }
+///
+/// let user = User { name: "", age: 42, address: Address { street: "Main St", zip: "123" } };
+/// let failures = validate(&user).unwrap_err();
+///
+/// let summary = failures.iter().map(|failure| (failure.path(), failure.message()));
+/// assert!(summary.eq([("", "name is required"), ("address.zip", "must be 5 digits")]));
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Checks {
+    /// Names of the enclosing scopes, innermost last.
+    path: Vec<String>,
+    /// The failed checks, in order.
+    failures: Vec<Failure>,
+}
+
+impl Checks {
+    /// ## Constructs a `Checks` with no conditions checked
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::Checks;
+    ///
+    /// assert!(Checks::new().finish().is_ok());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            path: Vec::new(),
+            failures: Vec::new(),
+        }
+    }
+
+    /// ## Records a failure with message `msg` if `condition` is `false`; returns `condition`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::Checks;
+    ///
+    /// let mut checks = Checks::new();
+    ///
+    /// assert!(checks.check(1 + 1 == 2, "arithmetic works"));
+    /// assert!(!checks.check(1 + 1 == 3, "1 + 1 should be 3"));
+    ///
+    /// let line = line!() - 2;
+    /// let failures = checks.finish().unwrap_err();
+    /// let failure = &failures.as_slice()[0];
+    ///
+    /// assert!(failure.message() == "1 + 1 should be 3");
+    /// assert!(failure.location().line() == line);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn check(&mut self, condition: bool, msg: &str) -> bool {
+        self.check_with(condition, || msg)
+    }
+
+    /// ## Records a failure with the message returned by `msg` if `condition` is `false` (calling
+    /// `msg` only then); returns `condition`.
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::Checks;
+    ///
+    /// let posts = 11;
+    /// let mut checks = Checks::new();
+    ///
+    /// checks.check_with(posts <= 10, || format!("{posts} posts exceeds the limit of 10"));
+    ///
+    /// let failures = checks.finish().unwrap_err();
+    /// assert!(failures.as_slice()[0].message() == "11 posts exceeds the limit of 10");
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn check_with<F: FnOnce() -> M, M: Display>(&mut self, condition: bool, msg: F) -> bool {
+        // Captured here, as `#[track_caller]` does not reach into closures
+        let location = Location::caller();
+        condition.or_do(|| {
+            self.failures.push(Failure {
+                path: self.path.join("."),
+                message: msg().to_string(),
+                location,
+            });
+        })
+    }
+
+    /// ## Opens a scope named `name`, within which failures are recorded under `name`
+    ///
+    /// The returned [`Scope`] dereferences to these `Checks`; the scope closes when it is
+    /// dropped.  Nested scopes' names are joined with `.` in [`Failure::path`].
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::Checks;
+    ///
+    /// let items = [3, 0, 7, 0];
+    /// let mut checks = Checks::new();
+    ///
+    /// let mut order = checks.scope("order");
+    /// for (index, quantity) in items.iter().enumerate() {
+    ///     let mut item = order.scope(&format!("items[{index}]"));
+    ///     item.check(*quantity > 0, "quantity must be positive");
+    /// }
+    /// drop(order);
+    ///
+    /// let failures = checks.finish().unwrap_err();
+    /// let paths = failures.iter().map(|failure| failure.path());
+    /// assert!(paths.eq(["order.items[1]", "order.items[3]"]));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn scope(&mut self, name: &str) -> Scope<'_> {
+        self.path.push(String::from(name));
+        Scope { checks: self }
+    }
+
+    /// ## Returns the failures recorded so far, in the order they were recorded
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::Checks;
+    ///
+    /// let mut checks = Checks::new();
+    /// checks.check(false, "first");
+    ///
+    /// assert!(checks.failures().len() == 1);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn failures(&self) -> &[Failure] {
+        &self.failures
+    }
+
+    /// ## Transforms no failures => `Ok(())`, otherwise `Err(Failures)` listing every failure
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::Checks;
+    ///
+    /// let mut checks = Checks::new();
+    /// checks.check(true, "passes");
+    /// assert!(checks.clone().finish().is_ok());
+    ///
+    /// checks.check(false, "fails");
+    /// assert!(checks.finish().is_err());
+    /// ```
+    #[inline]
+    pub fn finish(self) -> Result<(), Failures> {
+        let failures = self.failures;
+        failures.is_empty().or_err_with(|| Failures { failures })
+    }
+}
+
+/// `Scope` is a named scope opened by [`Checks::scope`]; it dereferences to the [`Checks`] and
+/// closes when dropped.
+#[derive(Debug)]
+pub struct Scope<'checks> {
+    /// The checks, with this scope's name pushed onto the path.
+    checks: &'checks mut Checks,
+}
+
+impl Deref for Scope<'_> {
+    type Target = Checks;
+
+    #[inline]
+    fn deref(&self) -> &Checks {
+        self.checks
+    }
+}
+
+impl DerefMut for Scope<'_> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Checks {
+        self.checks
+    }
+}
+
+impl Drop for Scope<'_> {
+    #[inline]
+    fn drop(&mut self) {
+        self.checks.path.pop();
+    }
+}
+
+/// One failed condition recorded by [`Checks`].
+///
+/// `Display` renders the path (if any), the message and the location, as in
+/// `address.zip: must be 5 digits at src/main.rs:12:9`.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Failure {
+    /// The dot-separated names of the enclosing scopes.
+    path: String,
+    /// The check's message.
+    message: String,
+    /// The location of the check.
+    location: &'static Location<'static>,
+}
+
+impl Failure {
+    /// Names of the [`Scope`]s enclosing the check, joined with `.` (empty outside any scope).
+    #[must_use]
+    #[inline]
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Message describing the failed condition.
+    #[must_use]
+    #[inline]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Source location of the failed check.
+    #[must_use]
+    #[inline]
+    pub const fn location(&self) -> &'static Location<'static> {
+        self.location
+    }
+}
+
+impl Display for Failure {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.path.is_empty() {
+            true => write!(f, "{} at {}", self.message, self.location),
+            false => write!(f, "{}: {} at {}", self.path, self.message, self.location),
+        }
+    }
+}
+
+#[expect(
+    clippy::missing_trait_methods,
+    reason = "a failed check has no underlying cause"
+)]
+impl Error for Failure {}
+
+/// Error produced by [`Checks::finish`], listing every failed condition (at least one) in the
+/// order they were recorded.
+///
+/// `Display` renders one [`Failure`] per line.
+/// ### Examples:
+/// ```
+/// use assert2::assert;
+/// use bool_ext::Checks;
+///
+/// let mut checks = Checks::new();
+/// checks.check(false, "name is required");
+/// checks.scope("address").check(false, "street is required");
+///
+/// let failures = checks.finish().unwrap_err().to_string();
+/// let mut lines = failures.lines();
+///
+/// assert!(lines.next().unwrap().starts_with("name is required at src/"));
+/// assert!(lines.next().unwrap().starts_with("address: street is required at src/"));
+/// assert!(lines.next().is_none());
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Failures {
+    /// The failed checks, in order; never empty.
+    failures: Vec<Failure>,
+}
+
+impl Failures {
+    /// ## Returns the failures, in the order they were recorded
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::Checks;
+    ///
+    /// let mut checks = Checks::new();
+    /// checks.check(false, "first");
+    /// checks.check(false, "second");
+    ///
+    /// assert!(checks.finish().unwrap_err().as_slice().len() == 2);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn as_slice(&self) -> &[Failure] {
+        &self.failures
+    }
+
+    /// ## Returns an iterator over the failures, in the order they were recorded
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::Checks;
+    ///
+    /// let mut checks = Checks::new();
+    /// checks.check(false, "first");
+    /// checks.check(false, "second");
+    ///
+    /// let failures = checks.finish().unwrap_err();
+    /// assert!(failures.iter().map(|failure| failure.message()).eq(["first", "second"]));
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> slice::Iter<'_, Failure> {
+        self.failures.iter()
+    }
+}
+
+impl Display for Failures {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        self.failures
+            .iter()
+            .try_for_each(|failure| writeln!(f, "{failure}"))
+    }
+}
+
+#[expect(
+    clippy::missing_trait_methods,
+    reason = "the failures are listed, not chained"
+)]
+impl Error for Failures {}
+
+impl IntoIterator for Failures {
+    type IntoIter = vec::IntoIter<Failure>;
+    type Item = Failure;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.failures.into_iter()
+    }
+}
+
+impl<'failures> IntoIterator for &'failures Failures {
+    type IntoIter = slice::Iter<'failures, Failure>;
+    type Item = &'failures Failure;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
@@ -0,0 +1,153 @@
+use core::{
+    error::Error,
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    panic::Location,
+};
+
+/// Defines an error type for a `bool` which was `$found` where `$expected` was expected.
+macro_rules! bool_error {
+    ($(#[$meta:meta])* $name:ident, $expected:literal, $found:literal) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy)]
+        #[non_exhaustive]
+        pub struct $name {
+            #[cfg(feature = "caller-location")]
+            location: &'static Location<'static>,
+        }
+
+        #[expect(clippy::arbitrary_source_item_ordering, reason = "the constructor comes first")]
+        impl $name {
+            /// Constructs a new error.  With the `caller-location` feature enabled, the location
+            /// of the caller is recorded.
+            #[must_use]
+            #[inline]
+            #[track_caller]
+            pub const fn new() -> Self {
+                Self {
+                    #[cfg(feature = "caller-location")]
+                    location: Location::caller(),
+                }
+            }
+
+            /// Source location at which the failing condition was converted into this error, if
+            /// the `caller-location` feature is enabled.
+            #[must_use]
+            #[inline]
+            pub const fn location(&self) -> Option<&'static Location<'static>> {
+                #[cfg(feature = "caller-location")]
+                return Some(self.location);
+                #[cfg(not(feature = "caller-location"))]
+                return None;
+            }
+        }
+
+        impl Default for $name {
+            #[inline]
+            #[track_caller]
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        // Two errors compare equal regardless of where they were raised; the location is
+        // diagnostic information only.
+        #[expect(clippy::missing_trait_methods, reason = "`ne` is the negation of `eq`")]
+        impl PartialEq for $name {
+            #[inline]
+            fn eq(&self, _other: &Self) -> bool {
+                true
+            }
+        }
+
+        #[expect(clippy::missing_trait_methods, reason = "a marker impl")]
+        impl Eq for $name {}
+
+        impl Debug for $name {
+            #[inline]
+            fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+                let mut dbg = f.debug_struct(stringify!($name));
+                if let Some(location) = self.location() {
+                    dbg.field("location", &format_args!("{}", location));
+                }
+                dbg.finish()
+            }
+        }
+
+        impl Display for $name {
+            #[expect(
+                clippy::question_mark_used,
+                reason = "`return_and_then` rejects the alternative, `and_then`"
+            )]
+            #[inline]
+            fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+                write!(f, concat!("expected `", $expected, "`, but found `", $found, "`"))?;
+                match self.location() {
+                    Some(location) => write!(f, " at {location}"),
+                    None => Ok(()),
+                }
+            }
+        }
+
+        #[expect(clippy::missing_trait_methods, reason = "the error has no underlying source")]
+        impl Error for $name {}
+
+        impl From<$name> for () {
+            #[inline]
+            fn from(_: $name) -> Self {}
+        }
+
+        #[cfg(feature = "std")]
+        impl From<$name> for std::io::Error {
+            #[inline]
+            fn from(err: $name) -> Self {
+                Self::new(std::io::ErrorKind::InvalidInput, err)
+            }
+        }
+    };
+}
+
+bool_error!(
+    /// Error produced when a condition expected to be `true` was `false` (e.g. by
+    /// [`BoolExt::to_result`](crate::BoolExt::to_result) or
+    /// [`BoolExt::and_ok`](crate::BoolExt::and_ok)).
+    ///
+    /// `FalseError` is zero-sized unless the `caller-location` feature is enabled, in which case
+    /// it records the source location of the failing condition.  It implements
+    /// [`core::error::Error`], so it composes with `?` in functions returning
+    /// `Box<dyn Error>`, `anyhow::Result`, etc.
+    /// ### Examples:
+    /// ```
+    /// use bool_ext::BoolExt;
+    ///
+    /// fn check(vec: &[i32]) -> Result<(), Box<dyn std::error::Error>> {
+    ///     vec.contains(&2).to_result()?;
+    ///     Ok(())
+    /// }
+    ///
+    /// assert!(check(&[1, 2, 3]).is_ok());
+    /// assert!(check(&[1, 3]).is_err());
+    /// ```
+    FalseError,
+    "true",
+    "false"
+);
+
+bool_error!(
+    /// Error produced when a condition expected to be `false` was `true` (e.g. by
+    /// [`BoolExt::or_ok`](crate::BoolExt::or_ok)).
+    ///
+    /// `TrueError` is zero-sized unless the `caller-location` feature is enabled, in which case
+    /// it records the source location of the failing condition.
+    /// ### Examples:
+    /// ```
+    /// use bool_ext::{BoolExt, TrueError};
+    ///
+    /// let err = vec![1, 2, 3].contains(&2).or_ok(42).unwrap_err();
+    ///
+    /// assert_eq!(err, TrueError::new());
+    /// assert!(err.to_string().starts_with("expected `false`, but found `true`"));
+    /// ```
+    TrueError,
+    "false",
+    "true"
+);
@@ -0,0 +1,429 @@
+#![expect(
+    clippy::arbitrary_source_item_ordering,
+    reason = "the program's parts are declared before the types built from them, and fields follow their meaning"
+)]
+#![expect(
+    clippy::pattern_type_mismatch,
+    reason = "the tree is matched by reference, and `ref_patterns` rejects the `ref` bindings of the alternative"
+)]
+use super::{Expr, UnboundVariable};
+use crate::bits::{BoolArray, BoolVec};
+use crate::BoolExt as _;
+use alloc::{collections::BTreeMap, string::String, vec, vec::Vec};
+use core::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+/// Label of the instruction returning `false`.
+const FALSE: usize = 0;
+/// Label of the instruction returning `true`.
+const TRUE: usize = 1;
+
+/// Input values of a [`CompiledExpr`], indexed by variable slot.
+///
+/// `Inputs` is implemented for slices, arrays and `Vec`s of `bool`, and for
+/// [`BoolArray`] and [`BoolVec`].
+pub trait Inputs {
+    /// Returns the value in `slot`, or `None` if `slot` is out of range.
+    fn input(&self, slot: usize) -> Option<bool>;
+}
+
+impl Inputs for [bool] {
+    #[inline]
+    fn input(&self, slot: usize) -> Option<bool> {
+        self.get(slot).copied()
+    }
+}
+
+impl<const N: usize> Inputs for [bool; N] {
+    #[inline]
+    fn input(&self, slot: usize) -> Option<bool> {
+        self.get(slot).copied()
+    }
+}
+
+impl Inputs for Vec<bool> {
+    #[inline]
+    fn input(&self, slot: usize) -> Option<bool> {
+        self.get(slot).copied()
+    }
+}
+
+impl<const BITS: usize, const WORDS: usize> Inputs for BoolArray<BITS, WORDS> {
+    #[inline]
+    fn input(&self, slot: usize) -> Option<bool> {
+        self.get(slot)
+    }
+}
+
+impl Inputs for BoolVec {
+    #[inline]
+    fn input(&self, slot: usize) -> Option<bool> {
+        self.get(slot)
+    }
+}
+
+/// An instruction of a compiled program.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+enum Instr {
+    /// Returns the given value.
+    Return(bool),
+    /// Continues at `then` if the input in `slot` is `true`, otherwise at `otherwise`.
+    Branch {
+        /// The index of the input to test.
+        slot: usize,
+        /// The label to continue at if the input is `true`.
+        then: usize,
+        /// The label to continue at if the input is `false`.
+        otherwise: usize,
+    },
+}
+
+/// `CompiledExpr` is an [`Expr`] compiled (by [`Expr::compile`]) to a flat program for fast,
+/// repeated evaluation.
+///
+/// Variables are resolved to slots (their indices in [`variables`](Self::variables), which are in
+/// sorted order), and evaluation reads inputs by slot rather than by name.  The program is a
+/// sequence of two-way branches on inputs, each jumping to an earlier instruction, so evaluation
+/// short-circuits, needs no stack and always terminates.
+/// ### Examples:
+/// ```
+/// use assert2::assert;
+/// use bool_ext::{expr::Expr, BoolArray};
+///
+/// let rule = "(premium && !suspended) || admin".parse::<Expr>().unwrap().compile();
+/// assert!(rule.variables() == ["admin", "premium", "suspended"]);
+///
+/// assert!(rule.eval(&[false, true, false]) == Ok(true));
+/// assert!(rule.eval(&BoolArray::<3, 1>::from_words([0b100])) == Ok(false));
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CompiledExpr {
+    /// The variables, in input order.
+    variables: Vec<String>,
+    /// The instructions, addressed by label.
+    program: Vec<Instr>,
+    /// The label of the first instruction to run.
+    entry: usize,
+}
+
+impl CompiledExpr {
+    /// ## Returns the names of the expression's variables, indexed by slot
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::expr::Expr;
+    ///
+    /// let rule = "b && a".parse::<Expr>().unwrap().compile();
+    /// assert!(rule.variables() == ["a", "b"]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn variables(&self) -> &[String] {
+        &self.variables
+    }
+
+    /// ## Returns the slot of the variable named `name`, or `None` if the expression has no such
+    /// variable.
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::expr::Expr;
+    ///
+    /// let rule = "b && a".parse::<Expr>().unwrap().compile();
+    ///
+    /// assert!(rule.slot("b") == Some(1));
+    /// assert!(rule.slot("c") == None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn slot(&self, name: &str) -> Option<usize> {
+        self.variables
+            .binary_search_by(|variable| variable.as_str().cmp(name))
+            .ok()
+    }
+
+    /// ## Evaluates the expression against `inputs`, indexed by slot
+    ///
+    /// As with [`Expr::eval`], evaluation short-circuits, so an input which does not influence the
+    /// result need not be present; otherwise, the variable of the first missing input encountered
+    /// is reported as unbound.
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::expr::Expr;
+    ///
+    /// let rule = "premium && verified".parse::<Expr>().unwrap().compile();
+    ///
+    /// assert!(rule.eval(&[false][..]) == Ok(false));
+    /// assert!(rule.eval(&[true][..]).unwrap_err().name() == "verified");
+    /// ```
+    #[inline]
+    pub fn eval<I: Inputs + ?Sized>(&self, inputs: &I) -> Result<bool, UnboundVariable> {
+        let mut pc = self.entry;
+        while let Some(&Instr::Branch {
+            slot,
+            then,
+            otherwise,
+        }) = self.program.get(pc)
+        {
+            pc = match inputs.input(slot) {
+                Some(true) => then,
+                Some(false) => otherwise,
+                None => {
+                    let name = self.variables.get(slot).map_or("", String::as_str);
+                    return Err(UnboundVariable::new(name));
+                }
+            };
+        }
+        Ok(self.program.get(pc) == Some(&Instr::Return(true)))
+    }
+
+    /// ## Evaluates the expression over every row of `columns` (one per variable, indexed by
+    /// slot), returning the mask of rows for which it is `true`.
+    ///
+    /// Rows are evaluated 64 at a time, one packed word of each column, using only word-wise
+    /// bitwise operations.  Every variable must have a column, and all columns must have the same
+    /// number of rows; the result has that many rows (or none, if `columns` is empty).
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::{expr::Expr, BoolIterExt, BoolVec};
+    ///
+    /// let rule = "(premium && !suspended) || admin".parse::<Expr>().unwrap().compile();
+    /// let admin = BoolVec::from(&[true, false, false, false][..]);
+    /// let premium = BoolVec::from(&[false, true, true, false][..]);
+    /// let suspended = BoolVec::from(&[true, true, false, false][..]);
+    ///
+    /// let mask = rule.eval_bitsliced(&[admin, premium, suspended]).unwrap();
+    ///
+    /// assert!(mask.true_indices().eq([0, 2]));
+    /// assert!(mask.iter().count_true() == 2);
+    /// ```
+    #[inline]
+    pub fn eval_bitsliced(&self, columns: &[BoolVec]) -> Result<BoolVec, ColumnError> {
+        let rows = columns.first().map_or(0, BoolVec::len);
+        for (slot, name) in self.variables.iter().enumerate() {
+            #[expect(
+                clippy::question_mark_used,
+                reason = "the first missing column is reported"
+            )]
+            let column = columns
+                .get(slot)
+                .ok_or_else(|| ColumnError::Missing { name: name.clone() })?;
+            if column.len() != rows {
+                return Err(ColumnError::Length {
+                    name: name.clone(),
+                    expected: rows,
+                    found: column.len(),
+                });
+            }
+        }
+        let word_count = columns.first().map_or(0, |column| column.words().len());
+        // Instructions after the entry are unreachable from it
+        let program = self.program.get(..=self.entry).unwrap_or_default();
+        let mut values = vec![u64::MIN; program.len()];
+        let words = (0..word_count)
+            .map(|word| {
+                for label in 0..program.len() {
+                    let value = match program.get(label) {
+                        Some(&Instr::Return(value)) => value.map(0, u64::MAX),
+                        Some(&Instr::Branch {
+                            slot,
+                            then,
+                            otherwise,
+                        }) => {
+                            let input = columns
+                                .get(slot)
+                                .and_then(|column| column.words().get(word))
+                                .copied()
+                                .unwrap_or_default();
+                            let if_true = values.get(then).copied().unwrap_or_default();
+                            let if_false = values.get(otherwise).copied().unwrap_or_default();
+                            (input & if_true) | (!input & if_false)
+                        }
+                        None => 0,
+                    };
+                    if let Some(slot) = values.get_mut(label) {
+                        *slot = value;
+                    }
+                }
+                values.get(self.entry).copied().unwrap_or_default()
+            })
+            .collect();
+        Ok(BoolVec::from_words(words, rows))
+    }
+}
+
+/// Error produced when the columns passed to [`CompiledExpr::eval_bitsliced`] do not match its
+/// variables.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum ColumnError {
+    /// No column was given for a variable.
+    Missing {
+        /// Name of the variable.
+        name: String,
+    },
+    /// A variable's column has a different number of rows than the first column.
+    Length {
+        /// Name of the variable.
+        name: String,
+        /// Number of rows in the first column.
+        expected: usize,
+        /// Number of rows in the variable's column.
+        found: usize,
+    },
+}
+
+impl Display for ColumnError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Missing { name } => write!(f, "no column for variable `{name}`"),
+            Self::Length {
+                name,
+                expected,
+                found,
+            } => write!(
+                f,
+                "column for variable `{name}` has {found} rows, but {expected} were expected"
+            ),
+        }
+    }
+}
+
+#[expect(
+    clippy::missing_trait_methods,
+    reason = "a missing or mismatched column has no underlying cause"
+)]
+impl Error for ColumnError {}
+
+impl Expr {
+    /// ## Compiles the expression to a [`CompiledExpr`], for fast, repeated evaluation against
+    /// inputs indexed by slot.
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::expr::Expr;
+    ///
+    /// let rule = "a ^ b ^ c".parse::<Expr>().unwrap();
+    /// let compiled = rule.compile();
+    ///
+    /// for assignment in 0..8_u8 {
+    ///     let inputs = [assignment & 4 != 0, assignment & 2 != 0, assignment & 1 != 0];
+    ///     let env = |name: &str| compiled.slot(name).map(|slot| inputs[slot]);
+    ///     assert!(compiled.eval(&inputs) == rule.eval(&env));
+    /// }
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn compile(&self) -> CompiledExpr {
+        let variables = self
+            .variables()
+            .into_iter()
+            .map(String::from)
+            .collect::<Vec<_>>();
+        let mut compiler = Compiler {
+            variables: &variables,
+            program: Vec::from([Instr::Return(false), Instr::Return(true)]),
+            branches: BTreeMap::new(),
+        };
+        let entry = compiler.compile(self, TRUE, FALSE);
+        let program = compiler.program;
+        CompiledExpr {
+            variables,
+            program,
+            entry,
+        }
+    }
+
+    /// Number of nodes in the expression.
+    fn size(&self) -> usize {
+        match self {
+            Self::Var(_) | Self::Const(_) => 1,
+            Self::Not(expr) => expr.size().saturating_add(1),
+            Self::And(lhs, rhs)
+            | Self::Or(lhs, rhs)
+            | Self::Xor(lhs, rhs)
+            | Self::Implies(lhs, rhs)
+            | Self::Iff(lhs, rhs) => lhs.size().saturating_add(rhs.size()).saturating_add(1),
+        }
+    }
+}
+
+/// State of the compilation of an [`Expr`] to a [`CompiledExpr`].
+struct Compiler<'vars> {
+    /// The variables, in input order.
+    variables: &'vars [String],
+    /// The instructions emitted so far.
+    program: Vec<Instr>,
+    /// Existing branches, so that identical code is shared rather than duplicated.
+    branches: BTreeMap<(usize, usize, usize), usize>,
+}
+
+impl Compiler<'_> {
+    /// Emits code which continues at `then` if `expr` is `true`, otherwise at `otherwise`, and
+    /// returns its label.
+    fn compile(&mut self, expr: &Expr, then: usize, otherwise: usize) -> usize {
+        match expr {
+            Expr::Var(name) => {
+                let slot = self
+                    .variables
+                    .binary_search_by(|variable| variable.as_str().cmp(name))
+                    .unwrap_or_else(|slot| slot);
+                self.branch(slot, then, otherwise)
+            }
+            Expr::Const(true) => then,
+            Expr::Const(false) => otherwise,
+            Expr::Not(operand) => self.compile(operand, otherwise, then),
+            Expr::And(lhs, rhs) => {
+                let next = self.compile(rhs, then, otherwise);
+                self.compile(lhs, next, otherwise)
+            }
+            Expr::Or(lhs, rhs) => {
+                let next = self.compile(rhs, then, otherwise);
+                self.compile(lhs, then, next)
+            }
+            Expr::Implies(lhs, rhs) => {
+                let next = self.compile(rhs, then, otherwise);
+                self.compile(lhs, next, then)
+            }
+            Expr::Xor(lhs, rhs) => self.compile_parity(lhs, rhs, otherwise, then),
+            Expr::Iff(lhs, rhs) => self.compile_parity(lhs, rhs, then, otherwise),
+        }
+    }
+
+    /// Emits code which continues at `equal` if `lhs` and `rhs` are equal, otherwise at
+    /// `unequal`.  The smaller operand is compiled twice (once for each value of the larger), so
+    /// that chains of `^` grow linearly whichever way they associate.
+    fn compile_parity(&mut self, lhs: &Expr, rhs: &Expr, equal: usize, unequal: usize) -> usize {
+        let (larger, smaller) = match lhs.size() >= rhs.size() {
+            true => (lhs, rhs),
+            false => (rhs, lhs),
+        };
+        let if_true = self.compile(smaller, equal, unequal);
+        let if_false = self.compile(smaller, unequal, equal);
+        self.compile(larger, if_true, if_false)
+    }
+
+    /// Returns the label of a branch on `slot` to `then` or `otherwise`, emitting it if needed.
+    fn branch(&mut self, slot: usize, then: usize, otherwise: usize) -> usize {
+        if then == otherwise {
+            return then;
+        }
+        let label = self.program.len();
+        *self
+            .branches
+            .entry((slot, then, otherwise))
+            .or_insert_with(|| {
+                self.program.push(Instr::Branch {
+                    slot,
+                    then,
+                    otherwise,
+                });
+                label
+            })
+    }
+}
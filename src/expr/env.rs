@@ -0,0 +1,38 @@
+use alloc::collections::BTreeMap;
+use core::borrow::Borrow;
+#[cfg(feature = "std")]
+use core::hash::{BuildHasher, Hash};
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+/// An environment binding variable names to values, against which an
+/// [`Expr`](super::Expr) is evaluated.
+///
+/// `Env` is implemented for closures `Fn(&str) -> Option<bool>`, `BTreeMap`s and (with the `std`
+/// feature) `HashMap`s keyed by any type which borrows as `str`.
+pub trait Env {
+    /// Returns the value bound to `name`, or `None` if `name` is unbound.
+    fn get(&self, name: &str) -> Option<bool>;
+}
+
+impl<F: Fn(&str) -> Option<bool>> Env for F {
+    #[inline]
+    fn get(&self, name: &str) -> Option<bool> {
+        self(name)
+    }
+}
+
+impl<K: Borrow<str> + Ord> Env for BTreeMap<K, bool> {
+    #[inline]
+    fn get(&self, name: &str) -> Option<bool> {
+        Self::get(self, name).copied()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: Borrow<str> + Eq + Hash, S: BuildHasher> Env for HashMap<K, bool, S> {
+    #[inline]
+    fn get(&self, name: &str) -> Option<bool> {
+        Self::get(self, name).copied()
+    }
+}
@@ -0,0 +1,303 @@
+#![expect(
+    clippy::arbitrary_source_item_ordering,
+    reason = "fields and methods follow a node of the proof tree from its expression to its operands"
+)]
+use super::{Env, Expr, UnboundVariable};
+use crate::BoolExt as _;
+use alloc::{string::String, vec, vec::Vec};
+use core::fmt::{Display, Formatter, Result as FmtResult};
+
+/// `Explanation` is a proof tree recording why an [`Expr`] evaluated as it did, produced by
+/// [`Expr::eval_explained`].
+///
+/// Each node records a sub-expression, its value and its evaluated operands (those skipped by
+/// short-circuiting are absent).  An operand is *decisive* if its value determined its parent's:
+/// `false` for `a && b` is decided by whichever operand was `false`, while `true` is decided by
+/// both.
+///
+/// `Display` renders the tree of evaluated sub-expressions, one per line, marking decisive
+/// operands with `*`.
+/// ### Examples:
+/// ```
+/// use assert2::assert;
+/// use bool_ext::expr::Expr;
+///
+/// let rule = "!suspended && (premium || admin)".parse::<Expr>().unwrap();
+/// let env = |name: &str| Some(name == "premium");
+///
+/// assert!(rule.eval_explained(&env).unwrap().to_string() == "\
+/// true: !suspended && (premium || admin)
+///   * true: !suspended
+///     * false: suspended
+///   * true: premium || admin
+///     * true: premium
+/// ");
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Explanation<'expr> {
+    /// The evaluated sub-expression.
+    expr: &'expr Expr,
+    /// Its value.
+    value: bool,
+    /// Whether its value determined its parent's.
+    decisive: bool,
+    /// The explanations of its evaluated operands.
+    operands: Vec<Self>,
+}
+
+impl<'expr> Explanation<'expr> {
+    /// ## Returns the explained sub-expression
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::expr::Expr;
+    ///
+    /// let rule = Expr::var("a") & Expr::var("b");
+    /// assert!(rule.eval_explained(&|_: &str| Some(true)).unwrap().expr() == &rule);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn expr(&self) -> &'expr Expr {
+        self.expr
+    }
+
+    /// ## Returns the value of the sub-expression
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::expr::Expr;
+    ///
+    /// let rule = Expr::var("a") & Expr::var("b");
+    /// assert!(rule.eval_explained(&|_: &str| Some(true)).unwrap().value());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn value(&self) -> bool {
+        self.value
+    }
+
+    /// ## Transforms decisive => `true`, otherwise `false` (the root of a tree is always decisive)
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::expr::Expr;
+    ///
+    /// let rule = "a || b".parse::<Expr>().unwrap();
+    /// let explanation = rule.eval_explained(&|name: &str| Some(name == "b")).unwrap();
+    ///
+    /// assert!(explanation.is_decisive());
+    /// assert!(!explanation.operands()[0].is_decisive());
+    /// assert!(explanation.operands()[1].is_decisive());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn is_decisive(&self) -> bool {
+        self.decisive
+    }
+
+    /// ## Returns the explanations of the evaluated operands, in evaluation order
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::expr::Expr;
+    ///
+    /// let rule = "a && b".parse::<Expr>().unwrap();
+    /// let explanation = rule.eval_explained(&|_: &str| Some(false)).unwrap();
+    ///
+    /// // `b` was short-circuited
+    /// assert!(explanation.operands().len() == 1);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn operands(&self) -> &[Self] {
+        &self.operands
+    }
+
+    /// ## Returns the variables (with their values) which decided the result, in evaluation order
+    ///
+    /// These are the variables reached from the root through decisive operands alone.  Each
+    /// appears once, however often it was evaluated.
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::expr::Expr;
+    ///
+    /// let rule = "(premium && verified) || admin".parse::<Expr>().unwrap();
+    /// let explanation = rule.eval_explained(&|name: &str| Some(name == "verified")).unwrap();
+    ///
+    /// assert!(explanation.reasons() == [("premium", false), ("admin", false)]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn reasons(&self) -> Vec<(&'expr str, bool)> {
+        let mut reasons = Vec::new();
+        self.collect_reasons(&mut reasons);
+        reasons
+    }
+
+    /// Appends the variables reached through decisive operands, with their values, to `reasons`
+    /// (each once).
+    #[expect(
+        clippy::pattern_type_mismatch,
+        reason = "`ref_patterns` rejects the `ref` binding of the alternative"
+    )]
+    fn collect_reasons(&self, reasons: &mut Vec<(&'expr str, bool)>) {
+        if let Expr::Var(name) = self.expr {
+            let reason = (name.as_str(), self.value);
+            if !reasons.contains(&reason) {
+                reasons.push(reason);
+            }
+        }
+        self.operands
+            .iter()
+            .filter(|operand| operand.decisive)
+            .for_each(|operand| operand.collect_reasons(reasons));
+    }
+
+    /// ## Renders the [`reasons`](Self::reasons) as a human-readable sentence fragment
+    ///
+    /// A result decided by no variables (that is, by constants alone) renders as
+    /// `the rule is constant`.
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::expr::Expr;
+    ///
+    /// let rule = "!suspended && (premium || admin)".parse::<Expr>().unwrap();
+    ///
+    /// let env = |name: &str| Some(name == "suspended");
+    /// let reason = rule.eval_explained(&env).unwrap().reason();
+    /// assert!(format!("denied because {reason}") == "denied because `suspended` was true");
+    ///
+    /// let env = |name: &str| Some(name != "suspended");
+    /// let reason = rule.eval_explained(&env).unwrap().reason();
+    /// assert!(reason == "`suspended` was false and `premium` was true");
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn reason(&self) -> String {
+        let reasons = self.reasons();
+        let mut reason = String::new();
+        let last = reasons.len().saturating_sub(1);
+        for (index, &(name, value)) in reasons.iter().enumerate() {
+            let separator = match index {
+                0 => "",
+                _ if index == last => " and ",
+                _ => ", ",
+            };
+            for part in [separator, "`", name, "` was ", value.map("false", "true")] {
+                reason.push_str(part);
+            }
+        }
+        if reason.is_empty() {
+            reason.push_str("the rule is constant");
+        }
+        reason
+    }
+
+    /// Writes `self` and its operands, indented by `depth`.
+    #[expect(
+        clippy::question_mark_used,
+        reason = "the tree is written line by line"
+    )]
+    fn fmt_indented(&self, f: &mut Formatter<'_>, depth: usize) -> FmtResult {
+        for _ in 1..depth {
+            f.write_str("  ")?;
+        }
+        if depth > 0 {
+            f.write_str(match self.decisive {
+                true => "  * ",
+                false => "    ",
+            })?;
+        }
+        writeln!(f, "{}: {}", self.value, self.expr)?;
+        self.operands
+            .iter()
+            .try_for_each(|operand| operand.fmt_indented(f, depth.saturating_add(1)))
+    }
+}
+
+impl Display for Explanation<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        self.fmt_indented(f, 0)
+    }
+}
+
+#[expect(
+    clippy::multiple_inherent_impl,
+    reason = "explained evaluation is kept beside `Explanation`"
+)]
+impl Expr {
+    /// ## Evaluates the expression against `env`, as [`eval`](Self::eval) does, recording why it
+    /// took the value it did in an [`Explanation`].
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::expr::Expr;
+    /// use std::collections::HashMap;
+    ///
+    /// let rule = "admin || (premium && !suspended)".parse::<Expr>().unwrap();
+    /// let user = HashMap::from([("admin", false), ("premium", true), ("suspended", true)]);
+    ///
+    /// let explanation = rule.eval_explained(&user).unwrap();
+    ///
+    /// assert!(!explanation.value());
+    /// assert!(explanation.reason() == "`admin` was false and `suspended` was true");
+    /// ```
+    #[expect(
+        clippy::pattern_type_mismatch,
+        clippy::question_mark_used,
+        reason = "`ref_patterns` rejects the `ref` bindings of the alternative; `?` stops at the first unbound variable"
+    )]
+    #[inline]
+    pub fn eval_explained<E: Env + ?Sized>(
+        &self,
+        env: &E,
+    ) -> Result<Explanation<'_>, UnboundVariable> {
+        let (value, mut operands) = match self {
+            Self::Var(name) => (
+                env.get(name).ok_or_else(|| UnboundVariable::new(name))?,
+                Vec::new(),
+            ),
+            Self::Const(value) => (*value, Vec::new()),
+            Self::Not(expr) => {
+                let operand = expr.eval_explained(env)?;
+                (!operand.value, vec![operand])
+            }
+            // `lhs` short-circuits `&&` and `->` when `false`, and `||` when `true`
+            Self::And(lhs, rhs) | Self::Or(lhs, rhs) | Self::Implies(lhs, rhs) => {
+                let left = lhs.eval_explained(env)?;
+                let disjunction = matches!(self, Self::Or(..));
+                if left.value == disjunction {
+                    (!matches!(self, Self::And(..)), vec![left])
+                } else {
+                    let right = rhs.eval_explained(env)?;
+                    (right.value, vec![left, right])
+                }
+            }
+            Self::Xor(lhs, rhs) | Self::Iff(lhs, rhs) => {
+                let left = lhs.eval_explained(env)?;
+                let right = rhs.eval_explained(env)?;
+                let equal = left.value == right.value;
+                (equal == matches!(self, Self::Iff(..)), vec![left, right])
+            }
+        };
+        // Where both operands of `&&`, `||` or `->` were evaluated but the result is the one
+        // which short-circuiting would have produced, the rhs alone decided it
+        let decided_by_rhs = match *self {
+            Self::And(..) => !value,
+            Self::Or(..) | Self::Implies(..) => value,
+            Self::Var(_) | Self::Const(_) | Self::Not(_) | Self::Xor(..) | Self::Iff(..) => false,
+        };
+        if let [lhs, _] = operands.as_mut_slice() {
+            lhs.decisive = !decided_by_rhs;
+        }
+        Ok(Explanation {
+            expr: self,
+            value,
+            decisive: true,
+            operands,
+        })
+    }
+}
@@ -0,0 +1,402 @@
+//! Boolean expressions over named variables.
+//!
+//! An [`Expr`] is an abstract syntax tree for a Boolean rule such as
+//! `(premium && !suspended) || admin`.  It evaluates against any [`Env`] (a closure
+//! `Fn(&str) -> Option<bool>` or a map from variable name to `bool`) and yields a
+//! `Result<bool, UnboundVariable>`, which continues straight into the
+//! [`ResultBoolExt`](crate::ResultBoolExt) combinators.
+#![expect(
+    clippy::mod_module_files,
+    reason = "`self_named_module_files` rejects the alternative, `expr.rs`"
+)]
+#![expect(
+    clippy::pub_use,
+    reason = "the submodules are private, so `expr` is the path of their items"
+)]
+
+/// Compilation to a branching program.
+mod compile;
+/// Variable environments.
+mod env;
+/// Explanations of an evaluation.
+mod explain;
+/// Parsing from text.
+mod parse;
+/// Simplification and normal forms.
+mod simplify;
+/// Truth tables and equivalence checking.
+#[expect(
+    clippy::pub_with_shorthand,
+    reason = "`pub_without_shorthand` rejects the alternative, `pub(in crate)`"
+)]
+pub(crate) mod truth_table;
+
+#[expect(
+    clippy::module_name_repetitions,
+    reason = "a compiled `Expr` is named after it"
+)]
+pub use compile::{ColumnError, CompiledExpr, Inputs};
+pub use env::Env;
+pub use explain::Explanation;
+pub use parse::{ParseError, ParseErrorKind, TokenKind, MAX_DEPTH};
+pub use truth_table::{Difference, Row, RowOrder, TruthTable};
+
+use alloc::{boxed::Box, collections::BTreeSet, string::String};
+use core::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    ops::{BitAnd, BitOr, BitXor, Not},
+};
+
+/// `Expr` is a Boolean expression over named variables.
+/// ### Examples:
+/// ```
+/// use assert2::assert;
+/// use bool_ext::{expr::Expr, ResultBoolExt};
+/// use std::collections::HashMap;
+///
+/// let rule = (Expr::var("premium") & !Expr::var("suspended")) | Expr::var("admin");
+/// let user = HashMap::from([("premium", true), ("suspended", false), ("admin", false)]);
+///
+/// let mut granted = false;
+/// assert!(rule.eval(&user).and_do(|| granted = true) == Ok(true));
+/// assert!(granted);
+/// ```
+///
+/// The variants are public, so an `Expr` can be built and taken apart directly:
+/// ```
+/// use assert2::assert;
+/// use bool_ext::expr::Expr;
+///
+/// let rule = "premium && !suspended".parse::<Expr>().unwrap();
+/// let Expr::And(lhs, rhs) = rule else { unreachable!() };
+/// assert!(*lhs == Expr::var("premium"));
+/// assert!(*rhs == !Expr::var("suspended"));
+/// ```
+///
+/// Operations on an `Expr` (dropping, cloning and comparing it included) recurse once per level
+/// of nesting, so a deep enough tree overflows the stack.  Only parsed trees are depth-bounded:
+/// the parser rejects an expression more than [`MAX_DEPTH`] levels deep.  Trees built through
+/// the constructors and operators are not checked, so the depth of one built from untrusted
+/// input must be bounded by the caller.  A chain of `n` binary operators (such as `a && b && …`)
+/// is `n` levels deep.
+/// ```
+/// use assert2::assert;
+/// use bool_ext::expr::{Expr, MAX_DEPTH};
+///
+/// let chain = |operators: usize| vec!["a"; operators + 1].join(" && ");
+///
+/// assert!(chain(MAX_DEPTH).parse::<Expr>().is_ok());
+/// assert!(chain(MAX_DEPTH + 1).parse::<Expr>().is_err());
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[expect(
+    clippy::exhaustive_enums,
+    clippy::arbitrary_source_item_ordering,
+    reason = "the tree is matched exhaustively, and its variants run from the leaves down through the precedence levels"
+)]
+pub enum Expr {
+    /// A named variable.
+    Var(String),
+    /// A constant value.
+    Const(bool),
+    /// Logical negation.
+    Not(Box<Self>),
+    /// Logical conjunction.
+    And(Box<Self>, Box<Self>),
+    /// Logical (inclusive) disjunction.
+    Or(Box<Self>, Box<Self>),
+    /// Exclusive disjunction.
+    Xor(Box<Self>, Box<Self>),
+    /// Material implication (`lhs` implies `rhs`).
+    Implies(Box<Self>, Box<Self>),
+    /// Logical equivalence (`lhs` if and only if `rhs`).
+    Iff(Box<Self>, Box<Self>),
+}
+
+#[expect(
+    clippy::multiple_inherent_impl,
+    clippy::arbitrary_source_item_ordering,
+    reason = "each submodule adds the methods of its own concern; here the constructors come first"
+)]
+impl Expr {
+    /// ## Constructs a variable named `name`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::expr::Expr;
+    ///
+    /// assert!(Expr::var("admin") == Expr::Var(String::from("admin")));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn var<S: Into<String>>(name: S) -> Self {
+        Self::Var(name.into())
+    }
+
+    /// ## Constructs `self` implies `rhs`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::expr::Expr;
+    ///
+    /// let rule = Expr::var("admin").implies(Expr::var("verified"));
+    /// assert!(rule.eval(&|_: &str| Some(false)) == Ok(true));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn implies(self, rhs: Self) -> Self {
+        Self::Implies(Box::new(self), Box::new(rhs))
+    }
+
+    /// ## Constructs `self` if and only if `rhs`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::expr::Expr;
+    ///
+    /// let rule = Expr::var("a").iff(Expr::var("b"));
+    /// assert!(rule.eval(&|_: &str| Some(false)) == Ok(true));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn iff(self, rhs: Self) -> Self {
+        Self::Iff(Box::new(self), Box::new(rhs))
+    }
+
+    /// ## Evaluates the expression against `env`
+    ///
+    /// Evaluation short-circuits exactly as Rust's `&&` and `||` do (an implication short-circuits
+    /// on a `false` antecedent), so a variable which does not influence the result need not be
+    /// bound.  Otherwise, the first unbound variable encountered is reported.
+    ///
+    /// The result is a `Result<bool, _>` rather than a `bool`, as an [`Env`] need not bind every
+    /// variable and there is no value which could stand in for an unbound one: defaulting it to
+    /// `false` would silently grant or deny whatever the rule guards.  The `Result` continues
+    /// into the [`ResultBoolExt`](crate::ResultBoolExt) combinators, and
+    /// `unwrap_or(false)` recovers a `bool` where failing closed is intended.
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::expr::Expr;
+    /// use std::collections::HashMap;
+    ///
+    /// let rule = Expr::var("premium") & Expr::var("verified");
+    ///
+    /// let env = HashMap::from([("premium", true)]);
+    /// assert!(rule.eval(&env).unwrap_err().name() == "verified");
+    ///
+    /// let env = HashMap::from([("premium", false)]);
+    /// assert!(rule.eval(&env) == Ok(false));
+    /// ```
+    #[expect(
+        clippy::pattern_type_mismatch,
+        clippy::question_mark_used,
+        reason = "`ref_patterns` rejects the `ref` bindings of the alternative; `?` stops at the first unbound variable"
+    )]
+    #[inline]
+    pub fn eval<E: Env + ?Sized>(&self, env: &E) -> Result<bool, UnboundVariable> {
+        match self {
+            Self::Var(name) => env.get(name).ok_or_else(|| UnboundVariable::new(name)),
+            Self::Const(value) => Ok(*value),
+            Self::Not(expr) => expr.eval(env).map(Not::not),
+            Self::And(lhs, rhs) => Ok(lhs.eval(env)? && rhs.eval(env)?),
+            Self::Or(lhs, rhs) => Ok(lhs.eval(env)? || rhs.eval(env)?),
+            Self::Xor(lhs, rhs) => Ok(lhs.eval(env)? ^ rhs.eval(env)?),
+            Self::Implies(lhs, rhs) => Ok(!lhs.eval(env)? || rhs.eval(env)?),
+            Self::Iff(lhs, rhs) => Ok(lhs.eval(env)? == rhs.eval(env)?),
+        }
+    }
+
+    /// ## Returns the names of all variables in the expression, in sorted order
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::expr::Expr;
+    ///
+    /// let rule = (Expr::var("b") & Expr::var("a")) | Expr::var("b");
+    /// assert!(rule.variables().into_iter().eq(["a", "b"]));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn variables(&self) -> BTreeSet<&str> {
+        let mut variables = BTreeSet::new();
+        self.collect_variables(&mut variables);
+        variables
+    }
+
+    /// Inserts the variables of `self` into `variables`.
+    #[expect(
+        clippy::pattern_type_mismatch,
+        reason = "`ref_patterns` rejects the `ref` bindings of the alternative"
+    )]
+    fn collect_variables<'expr>(&'expr self, variables: &mut BTreeSet<&'expr str>) {
+        match self {
+            Self::Var(name) => {
+                variables.insert(name.as_str());
+            }
+            Self::Const(_) => (),
+            Self::Not(expr) => expr.collect_variables(variables),
+            Self::And(lhs, rhs)
+            | Self::Or(lhs, rhs)
+            | Self::Xor(lhs, rhs)
+            | Self::Implies(lhs, rhs)
+            | Self::Iff(lhs, rhs) => {
+                lhs.collect_variables(variables);
+                rhs.collect_variables(variables);
+            }
+        }
+    }
+
+    /// Binding strength of the expression's top-level operator, used to minimize parentheses
+    /// when rendering.  Higher binds more tightly.
+    const fn precedence(&self) -> u8 {
+        match *self {
+            Self::Var(_) | Self::Const(_) | Self::Not(_) => 6,
+            Self::And(..) => 5,
+            Self::Xor(..) => 4,
+            Self::Or(..) => 3,
+            Self::Implies(..) => 2,
+            Self::Iff(..) => 1,
+        }
+    }
+
+    /// Writes `self`, in parentheses if `parenthesize`.
+    fn fmt_operand(&self, f: &mut Formatter<'_>, parenthesize: bool) -> FmtResult {
+        match parenthesize {
+            true => write!(f, "({self})"),
+            false => write!(f, "{self}"),
+        }
+    }
+
+    /// Writes the binary operation `lhs op rhs`, parenthesizing the operands as precedence and
+    /// associativity require.
+    #[expect(
+        clippy::question_mark_used,
+        reason = "the operands are written piecewise"
+    )]
+    fn fmt_binary(&self, f: &mut Formatter<'_>, lhs: &Self, op: &str, rhs: &Self) -> FmtResult {
+        let precedence = self.precedence();
+        // `->` is right-associative; all other binary operators are left-associative
+        let right_assoc = matches!(self, Self::Implies(..));
+        lhs.fmt_operand(
+            f,
+            lhs.precedence() < precedence || (right_assoc && lhs.precedence() == precedence),
+        )?;
+        write!(f, " {op} ")?;
+        rhs.fmt_operand(
+            f,
+            rhs.precedence() < precedence || (!right_assoc && rhs.precedence() == precedence),
+        )
+    }
+}
+
+/// Renders the expression in C-style syntax (`!`, `&&`, `^`, `||`, `->`, `<->`), using only
+/// those parentheses required by precedence and associativity.
+impl Display for Expr {
+    #[expect(
+        clippy::pattern_type_mismatch,
+        clippy::question_mark_used,
+        reason = "`ref_patterns` rejects the `ref` bindings of the alternative; a negation is written piecewise"
+    )]
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Var(name) => f.write_str(name),
+            Self::Const(value) => write!(f, "{value}"),
+            Self::Not(expr) => {
+                f.write_str("!")?;
+                expr.fmt_operand(f, expr.precedence() < self.precedence())
+            }
+            Self::And(lhs, rhs) => self.fmt_binary(f, lhs, "&&", rhs),
+            Self::Or(lhs, rhs) => self.fmt_binary(f, lhs, "||", rhs),
+            Self::Xor(lhs, rhs) => self.fmt_binary(f, lhs, "^", rhs),
+            Self::Implies(lhs, rhs) => self.fmt_binary(f, lhs, "->", rhs),
+            Self::Iff(lhs, rhs) => self.fmt_binary(f, lhs, "<->", rhs),
+        }
+    }
+}
+
+impl From<bool> for Expr {
+    #[inline]
+    fn from(value: bool) -> Self {
+        Self::Const(value)
+    }
+}
+
+impl Not for Expr {
+    type Output = Self;
+
+    #[inline]
+    fn not(self) -> Self {
+        Self::Not(Box::new(self))
+    }
+}
+
+impl BitAnd for Expr {
+    type Output = Self;
+
+    #[inline]
+    fn bitand(self, rhs: Self) -> Self {
+        Self::And(Box::new(self), Box::new(rhs))
+    }
+}
+
+impl BitOr for Expr {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, rhs: Self) -> Self {
+        Self::Or(Box::new(self), Box::new(rhs))
+    }
+}
+
+impl BitXor for Expr {
+    type Output = Self;
+
+    #[inline]
+    fn bitxor(self, rhs: Self) -> Self {
+        Self::Xor(Box::new(self), Box::new(rhs))
+    }
+}
+
+/// Error produced when an [`Expr`] references a variable which its [`Env`] does not bind.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct UnboundVariable {
+    /// The name of the unbound variable.
+    name: String,
+}
+
+#[expect(
+    clippy::arbitrary_source_item_ordering,
+    reason = "the constructor comes first"
+)]
+impl UnboundVariable {
+    /// Creates an error for the variable `name`.
+    #[inline]
+    fn new(name: &str) -> Self {
+        Self {
+            name: String::from(name),
+        }
+    }
+
+    /// Name of the unbound variable.
+    #[must_use]
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl Display for UnboundVariable {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "unbound variable `{}`", self.name)
+    }
+}
+
+#[expect(
+    clippy::missing_trait_methods,
+    reason = "the error has no underlying source"
+)]
+impl Error for UnboundVariable {}
@@ -0,0 +1,572 @@
+#![expect(
+    clippy::arbitrary_source_item_ordering,
+    reason = "the tokens and parsing methods follow the grammar, from the loosest binding operator to the tightest"
+)]
+#![expect(
+    clippy::question_mark_used,
+    reason = "each level of the recursive descent passes its callees' errors up"
+)]
+#![expect(
+    clippy::single_call_fn,
+    reason = "the grammar has a method per precedence level"
+)]
+use super::Expr;
+use alloc::vec::Vec;
+use core::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    ops::{BitAnd, BitOr, BitXor, Range},
+    str::FromStr,
+};
+
+/// Kind of token recognised by the [`Expr`] parser, as reported by a [`ParseError`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[expect(
+    clippy::exhaustive_enums,
+    reason = "the tokens are those of the documented grammar"
+)]
+pub enum TokenKind {
+    /// A variable name: a letter or `_`, then any letters, digits, `_`s and `.`s.
+    Ident,
+    /// `true` or `false`.
+    Literal,
+    /// `!`, `~`, `not` or `¬`.
+    Not,
+    /// `&&`, `&`, `and` or `∧`.
+    And,
+    /// `||`, `|`, `or` or `∨`.
+    Or,
+    /// `^`, `xor` or `⊕`.
+    Xor,
+    /// `->`, `=>`, `implies` or `→`.
+    Implies,
+    /// `<->`, `<=>`, `iff` or `↔`.
+    Iff,
+    /// `(`.
+    LParen,
+    /// `)`.
+    RParen,
+    /// The end of the input.
+    Eof,
+    /// A character which cannot begin any token.
+    Invalid,
+}
+
+impl Display for TokenKind {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(match *self {
+            Self::Ident => "identifier",
+            Self::Literal => "`true` or `false`",
+            Self::Not => "`!`",
+            Self::And => "`&&`",
+            Self::Or => "`||`",
+            Self::Xor => "`^`",
+            Self::Implies => "`->`",
+            Self::Iff => "`<->`",
+            Self::LParen => "`(`",
+            Self::RParen => "`)`",
+            Self::Eof => "end of input",
+            Self::Invalid => "invalid character",
+        })
+    }
+}
+
+/// Kind of [`ParseError`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum ParseErrorKind {
+    /// The offending token cannot appear where it was found.
+    UnexpectedToken,
+    /// The offending token (an operator or `(`) nests more than [`MAX_DEPTH`] levels deep.
+    TooDeep,
+}
+
+/// Error produced when parsing an [`Expr`] fails.
+///
+/// Records the byte span of the offending token within the input, what was found there, and, if
+/// the token was unexpected, every kind of token which would have been accepted in its place.
+/// ### Examples:
+/// ```
+/// use assert2::assert;
+/// use bool_ext::expr::{Expr, ParseErrorKind, TokenKind};
+///
+/// let err = "premium && (admin ||)".parse::<Expr>().unwrap_err();
+///
+/// assert!(err.kind() == ParseErrorKind::UnexpectedToken);
+/// assert!(err.span() == (20..21));
+/// assert!(err.found() == TokenKind::RParen);
+/// assert!(err.expected().contains(&TokenKind::Ident));
+/// assert!(err.to_string()
+///     == "expected identifier, `true` or `false`, `!` or `(`, but found `)` at bytes 20..21");
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ParseError {
+    /// Byte range of the offending token.
+    span: Range<usize>,
+    /// Kind of the offending token.
+    found: TokenKind,
+    /// What is wrong with the offending token.
+    kind: ParseErrorKind,
+    /// Kinds of token which would have been accepted in its place.
+    expected: Vec<TokenKind>,
+}
+
+impl ParseError {
+    /// Creates an error for the unexpected `token`, in place of which any of `expected` would have
+    /// been accepted.
+    const fn unexpected(token: Token, expected: Vec<TokenKind>) -> Self {
+        Self {
+            span: token.span,
+            found: token.kind,
+            kind: ParseErrorKind::UnexpectedToken,
+            expected,
+        }
+    }
+
+    /// Creates an error for the operator or parenthesis `token`, which nests too deeply.
+    fn too_deep(token: &Token) -> Self {
+        Self {
+            span: token.span.clone(),
+            found: token.kind,
+            kind: ParseErrorKind::TooDeep,
+            expected: Vec::new(),
+        }
+    }
+
+    /// Byte range of the offending token within the input.
+    #[must_use]
+    #[inline]
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+
+    /// Kind of the offending token.
+    #[must_use]
+    #[inline]
+    pub const fn found(&self) -> TokenKind {
+        self.found
+    }
+
+    /// What is wrong with the offending token.
+    #[must_use]
+    #[inline]
+    pub const fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+
+    /// Kinds of token which would have been accepted in place of the offending token (empty if
+    /// the input [nests too deeply](ParseErrorKind::TooDeep)).
+    #[must_use]
+    #[inline]
+    pub fn expected(&self) -> &[TokenKind] {
+        &self.expected
+    }
+
+    /// Transforms an error caused by the offending token (an operator or `(`) nesting more than
+    /// [`MAX_DEPTH`] levels deep => `true`, otherwise `false`.
+    #[must_use]
+    #[inline]
+    pub const fn is_too_deep(&self) -> bool {
+        matches!(self.kind, ParseErrorKind::TooDeep)
+    }
+}
+
+impl Display for ParseError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if self.is_too_deep() {
+            return write!(
+                f,
+                "{} nested more than {MAX_DEPTH} levels deep at bytes {}..{}",
+                self.found, self.span.start, self.span.end
+            );
+        }
+        f.write_str("expected ")?;
+        let last = self.expected.len().saturating_sub(1);
+        for (index, kind) in self.expected.iter().enumerate() {
+            match index {
+                0 => (),
+                _ if index == last => f.write_str(" or ")?,
+                _ => f.write_str(", ")?,
+            }
+            write!(f, "{kind}")?;
+        }
+        write!(
+            f,
+            ", but found {} at bytes {}..{}",
+            self.found, self.span.start, self.span.end
+        )
+    }
+}
+
+#[expect(
+    clippy::missing_trait_methods,
+    reason = "a syntax error has no underlying cause"
+)]
+impl Error for ParseError {}
+
+/// Parses C-style (`!`, `&&`, `||`, `^`, `->`, `<->`), word-style (`not`, `and`, `or`, `xor`,
+/// `implies`, `iff`; case-insensitive) or Unicode (`¬`, `∧`, `∨`, `⊕`, `→`, `↔`) syntax, freely
+/// mixed.  A variable name begins with a letter or `_`, followed by any letters, digits, `_`s and
+/// `.`s.
+///
+/// From tightest- to loosest-binding, the operators are `!`, `&&`, `^`, `||`, `->` and `<->`.
+/// `->` is right-associative; all other binary operators are left-associative.
+///
+/// So that neither parsing nor any later (recursive) operation on the parsed expression can
+/// overflow the stack, operators and parentheses may nest at most [`MAX_DEPTH`] levels deep; deeper
+/// input, including a chain of more than [`MAX_DEPTH`] binary operators (such as `a && b && …`),
+/// is rejected.
+/// ### Examples:
+/// ```
+/// use assert2::assert;
+/// use bool_ext::expr::{Expr, TokenKind};
+///
+/// let c_style = "premium && !suspended || admin".parse::<Expr>().unwrap();
+/// let words = "premium AND NOT suspended OR admin".parse::<Expr>().unwrap();
+/// let unicode = "(premium ∧ ¬suspended) ∨ admin".parse::<Expr>().unwrap();
+///
+/// assert!(c_style == words);
+/// assert!(c_style == unicode);
+/// assert!(c_style.to_string().parse::<Expr>() == Ok(c_style));
+/// assert!("1 && 0".parse::<Expr>().unwrap_err().found() == TokenKind::Invalid);
+///
+/// let parens = format!("{}a{}", "(".repeat(100_000), ")".repeat(100_000));
+/// let err = parens.parse::<Expr>().unwrap_err();
+/// assert!(err.is_too_deep());
+/// assert!(err.span() == (256..257));
+/// assert!("!".repeat(100_000).parse::<Expr>().unwrap_err().is_too_deep());
+/// assert!(["a"; 100_000].join(" && ").parse::<Expr>().unwrap_err().is_too_deep());
+/// ```
+impl FromStr for Expr {
+    type Err = ParseError;
+
+    #[inline]
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        Parser::new(input).parse()
+    }
+}
+
+/// Maximum depth of an expression accepted by the [`Expr`] parser.
+///
+/// The number of operators on any path from the root of the parsed expression to a leaf, and the
+/// nesting of its parentheses, may each be at most `MAX_DEPTH`.
+pub const MAX_DEPTH: usize = 256;
+
+/// Binary operators, any of which may follow a complete operand.
+const OPERATORS: [TokenKind; 5] = [
+    TokenKind::And,
+    TokenKind::Or,
+    TokenKind::Xor,
+    TokenKind::Implies,
+    TokenKind::Iff,
+];
+
+/// Tokens which may begin an operand.
+const OPERAND_START: [TokenKind; 4] = [
+    TokenKind::Ident,
+    TokenKind::Literal,
+    TokenKind::Not,
+    TokenKind::LParen,
+];
+
+/// Operator and parenthesis symbols; longer symbols precede their prefixes.
+const SYMBOLS: [(&str, TokenKind); 19] = [
+    ("<->", TokenKind::Iff),
+    ("<=>", TokenKind::Iff),
+    ("->", TokenKind::Implies),
+    ("=>", TokenKind::Implies),
+    ("&&", TokenKind::And),
+    ("||", TokenKind::Or),
+    ("&", TokenKind::And),
+    ("|", TokenKind::Or),
+    ("!", TokenKind::Not),
+    ("~", TokenKind::Not),
+    ("^", TokenKind::Xor),
+    ("\u{ac}", TokenKind::Not),       // ¬
+    ("\u{2227}", TokenKind::And),     // ∧
+    ("\u{2228}", TokenKind::Or),      // ∨
+    ("\u{2295}", TokenKind::Xor),     // ⊕
+    ("\u{2192}", TokenKind::Implies), // →
+    ("\u{2194}", TokenKind::Iff),     // ↔
+    ("(", TokenKind::LParen),
+    (")", TokenKind::RParen),
+];
+
+/// Word operators and literals, matched case-insensitively.
+const KEYWORDS: [(&str, TokenKind); 8] = [
+    ("not", TokenKind::Not),
+    ("and", TokenKind::And),
+    ("or", TokenKind::Or),
+    ("xor", TokenKind::Xor),
+    ("implies", TokenKind::Implies),
+    ("iff", TokenKind::Iff),
+    ("true", TokenKind::Literal),
+    ("false", TokenKind::Literal),
+];
+
+/// A lexed token.
+#[derive(Clone, Debug)]
+struct Token {
+    /// Kind of the token.
+    kind: TokenKind,
+    /// Byte range of the token within the input.
+    span: Range<usize>,
+}
+
+/// Recursive-descent parser, one function per level of precedence.
+struct Parser<'input> {
+    /// The text being parsed.
+    input: &'input str,
+    /// Byte offset of the next token to lex.
+    offset: usize,
+    /// Token lexed by [`peek`](Self::peek) but not yet consumed.
+    peeked: Option<Token>,
+    /// Number of `!`s and `(`s enclosing the operand being parsed.
+    depth: usize,
+}
+
+impl<'input> Parser<'input> {
+    /// Returns a parser positioned at the start of `input`.
+    const fn new(input: &'input str) -> Self {
+        Self {
+            input,
+            offset: 0,
+            peeked: None,
+            depth: 0,
+        }
+    }
+
+    /// Parses the whole input as an expression.
+    fn parse(mut self) -> Result<Expr, ParseError> {
+        let parsed = self.parse_iff()?;
+        self.expect(TokenKind::Eof)?;
+        Ok(parsed.expr)
+    }
+
+    /// Returns the input not yet lexed.
+    fn rest(&self) -> &'input str {
+        self.input.get(self.offset..).unwrap_or_default()
+    }
+
+    /// Returns the text of the input at `span`.
+    fn text(&self, span: &Range<usize>) -> &'input str {
+        self.input.get(span.clone()).unwrap_or_default()
+    }
+
+    /// Lexes the next token, skipping leading whitespace.
+    fn lex(&mut self) -> Token {
+        let rest = self.rest();
+        let trimmed = rest.trim_start();
+        let start = self
+            .offset
+            .saturating_add(rest.len().saturating_sub(trimmed.len()));
+        let (kind, len) = match trimmed.chars().next() {
+            None => (TokenKind::Eof, 0),
+            Some(ch) if ch.is_alphabetic() || ch == '_' => {
+                let len = trimmed
+                    .find(|letter: char| {
+                        !(letter.is_alphanumeric() || letter == '_' || letter == '.')
+                    })
+                    .unwrap_or(trimmed.len());
+                let word = trimmed.get(..len).unwrap_or_default();
+                let kind = KEYWORDS
+                    .iter()
+                    .find(|&&(keyword, _)| keyword.eq_ignore_ascii_case(word))
+                    .map_or(TokenKind::Ident, |&(_, kind)| kind);
+                (kind, len)
+            }
+            Some(ch) => SYMBOLS
+                .iter()
+                .find(|&&(symbol, _)| trimmed.starts_with(symbol))
+                .map_or_else(
+                    || (TokenKind::Invalid, ch.len_utf8()),
+                    |&(symbol, kind)| (kind, symbol.len()),
+                ),
+        };
+        let end = start.saturating_add(len);
+        self.offset = end;
+        Token {
+            kind,
+            span: start..end,
+        }
+    }
+
+    /// Returns the next token without consuming it.
+    fn peek(&mut self) -> &Token {
+        let token = self.next();
+        self.peeked.insert(token)
+    }
+
+    /// Consumes and returns the next token.
+    fn next(&mut self) -> Token {
+        self.peeked.take().unwrap_or_else(|| self.lex())
+    }
+
+    /// Consumes the next token if it is of kind `kind`, returning whether it was.
+    fn eat(&mut self, kind: TokenKind) -> bool {
+        let matched = self.peek().kind == kind;
+        if matched {
+            self.peeked = None;
+        }
+        matched
+    }
+
+    /// Consumes the closing token of the current nesting level (`)` or end of input), which is the
+    /// only other token allowed after a complete operand.
+    fn expect(&mut self, kind: TokenKind) -> Result<(), ParseError> {
+        if self.eat(kind) {
+            return Ok(());
+        }
+        let mut expected = OPERATORS.to_vec();
+        expected.push(kind);
+        Err(ParseError::unexpected(self.next(), expected))
+    }
+
+    /// Parses the operand nested within `token` (a `!` or `(`) with `parse`, failing if that would
+    /// exceed [`MAX_DEPTH`] levels of nesting.
+    fn nested(
+        &mut self,
+        token: &Token,
+        parse: impl FnOnce(&mut Self) -> Result<Parsed, ParseError>,
+    ) -> Result<Parsed, ParseError> {
+        if self.depth >= MAX_DEPTH {
+            return Err(ParseError::too_deep(token));
+        }
+        self.depth = self.depth.saturating_add(1);
+        let parsed = parse(self);
+        self.depth = self.depth.saturating_sub(1);
+        parsed
+    }
+
+    /// Parses a left-associative chain of `op`s over operands parsed by `parse`, combined by
+    /// `combine`.
+    fn parse_chain(
+        &mut self,
+        op: TokenKind,
+        parse: fn(&mut Self) -> Result<Parsed, ParseError>,
+        combine: fn(Expr, Expr) -> Expr,
+    ) -> Result<Parsed, ParseError> {
+        let mut lhs = parse(self)?;
+        while self.peek().kind == op {
+            let token = self.next();
+            let rhs = parse(self)?;
+            lhs = Parsed::binary(&token, lhs, rhs, combine)?;
+        }
+        Ok(lhs)
+    }
+
+    /// Parses a chain of `<->`s, the operator of lowest precedence.
+    fn parse_iff(&mut self) -> Result<Parsed, ParseError> {
+        self.parse_chain(TokenKind::Iff, Self::parse_implies, Expr::iff)
+    }
+
+    /// Parses a chain of `->`s.
+    fn parse_implies(&mut self) -> Result<Parsed, ParseError> {
+        // `->` is right-associative, so the chain is folded from its last operand
+        let mut antecedents = Vec::new();
+        let mut consequent = self.parse_or()?;
+        while self.peek().kind == TokenKind::Implies {
+            let token = self.next();
+            antecedents.push((consequent, token));
+            consequent = self.parse_or()?;
+        }
+        antecedents
+            .into_iter()
+            .rev()
+            .try_fold(consequent, |implied, (antecedent, token)| {
+                Parsed::binary(&token, antecedent, implied, Expr::implies)
+            })
+    }
+
+    /// Parses a chain of `||`s.
+    fn parse_or(&mut self) -> Result<Parsed, ParseError> {
+        self.parse_chain(TokenKind::Or, Self::parse_xor, BitOr::bitor)
+    }
+
+    /// Parses a chain of `^`s.
+    fn parse_xor(&mut self) -> Result<Parsed, ParseError> {
+        self.parse_chain(TokenKind::Xor, Self::parse_and, BitXor::bitxor)
+    }
+
+    /// Parses a chain of `&&`s.
+    fn parse_and(&mut self) -> Result<Parsed, ParseError> {
+        self.parse_chain(TokenKind::And, Self::parse_unary, BitAnd::bitand)
+    }
+
+    /// Parses a variable, a literal, a negation or a parenthesised expression.
+    fn parse_unary(&mut self) -> Result<Parsed, ParseError> {
+        let token = self.next();
+        match token.kind {
+            TokenKind::Ident => Ok(Parsed::leaf(Expr::var(self.text(&token.span)))),
+            TokenKind::Literal => Ok(Parsed::leaf(Expr::Const(
+                self.text(&token.span).eq_ignore_ascii_case("true"),
+            ))),
+            TokenKind::Not => {
+                let operand = self.nested(&token, Self::parse_unary)?;
+                Parsed::negation(&token, operand)
+            }
+            TokenKind::LParen => self.nested(&token, |parser| {
+                let parsed = parser.parse_iff()?;
+                parser.expect(TokenKind::RParen)?;
+                Ok(parsed)
+            }),
+            TokenKind::And
+            | TokenKind::Or
+            | TokenKind::Xor
+            | TokenKind::Implies
+            | TokenKind::Iff
+            | TokenKind::RParen
+            | TokenKind::Eof
+            | TokenKind::Invalid => Err(ParseError::unexpected(token, OPERAND_START.to_vec())),
+        }
+    }
+}
+
+/// A parsed expression, with its height (the number of operators on its longest path from root
+/// to leaf).
+struct Parsed {
+    /// The expression.
+    expr: Expr,
+    /// Its height.
+    height: usize,
+}
+
+impl Parsed {
+    /// Returns `expr`, an expression without operators.
+    const fn leaf(expr: Expr) -> Self {
+        Self { expr, height: 0 }
+    }
+
+    /// Negates `operand` with the `!` `token`, failing if the result would be more than
+    /// [`MAX_DEPTH`] levels deep.
+    fn negation(token: &Token, operand: Self) -> Result<Self, ParseError> {
+        let height = operand.height.saturating_add(1);
+        if height > MAX_DEPTH {
+            return Err(ParseError::too_deep(token));
+        }
+        Ok(Self {
+            expr: !operand.expr,
+            height,
+        })
+    }
+
+    /// Combines `lhs` and `rhs` with the binary operator `token`, failing if the result would be
+    /// more than [`MAX_DEPTH`] levels deep.
+    fn binary(
+        token: &Token,
+        lhs: Self,
+        rhs: Self,
+        combine: fn(Expr, Expr) -> Expr,
+    ) -> Result<Self, ParseError> {
+        let height = lhs.height.max(rhs.height).saturating_add(1);
+        if height > MAX_DEPTH {
+            return Err(ParseError::too_deep(token));
+        }
+        Ok(Self {
+            expr: combine(lhs.expr, rhs.expr),
+            height,
+        })
+    }
+}
@@ -0,0 +1,400 @@
+#![expect(
+    clippy::pattern_type_mismatch,
+    reason = "trees and literals are matched by reference, and `ref_patterns` rejects the `ref` bindings of the alternative"
+)]
+use super::Expr;
+use alloc::{boxed::Box, collections::BTreeSet, string::String, vec::Vec};
+
+/// A variable or its negation, as `(name, polarity)`.
+type Literal = (String, bool);
+
+/// A set of literals joined by the inner operator of a normal form (`||` in CNF, `&&` in DNF).
+type Clause = BTreeSet<Literal>;
+
+#[expect(
+    clippy::multiple_inherent_impl,
+    clippy::arbitrary_source_item_ordering,
+    reason = "the rewrites are kept beside their helpers, simplification first and then the normal forms, from NNF to DNF"
+)]
+impl Expr {
+    /// ## Returns an equivalent, usually smaller, expression
+    ///
+    /// Applies constant folding, double negation, De Morgan's laws (pushing negations inward),
+    /// idempotence (`a && a`), complementation (`a && !a`) and absorption (`a && (a || b)`,
+    /// `a && (!a || b)`) until no rule applies.  Operators other than `&&` and `||` are preserved
+    /// unless they fold away.
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::expr::Expr;
+    ///
+    /// let rule = "a && (a || b) || !!c && !(d || true)".parse::<Expr>().unwrap();
+    /// let simplified = rule.simplify();
+    ///
+    /// assert!(simplified.to_string() == "a");
+    /// assert!(simplified.truth_table().diff(&rule.truth_table()).is_empty());
+    /// ```
+    ///
+    /// Every transformation preserves the truth table:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::expr::Expr;
+    ///
+    /// let operands = ["a", "!a", "b", "true", "false"];
+    /// let operators = ["&&", "||", "^", "->", "<->"];
+    /// for lhs in operands {
+    ///     for rhs in operands {
+    ///         for outer in operators {
+    ///             for inner in operators {
+    ///                 let rule = format!("!({lhs} {outer} b) {inner} ({lhs} {outer} {rhs})")
+    ///                     .parse::<Expr>()
+    ///                     .unwrap();
+    ///                 let table = rule.truth_table();
+    ///                 for transformed in [rule.simplify(), rule.to_nnf(), rule.to_cnf(), rule.to_dnf()] {
+    ///                     assert!(transformed.truth_table().diff(&table).is_empty());
+    ///                 }
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn simplify(&self) -> Self {
+        let mut expr = simplify_once(self);
+        loop {
+            let next = simplify_once(&expr);
+            if next == expr {
+                return expr;
+            }
+            expr = next;
+        }
+    }
+
+    /// ## Returns an equivalent expression in negation normal form
+    ///
+    /// The result uses only `&&`, `||` and `!`, with `!` applied only to variables.
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::expr::Expr;
+    ///
+    /// let rule = "!(a -> (b <-> c))".parse::<Expr>().unwrap();
+    /// let nnf = rule.to_nnf();
+    ///
+    /// assert!(nnf.to_string() == "a && (b && !c || !b && c)");
+    /// assert!(nnf.truth_table().diff(&rule.truth_table()).is_empty());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn to_nnf(&self) -> Self {
+        nnf(self, false)
+    }
+
+    /// ## Returns an equivalent expression in conjunctive normal form (an `&&` of `||`s of
+    /// literals).
+    ///
+    /// Tautological clauses and clauses subsumed by others are dropped; the result may
+    /// nevertheless be exponentially larger than the original.
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::expr::Expr;
+    ///
+    /// let rule = "a && b || c".parse::<Expr>().unwrap();
+    /// let cnf = rule.to_cnf();
+    ///
+    /// assert!(cnf.to_string() == "(a || c) && (b || c)");
+    /// assert!(cnf.truth_table().diff(&rule.truth_table()).is_empty());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn to_cnf(&self) -> Self {
+        let nnf = self.to_nnf();
+        from_clauses(&clauses(&nnf, true), true)
+    }
+
+    /// ## Returns an equivalent expression in disjunctive normal form (an `||` of `&&`s of
+    /// literals).
+    ///
+    /// Contradictory terms and terms subsumed by others are dropped; the result may nevertheless
+    /// be exponentially larger than the original.
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::expr::Expr;
+    ///
+    /// let rule = "(a || b) && !a".parse::<Expr>().unwrap();
+    /// let dnf = rule.to_dnf();
+    ///
+    /// assert!(dnf.to_string() == "!a && b");
+    /// assert!(dnf.truth_table().diff(&rule.truth_table()).is_empty());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn to_dnf(&self) -> Self {
+        from_clauses(&self.dnf_terms(), false)
+    }
+
+    /// Returns the terms of the expression's disjunctive normal form (as by
+    /// [`to_dnf`](Self::to_dnf)).
+    pub(crate) fn dnf_terms(&self) -> BTreeSet<Clause> {
+        clauses(&self.to_nnf(), false)
+    }
+}
+
+/// Builds `lhs && rhs` (`conjunction`) or `lhs || rhs`.
+#[inline]
+fn junction(conjunction: bool, lhs: Expr, rhs: Expr) -> Expr {
+    match conjunction {
+        true => lhs & rhs,
+        false => lhs | rhs,
+    }
+}
+
+/// Returns the negation normal form of `expr`, or of `!expr` if `negate`.
+fn nnf(expr: &Expr, negate: bool) -> Expr {
+    match expr {
+        Expr::Var(_) => match negate {
+            true => !expr.clone(),
+            false => expr.clone(),
+        },
+        Expr::Const(value) => Expr::Const(value ^ negate),
+        Expr::Not(operand) => nnf(operand, !negate),
+        // De Morgan: negation swaps `&&` and `||`
+        Expr::And(lhs, rhs) => junction(!negate, nnf(lhs, negate), nnf(rhs, negate)),
+        Expr::Or(lhs, rhs) => junction(negate, nnf(lhs, negate), nnf(rhs, negate)),
+        // `a -> b` is `!a || b`
+        Expr::Implies(lhs, rhs) => junction(negate, nnf(lhs, !negate), nnf(rhs, negate)),
+        // `a <-> b` is `a && b || !a && !b`; `a ^ b` is its negation
+        Expr::Iff(lhs, rhs) | Expr::Xor(lhs, rhs) => {
+            let negate_rhs = negate ^ matches!(expr, Expr::Xor(..));
+            junction(
+                false,
+                nnf(lhs, false) & nnf(rhs, negate_rhs),
+                nnf(lhs, true) & nnf(rhs, !negate_rhs),
+            )
+        }
+    }
+}
+
+/// Returns the clauses of the conjunctive (`conjunctive`) or disjunctive normal form of `expr`.
+fn clauses(expr: &Expr, conjunctive: bool) -> BTreeSet<Clause> {
+    let clauses = match expr {
+        Expr::Var(name) => BTreeSet::from([Clause::from([(name.clone(), true)])]),
+        Expr::Not(operand) => match &**operand {
+            Expr::Var(name) => BTreeSet::from([Clause::from([(name.clone(), false)])]),
+            Expr::Const(_)
+            | Expr::Not(_)
+            | Expr::And(..)
+            | Expr::Or(..)
+            | Expr::Xor(..)
+            | Expr::Implies(..)
+            | Expr::Iff(..) => return clauses(&nnf(operand, true), conjunctive),
+        },
+        // The outer operator's identity has no clauses; its absorbing element is the empty clause
+        Expr::Const(value) => match value == &conjunctive {
+            true => BTreeSet::new(),
+            false => BTreeSet::from([Clause::new()]),
+        },
+        Expr::And(lhs, rhs) | Expr::Or(lhs, rhs) => {
+            let left = clauses(lhs, conjunctive);
+            let right = clauses(rhs, conjunctive);
+            match matches!(expr, Expr::And(..)) == conjunctive {
+                true => left.into_iter().chain(right).collect(),
+                // Distribute the inner operator over the outer
+                false => left
+                    .iter()
+                    .flat_map(|first| right.iter().map(move |second| first | second))
+                    .filter(|clause| {
+                        !clause
+                            .iter()
+                            .any(|(name, polarity)| clause.contains(&(name.clone(), !polarity)))
+                    })
+                    .collect(),
+            }
+        }
+        Expr::Xor(..) | Expr::Implies(..) | Expr::Iff(..) => {
+            return clauses(&nnf(expr, false), conjunctive)
+        }
+    };
+    // Drop clauses subsumed by (that is, supersets of) another clause
+    clauses
+        .iter()
+        .filter(|clause| {
+            !clauses
+                .iter()
+                .any(|other| other != *clause && other.is_subset(clause))
+        })
+        .cloned()
+        .collect()
+}
+
+/// Builds the conjunctive (`conjunctive`) or disjunctive normal form expression from `clauses`.
+fn from_clauses(clauses: &BTreeSet<Clause>, conjunctive: bool) -> Expr {
+    let literal = |(name, polarity): &Literal| match polarity {
+        true => Expr::var(name.as_str()),
+        false => !Expr::var(name.as_str()),
+    };
+    clauses
+        .iter()
+        .map(|clause| {
+            clause
+                .iter()
+                .map(literal)
+                .reduce(|lhs, rhs| junction(!conjunctive, lhs, rhs))
+                .unwrap_or(Expr::Const(!conjunctive))
+        })
+        .reduce(|lhs, rhs| junction(conjunctive, lhs, rhs))
+        .unwrap_or(Expr::Const(conjunctive))
+}
+
+/// Applies one bottom-up pass of the rewrite rules of [`Expr::simplify`].
+fn simplify_once(expr: &Expr) -> Expr {
+    match expr {
+        Expr::Var(_) | Expr::Const(_) => expr.clone(),
+        Expr::Not(operand) => negate(simplify_once(operand)),
+        Expr::And(lhs, rhs) | Expr::Or(lhs, rhs) => simplify_junction(
+            matches!(expr, Expr::And(..)),
+            simplify_once(lhs),
+            simplify_once(rhs),
+        ),
+        Expr::Xor(lhs, rhs) => match (simplify_once(lhs), simplify_once(rhs)) {
+            (Expr::Const(value), operand) | (operand, Expr::Const(value)) => match value {
+                true => negate(operand),
+                false => operand,
+            },
+            (left, right) if left == right => Expr::Const(false),
+            (left, right) if complementary(&left, &right) => Expr::Const(true),
+            (left, right) => left ^ right,
+        },
+        Expr::Implies(lhs, rhs) => match (simplify_once(lhs), simplify_once(rhs)) {
+            (Expr::Const(false), _) | (_, Expr::Const(true)) => Expr::Const(true),
+            (Expr::Const(true), operand) => operand,
+            (operand, Expr::Const(false)) => negate(operand),
+            (left, right) if left == right => Expr::Const(true),
+            (left, right) => left.implies(right),
+        },
+        Expr::Iff(lhs, rhs) => match (simplify_once(lhs), simplify_once(rhs)) {
+            (Expr::Const(value), operand) | (operand, Expr::Const(value)) => match value {
+                true => operand,
+                false => negate(operand),
+            },
+            (left, right) if left == right => Expr::Const(true),
+            (left, right) if complementary(&left, &right) => Expr::Const(false),
+            (left, right) => left.iff(right),
+        },
+    }
+}
+
+/// Returns `!expr`, folding constants, cancelling double negation and pushing the negation
+/// through the top-level operator (by De Morgan's laws, for `&&` and `||`).
+fn negate(expr: Expr) -> Expr {
+    match expr {
+        Expr::Var(_) => Expr::Not(Box::new(expr)),
+        Expr::Const(value) => Expr::Const(!value),
+        Expr::Not(operand) => *operand,
+        Expr::And(lhs, rhs) => negate(*lhs) | negate(*rhs),
+        Expr::Or(lhs, rhs) => negate(*lhs) & negate(*rhs),
+        Expr::Xor(lhs, rhs) => lhs.iff(*rhs),
+        Expr::Implies(lhs, rhs) => *lhs & negate(*rhs),
+        Expr::Iff(lhs, rhs) => *lhs ^ *rhs,
+    }
+}
+
+/// Transforms `lhs` == `!rhs` (or vice versa) => `true`, otherwise `false`.
+fn complementary(lhs: &Expr, rhs: &Expr) -> bool {
+    match (lhs, rhs) {
+        (Expr::Not(negated), other) | (other, Expr::Not(negated)) => **negated == *other,
+        _ => false,
+    }
+}
+
+/// Appends the operands of the chain of `&&` (`conjunction`) or `||` rooted at `expr`.
+fn flatten(expr: Expr, conjunction: bool, operands: &mut Vec<Expr>) {
+    match expr {
+        Expr::And(lhs, rhs) if conjunction => {
+            flatten(*lhs, conjunction, operands);
+            flatten(*rhs, conjunction, operands);
+        }
+        Expr::Or(lhs, rhs) if !conjunction => {
+            flatten(*lhs, conjunction, operands);
+            flatten(*rhs, conjunction, operands);
+        }
+        Expr::Var(_)
+        | Expr::Const(_)
+        | Expr::Not(_)
+        | Expr::And(..)
+        | Expr::Or(..)
+        | Expr::Xor(..)
+        | Expr::Implies(..)
+        | Expr::Iff(..) => operands.push(expr),
+    }
+}
+
+/// Simplifies `lhs && rhs` (`conjunction`) or `lhs || rhs`, whose operands are already
+/// simplified, treating the whole chain of like operators as a single n-ary operator.
+#[expect(
+    clippy::single_call_fn,
+    reason = "the flattening of `&&` and `||` chains is too long to inline into `simplify_once`"
+)]
+fn simplify_junction(conjunction: bool, lhs: Expr, rhs: Expr) -> Expr {
+    let mut flattened = Vec::new();
+    flatten(lhs, conjunction, &mut flattened);
+    flatten(rhs, conjunction, &mut flattened);
+
+    // Identity elements vanish; an absorbing element absorbs the whole chain
+    let mut operands = Vec::<Expr>::new();
+    for operand in flattened {
+        match operand {
+            Expr::Const(value) if value == conjunction => (),
+            Expr::Const(_) => return Expr::Const(!conjunction),
+            Expr::Var(_)
+            | Expr::Not(_)
+            | Expr::And(..)
+            | Expr::Or(..)
+            | Expr::Xor(..)
+            | Expr::Implies(..)
+            | Expr::Iff(..) => {
+                if !operands.contains(&operand) {
+                    operands.push(operand);
+                }
+            }
+        }
+    }
+    if operands
+        .iter()
+        .any(|first| operands.iter().any(|second| complementary(first, second)))
+    {
+        return Expr::Const(!conjunction);
+    }
+
+    // Absorption: `a && (a || b)` => `a`, and `a && (!a || b)` => `a && b`
+    operands
+        .iter()
+        .enumerate()
+        .filter_map(|(index, operand)| {
+            let mut inner = Vec::new();
+            flatten(operand.clone(), !conjunction, &mut inner);
+            let others = || {
+                operands
+                    .iter()
+                    .enumerate()
+                    .filter(move |&(other, _)| other != index)
+                    .map(|(_, other)| other)
+            };
+            match inner.len() > 1 && inner.iter().any(|term| others().any(|other| other == term)) {
+                true => None,
+                false => Some(
+                    inner
+                        .iter()
+                        .filter(|term| !others().any(|other| complementary(other, term)))
+                        .cloned()
+                        .reduce(|joined, next| junction(!conjunction, joined, next))
+                        .unwrap_or(Expr::Const(!conjunction)),
+                ),
+            }
+        })
+        .reduce(|joined, next| junction(conjunction, joined, next))
+        .unwrap_or(Expr::Const(conjunction))
+}
@@ -0,0 +1,498 @@
+#![expect(
+    clippy::arbitrary_source_item_ordering,
+    reason = "each type precedes its impls, and fields and methods follow the table's layout, from its columns to its rows"
+)]
+#![expect(
+    clippy::question_mark_used,
+    reason = "the tables and differences are written cell by cell"
+)]
+use super::Expr;
+use crate::BoolVec;
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    string::{String, ToString as _},
+    vec::Vec,
+};
+use core::fmt::{Display, Formatter, Result as FmtResult};
+
+/// Order in which a [`TruthTable`] enumerates the assignments of its variables.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[expect(
+    clippy::exhaustive_enums,
+    reason = "the orders are the usual two for enumerating assignments"
+)]
+pub enum RowOrder {
+    /// Counts in binary, the first variable being the most significant.
+    #[default]
+    Binary,
+    /// Reflected binary Gray code; consecutive rows differ in exactly one variable.
+    Gray,
+}
+
+impl RowOrder {
+    /// Returns the assignment (one bit per variable, the first variable most significant) shown in
+    /// row `position`.
+    #[inline]
+    const fn assignment(self, position: usize) -> usize {
+        match self {
+            Self::Binary => position,
+            Self::Gray => position ^ (position >> 1),
+        }
+    }
+}
+
+#[expect(
+    clippy::multiple_inherent_impl,
+    reason = "the truth table methods are kept beside `TruthTable`"
+)]
+impl Expr {
+    /// ## Returns the truth table of the expression, enumerating its variables in sorted order
+    /// and their assignments in binary order.
+    /// ### Panics
+    /// Panics if the expression has `usize::BITS` or more distinct variables.
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::expr::Expr;
+    ///
+    /// let table = "a && !b".parse::<Expr>().unwrap().truth_table();
+    ///
+    /// assert!(table.rows().map(|row| row.output()).eq([false, false, true, false]));
+    /// assert!(table.to_string() == "\
+    /// a b | a && !b
+    /// ----+--------
+    /// F F | F
+    /// F T | F
+    /// T F | T
+    /// T T | F
+    /// ");
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn truth_table(&self) -> TruthTable {
+        self.truth_table_with(RowOrder::Binary)
+    }
+
+    /// ## Returns the truth table of the expression, enumerating its variables in sorted order
+    /// and their assignments in the given `order`.
+    /// ### Panics
+    /// Panics if the expression has `usize::BITS` or more distinct variables.
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::expr::{Expr, RowOrder};
+    ///
+    /// let table = "a ^ b".parse::<Expr>().unwrap().truth_table_with(RowOrder::Gray);
+    ///
+    /// assert!(table.rows().map(|row| row.output()).eq([false, true, false, true]));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn truth_table_with(&self, order: RowOrder) -> TruthTable {
+        let variables = self
+            .variables()
+            .into_iter()
+            .map(String::from)
+            .collect::<Vec<_>>();
+        let outputs = assignments(variables.len())
+            .map(|assignment| {
+                self.eval(&|name: &str| {
+                    variables
+                        .iter()
+                        .position(|variable| variable == name)
+                        .map(|index| bit(assignment, variables.len(), index))
+                })
+                .unwrap_or_default()
+            })
+            .collect();
+        TruthTable {
+            header: self.to_string(),
+            variables,
+            outputs,
+            order,
+        }
+    }
+}
+
+/// Returns an iterator over every assignment of `count` variables, in binary order.
+#[expect(
+    clippy::panic,
+    reason = "the rows of such a table could not be counted, let alone held"
+)]
+pub fn assignments(count: usize) -> impl Iterator<Item = usize> {
+    let rows = u32::try_from(count)
+        .ok()
+        .and_then(|bits| usize::checked_shl(1, bits))
+        .unwrap_or_else(|| panic!("too many variables for a truth table: {count}"));
+    0..rows
+}
+
+/// Returns the value of variable `index` of `count` in `assignment` (the first variable being the
+/// most significant bit).
+#[inline]
+pub fn bit(assignment: usize, count: usize, index: usize) -> bool {
+    count
+        .checked_sub(index)
+        .and_then(|shift| shift.checked_sub(1))
+        .and_then(|shift| u32::try_from(shift).ok())
+        .and_then(|shift| assignment.checked_shr(shift))
+        .is_some_and(|bits| bits & 1 == 1)
+}
+
+/// `TruthTable` records the value of an [`Expr`] under every assignment of its variables.
+///
+/// `Display` renders an aligned plain-text table; [`to_markdown`](Self::to_markdown) and
+/// [`to_csv`](Self::to_csv) render for documents and spreadsheets.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TruthTable {
+    /// The rendered expression, heading the output column.
+    header: String,
+    /// The variables, first variable most significant.
+    variables: Vec<String>,
+    /// Indexed by assignment (in binary order), regardless of `order`.
+    outputs: BoolVec,
+    /// The order in which the rows are enumerated.
+    order: RowOrder,
+}
+
+impl TruthTable {
+    /// ## Returns the variable names, in column order
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::expr::Expr;
+    ///
+    /// let table = "b || a".parse::<Expr>().unwrap().truth_table();
+    ///
+    /// assert!(table.variables() == ["a", "b"]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn variables(&self) -> &[String] {
+        &self.variables
+    }
+
+    /// ## Returns the number of rows
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::expr::Expr;
+    ///
+    /// assert!("a -> b".parse::<Expr>().unwrap().truth_table().len() == 4);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.outputs.len()
+    }
+
+    /// ## Transforms zero-row table => `true`, otherwise `false` (a table always has at least one
+    /// row, so this is always `false`).
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::expr::Expr;
+    ///
+    /// assert!(!Expr::from(true).truth_table().is_empty());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.outputs.is_empty()
+    }
+
+    /// ## Returns an iterator over the rows, in the table's [`RowOrder`]
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::expr::Expr;
+    ///
+    /// let table = "a || b".parse::<Expr>().unwrap().truth_table();
+    /// let row = table.rows().nth(1).unwrap();
+    ///
+    /// assert!(row.inputs().eq([false, true]));
+    /// assert!(row.value("b") == Some(true));
+    /// assert!(row.output());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn rows(&self) -> impl ExactSizeIterator<Item = Row<'_>> {
+        (0..self.len()).map(|position| {
+            let assignment = self.order.assignment(position);
+            Row {
+                table: self,
+                assignment,
+                output: self.outputs.get(assignment).unwrap_or_default(),
+            }
+        })
+    }
+
+    /// ## Lists the assignments under which `self` and `other` differ
+    ///
+    /// The tables may be over different variables; each assignment of the union of their variables
+    /// is compared, in binary order.
+    /// ### Panics
+    /// Panics if the union of the tables' variables has `usize::BITS` or more members.
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::expr::Expr;
+    ///
+    /// let intended = "a && b".parse::<Expr>().unwrap().truth_table();
+    /// let written = "a || b".parse::<Expr>().unwrap().truth_table();
+    /// let diff = intended.diff(&written);
+    ///
+    /// assert!(diff.len() == 2);
+    /// assert!(diff[0].to_string() == "a = false, b = true: false != true");
+    /// assert!(intended.diff(&intended).is_empty());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn diff(&self, other: &Self) -> Vec<Difference> {
+        let variables = self
+            .variables
+            .iter()
+            .chain(&other.variables)
+            .map(String::as_str)
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+        assignments(variables.len())
+            .filter_map(|assignment| {
+                let value = |name: &str| {
+                    variables
+                        .iter()
+                        .position(|variable| *variable == name)
+                        .is_some_and(|index| bit(assignment, variables.len(), index))
+                };
+                let lhs = self.output_under(value);
+                let rhs = other.output_under(value);
+                (lhs != rhs).then(|| Difference {
+                    assignment: variables
+                        .iter()
+                        .map(|&name| (String::from(name), value(name)))
+                        .collect(),
+                    lhs,
+                    rhs,
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the output under the assignment giving each variable the value `value(name)`.
+    pub(crate) fn output_under(&self, value: impl Fn(&str) -> bool) -> bool {
+        let assignment: usize = self.variables.iter().fold(0, |assignment, name| {
+            assignment.wrapping_shl(1) | usize::from(value(name))
+        });
+        self.outputs.get(assignment).unwrap_or_default()
+    }
+
+    /// ## Renders the table as GitHub-flavored Markdown
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::expr::Expr;
+    ///
+    /// let table = "!a || b".parse::<Expr>().unwrap().truth_table();
+    ///
+    /// assert!(table.to_markdown() == "\
+    /// | a | b | !a \\|\\| b |
+    /// |---|---|---|
+    /// | F | F | T |
+    /// | F | T | T |
+    /// | T | F | F |
+    /// | T | T | T |
+    /// ");
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn to_markdown(&self) -> String {
+        let mut markdown = String::new();
+        for header in self.headers() {
+            markdown.push_str("| ");
+            markdown.push_str(&header.replace('|', "\\|"));
+            markdown.push(' ');
+        }
+        markdown.push_str("|\n");
+        markdown.push_str(&"|---".repeat(self.variables.len().saturating_add(1)));
+        markdown.push_str("|\n");
+        for row in self.rows() {
+            for value in row.inputs().chain([row.output()]) {
+                markdown.push_str("| ");
+                markdown.push_str(symbol(value));
+                markdown.push(' ');
+            }
+            markdown.push_str("|\n");
+        }
+        markdown
+    }
+
+    /// ## Renders the table as comma-separated values, with a header row
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::expr::Expr;
+    ///
+    /// let table = "!a".parse::<Expr>().unwrap().truth_table();
+    ///
+    /// assert!(table.to_csv() == "a,!a\nfalse,true\ntrue,false\n");
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn to_csv(&self) -> String {
+        let mut csv = self
+            .headers()
+            .map(|header| match header.contains([',', '"', '\n']) {
+                true => alloc::format!("\"{}\"", header.replace('"', "\"\"")),
+                false => String::from(header),
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+        csv.push('\n');
+        for row in self.rows() {
+            let values = row
+                .inputs()
+                .chain([row.output()])
+                .map(|value| value.to_string())
+                .collect::<Vec<_>>();
+            csv.push_str(&values.join(","));
+            csv.push('\n');
+        }
+        csv
+    }
+
+    /// Returns the column headings: the variables, then the expression.
+    fn headers(&self) -> impl Iterator<Item = &str> {
+        self.variables
+            .iter()
+            .map(String::as_str)
+            .chain([self.header.as_str()])
+    }
+}
+
+/// Renders `value` as a single-character table cell.
+#[inline]
+const fn symbol(value: bool) -> &'static str {
+    match value {
+        true => "T",
+        false => "F",
+    }
+}
+
+/// Renders an aligned plain-text table, with the inputs separated from the output by `|`.
+impl Display for TruthTable {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let widths = self
+            .headers()
+            .map(|header| header.chars().count())
+            .collect::<Vec<_>>();
+        let (output_width, input_widths) = widths.split_last().unwrap_or((&0, &[]));
+        let write_row = |out: &mut Formatter<'_>, cells: &mut dyn Iterator<Item = &str>| {
+            // Zip the widths first, so that the output cell is not consumed
+            for (width, cell) in input_widths.iter().zip(&mut *cells) {
+                write!(out, "{cell:width$} ")?;
+            }
+            writeln!(out, "| {}", cells.next().unwrap_or_default())
+        };
+        write_row(f, &mut self.headers())?;
+        for width in input_widths {
+            write!(f, "{}", "-".repeat(width.saturating_add(1)))?;
+        }
+        writeln!(f, "+{}", "-".repeat(output_width.saturating_add(1)))?;
+        for row in self.rows() {
+            write_row(f, &mut row.inputs().chain([row.output()]).map(symbol))?;
+        }
+        Ok(())
+    }
+}
+
+/// A single row of a [`TruthTable`].
+#[derive(Clone, Copy, Debug)]
+pub struct Row<'table> {
+    /// The table the row belongs to.
+    table: &'table TruthTable,
+    /// The row's assignment, one bit per variable.
+    assignment: usize,
+    /// The expression's value under the assignment.
+    output: bool,
+}
+
+impl Row<'_> {
+    /// Returns the value of each variable in this row, in column order.
+    #[inline]
+    pub fn inputs(&self) -> impl Iterator<Item = bool> + '_ {
+        let count = self.table.variables.len();
+        (0..count).map(move |index| bit(self.assignment, count, index))
+    }
+
+    /// Returns the value of the variable `name` in this row, or `None` if the table has no such
+    /// variable.
+    #[must_use]
+    #[inline]
+    pub fn value(&self, name: &str) -> Option<bool> {
+        let count = self.table.variables.len();
+        self.table
+            .variables
+            .iter()
+            .position(|variable| variable == name)
+            .map(|index| bit(self.assignment, count, index))
+    }
+
+    /// Returns the value of the expression in this row.
+    #[must_use]
+    #[inline]
+    pub const fn output(&self) -> bool {
+        self.output
+    }
+}
+
+/// An assignment under which two [`TruthTable`]s differ.  See [`TruthTable::diff`].
+///
+/// The assignment is a `BTreeMap`, and so is itself an [`Env`](super::Env) against which either
+/// expression may be re-evaluated.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Difference {
+    /// The values of the variables.
+    assignment: BTreeMap<String, bool>,
+    /// The value of the left-hand table's expression.
+    lhs: bool,
+    /// The value of the right-hand table's expression.
+    rhs: bool,
+}
+
+impl Difference {
+    /// Value of each variable, keyed by name.
+    #[must_use]
+    #[inline]
+    pub const fn assignment(&self) -> &BTreeMap<String, bool> {
+        &self.assignment
+    }
+
+    /// Output of the table on which [`TruthTable::diff`] was called.
+    #[must_use]
+    #[inline]
+    pub const fn lhs(&self) -> bool {
+        self.lhs
+    }
+
+    /// Output of the table passed to [`TruthTable::diff`].
+    #[must_use]
+    #[inline]
+    pub const fn rhs(&self) -> bool {
+        self.rhs
+    }
+}
+
+impl Display for Difference {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        for (index, (name, value)) in self.assignment.iter().enumerate() {
+            if index != 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{name} = {value}")?;
+        }
+        write!(f, ": {} != {}", self.lhs, self.rhs)
+    }
+}
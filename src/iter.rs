@@ -0,0 +1,308 @@
+//! Boolean combinators over iterators of `bool`.
+//!
+//! [`BoolIterExt`] reduces an `Iterator<Item = bool>` to a single `bool` (short-circuiting
+//! wherever the result is already decided) or to the index of the deciding element.
+
+use crate::BoolExt as _;
+use core::{
+    iter::{Enumerate, FusedIterator, Peekable},
+    ops::Range,
+};
+
+/// `BoolIterExt` trait defines reductions and adapters over iterators of `bool`.
+#[expect(
+    clippy::arbitrary_source_item_ordering,
+    reason = "reductions come first, from the quantifiers to counting, then searches and adapters"
+)]
+pub trait BoolIterExt: Iterator<Item = bool> + Sized {
+    /// ## Transforms all `true` (or empty) => `true`, otherwise `false` (short-circuiting)
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::BoolIterExt;
+    ///
+    /// assert!([true, true].into_iter().all_true());
+    /// assert!(![true, false].into_iter().all_true());
+    /// ```
+    #[inline]
+    fn all_true(mut self) -> bool {
+        self.all(|value| value)
+    }
+
+    /// ## Transforms any `true` => `true`, otherwise `false` (short-circuiting)
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::BoolIterExt;
+    ///
+    /// assert!([false, true].into_iter().any_true());
+    /// assert!(![false, false].into_iter().any_true());
+    /// ```
+    #[inline]
+    fn any_true(mut self) -> bool {
+        self.any(|value| value)
+    }
+
+    /// ## Transforms no `true` (or empty) => `true`, otherwise `false` (short-circuiting)
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::BoolIterExt;
+    ///
+    /// assert!([false, false].into_iter().none_true());
+    /// assert!(![false, true].into_iter().none_true());
+    /// ```
+    #[inline]
+    fn none_true(self) -> bool {
+        !self.any_true()
+    }
+
+    /// ## Returns the number of `true` values
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::BoolIterExt;
+    ///
+    /// assert!([true, false, true].into_iter().count_true() == 2);
+    /// ```
+    #[inline]
+    fn count_true(self) -> usize {
+        self.filter(|value| *value).count()
+    }
+
+    /// ## Transforms exactly one `true` => `true`, otherwise `false` (short-circuiting on the
+    /// second `true`).
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::BoolIterExt;
+    ///
+    /// assert!([false, true, false].into_iter().exactly_one());
+    /// assert!(![true, true, false].into_iter().exactly_one());
+    /// assert!(![false, false].into_iter().exactly_one());
+    /// ```
+    #[inline]
+    fn exactly_one(self) -> bool {
+        let mut trues = self.filter(|value| *value);
+        trues.next().is_some() && trues.next().is_none()
+    }
+
+    /// ## Transforms at most `n` `true`s => `true`, otherwise `false` (short-circuiting on the
+    /// `n + 1`th `true`).
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::BoolIterExt;
+    ///
+    /// assert!([true, false, true].into_iter().at_most(2));
+    /// assert!(![true, true, true].into_iter().at_most(2));
+    /// ```
+    #[inline]
+    fn at_most(self, n: usize) -> bool {
+        self.filter(|value| *value).nth(n).is_none()
+    }
+
+    /// ## Transforms an odd number of `true`s => `true`, otherwise `false` (XOR of all values)
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::BoolIterExt;
+    ///
+    /// assert!([true, true, true].into_iter().parity());
+    /// assert!(![true, false, true].into_iter().parity());
+    /// ```
+    #[inline]
+    fn parity(self) -> bool {
+        self.fold(false, |parity, value| parity ^ value)
+    }
+
+    /// ## Transforms strictly more `true`s than `false`s => `true`, otherwise `false`
+    ///
+    /// Consumes the whole iterator, counting the values as it goes: the outcome is never taken
+    /// from [`size_hint`](Iterator::size_hint), which safe code may implement wrongly.
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::BoolIterExt;
+    ///
+    /// assert!([true, false, true].into_iter().majority());
+    /// assert!(![true, false].into_iter().majority());
+    /// ```
+    #[inline]
+    fn majority(self) -> bool {
+        let (trues, falses) = self.fold((0, 0), |(trues, falses): (usize, usize), value| {
+            value.map(
+                (trues, falses.saturating_add(1)),
+                (trues.saturating_add(1), falses),
+            )
+        });
+        trues > falses
+    }
+
+    /// ## Returns the index of the first `true` value, if any
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::BoolIterExt;
+    ///
+    /// assert!([false, true].into_iter().first_true() == Some(1));
+    /// assert!([false, false].into_iter().first_true() == None);
+    /// ```
+    #[inline]
+    fn first_true(mut self) -> Option<usize> {
+        self.position(|value| value)
+    }
+
+    /// ## Returns the index of the first `false` value, if any
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::BoolIterExt;
+    ///
+    /// assert!([true, false].into_iter().first_false() == Some(1));
+    /// assert!([true, true].into_iter().first_false() == None);
+    /// ```
+    #[inline]
+    fn first_false(mut self) -> Option<usize> {
+        self.position(|value| !value)
+    }
+
+    /// ## Transforms all `true` => `Ok(())`, otherwise `Err(index of first false)`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::BoolIterExt;
+    ///
+    /// assert!([true, true].into_iter().to_result() == Ok(()));
+    /// assert!([true, false, false].into_iter().to_result() == Err(1));
+    /// ```
+    #[inline]
+    fn to_result(self) -> Result<(), usize> {
+        self.first_false().map_or(Ok(()), Err)
+    }
+
+    /// ## Transforms all `true` => `Ok(())`, otherwise `Err(E)` built from the index of the first
+    /// `false`, lazily evaluated.
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::BoolIterExt;
+    ///
+    /// let checks = [true, false];
+    ///
+    /// assert!(checks.into_iter().or_err_with(|i| format!("check {i} failed"))
+    ///     == Err(String::from("check 1 failed")));
+    /// ```
+    #[inline]
+    fn or_err_with<F: FnOnce(usize) -> E, E>(self, err: F) -> Result<(), E> {
+        self.first_false().map_or(Ok(()), |index| Err(err(index)))
+    }
+
+    /// ## Transforms no `true` => `Ok(())`, otherwise `Err(E)` built from the index of the first
+    /// `true`, lazily evaluated.
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::BoolIterExt;
+    ///
+    /// let conflicts = [false, false, true];
+    ///
+    /// assert!(conflicts.into_iter().and_err_with(|i| i) == Err(2));
+    /// ```
+    #[inline]
+    fn and_err_with<F: FnOnce(usize) -> E, E>(self, err: F) -> Result<(), E> {
+        self.first_true().map_or(Ok(()), |index| Err(err(index)))
+    }
+
+    /// ## Returns an iterator over the indices of the `true` values
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::BoolIterExt;
+    ///
+    /// assert!([true, false, true].into_iter().true_indices().eq([0, 2]));
+    /// ```
+    #[inline]
+    fn true_indices(self) -> TrueIndices<Self> {
+        TrueIndices {
+            iter: self.enumerate(),
+        }
+    }
+
+    /// ## Returns an iterator over the maximal runs of equal values, as `(value, index range)`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::BoolIterExt;
+    ///
+    /// let runs = [true, true, false, true].into_iter().runs();
+    ///
+    /// assert!(runs.eq([(true, 0..2), (false, 2..3), (true, 3..4)]));
+    /// ```
+    #[inline]
+    fn runs(self) -> Runs<Self> {
+        Runs {
+            iter: self.enumerate().peekable(),
+        }
+    }
+}
+
+#[expect(clippy::missing_trait_methods, reason = "the methods are all provided")]
+impl<I: Iterator<Item = bool>> BoolIterExt for I {}
+
+/// Iterator over the indices of the `true` values of an iterator of `bool`.  See
+/// [`BoolIterExt::true_indices`].
+#[derive(Clone, Debug)]
+pub struct TrueIndices<I> {
+    /// The underlying iterator, with indices.
+    iter: Enumerate<I>,
+}
+
+#[expect(
+    clippy::missing_trait_methods,
+    reason = "the provided methods are built on `next`"
+)]
+impl<I: Iterator<Item = bool>> Iterator for TrueIndices<I> {
+    type Item = usize;
+
+    #[inline]
+    fn next(&mut self) -> Option<usize> {
+        self.iter.find(|&(_, value)| value).map(|(index, _)| index)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<I: FusedIterator<Item = bool>> FusedIterator for TrueIndices<I> {}
+
+/// Iterator over the maximal runs of equal values of an iterator of `bool`.  See
+/// [`BoolIterExt::runs`].
+#[derive(Clone, Debug)]
+pub struct Runs<I: Iterator<Item = bool>> {
+    /// The underlying iterator, with indices.
+    iter: Peekable<Enumerate<I>>,
+}
+
+#[expect(
+    clippy::missing_trait_methods,
+    reason = "the provided methods are built on `next`"
+)]
+impl<I: Iterator<Item = bool>> Iterator for Runs<I> {
+    type Item = (bool, Range<usize>);
+
+    #[inline]
+    fn next(&mut self) -> Option<(bool, Range<usize>)> {
+        self.iter.next().map(|(start, value)| {
+            let mut last = start;
+            while let Some((index, _)) = self.iter.next_if(|&(_, next)| next == value) {
+                last = index;
+            }
+            (value, start..last.saturating_add(1))
+        })
+    }
+}
+
+impl<I: FusedIterator<Item = bool>> FusedIterator for Runs<I> {}
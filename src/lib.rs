@@ -4,7 +4,7 @@
     clippy::pedantic,
     clippy::float_cmp_const,
     clippy::indexing_slicing,
-    clippy::integer_arithmetic,
+    clippy::arithmetic_side_effects,
     clippy::unwrap_used
 )]
 #![allow(
@@ -22,14 +22,120 @@
 #![allow(clippy::blanket_clippy_restriction_lints)]
 #![warn(clippy::cargo, clippy::restriction, missing_docs, warnings)]
 #![allow(clippy::implicit_return, clippy::semicolon_if_nothing_returned)]
+#![expect(
+    clippy::negative_feature_names,
+    reason = "`no-panic` is named for the methods it removes"
+)]
 //! `bool_ext` is a crate which defines and implements a complete set of Boolean functional
 //! combinators.  See this crate's `README.md` for more background.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use core::ops::Not;
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+/// Decomposed assertions, behind `bool_assert!` and its siblings.
+#[cfg(feature = "assert")]
+mod assertion;
+/// Asynchronous counterparts of the side-effecting combinators.
+#[cfg(feature = "async")]
+mod async_ext;
+#[cfg(feature = "alloc")]
+pub mod bdd;
+pub mod bits;
+/// Accumulation of named checks into a list of failures.
+#[cfg(feature = "alloc")]
+mod checks;
+/// Errors produced by the fallible combinators.
+mod error;
+#[cfg(feature = "alloc")]
+pub mod expr;
+pub mod iter;
+/// Lifting of `Option<bool>` and `Result<bool, E>` into the combinators.
+mod lift;
+#[cfg(feature = "alloc")]
+pub mod mcdc;
+#[cfg(feature = "alloc")]
+pub mod minimize;
+pub mod predicate;
+#[cfg(feature = "alloc")]
+pub mod sat;
+/// Three-valued logic.
+mod tribool;
+/// Validation of values against a set of rules.
+#[cfg(feature = "alloc")]
+mod validate;
+
+// The modules are private, or grouped for documentation, so their items are re-exported here.
+// `useless_attribute` misfires on the `pub_use` expectations: lint attributes on `use` items are
+// honoured, but it only recognises those for a fixed few lints.
+#[cfg(feature = "async")]
+#[expect(
+    clippy::pub_use,
+    clippy::useless_attribute,
+    reason = "`AsyncBoolExt` belongs beside `BoolExt` at the crate root"
+)]
+pub use async_ext::AsyncBoolExt;
+#[cfg(feature = "derive")]
+#[expect(
+    clippy::pub_use,
+    clippy::useless_attribute,
+    reason = "the derives share the paths of their traits"
+)]
+pub use bool_ext_derive::{BoolExt, Validate};
+#[cfg(feature = "assert")]
+#[expect(
+    clippy::pub_use,
+    clippy::useless_attribute,
+    reason = "the assertion types belong beside their macros at the crate root"
+)]
+pub use {
+    assertion::{AssertionFailure, Operand},
+    bool_ext_derive::bool_expect,
+};
+#[expect(
+    clippy::pub_use,
+    clippy::useless_attribute,
+    reason = "the crate root is the public API"
+)]
+pub use {
+    bits::BoolArray,
+    error::{FalseError, TrueError},
+    iter::BoolIterExt,
+    lift::{OptionBoolExt, ResultBoolExt},
+    predicate::{Predicate, PredicateExt},
+    tribool::Tribool,
+};
+#[cfg(feature = "alloc")]
+#[expect(
+    clippy::pub_use,
+    clippy::useless_attribute,
+    reason = "the crate root is the public API, with or without `alloc`"
+)]
+pub use {
+    bits::BoolVec,
+    checks::{Checks, Failure, Failures, Scope},
+    validate::Validate,
+};
+
+// Used by the expansions of `bool_assert!` and `bool_expect!`; not public API
+#[cfg(feature = "assert")]
+#[doc(hidden)]
+#[expect(
+    clippy::pub_use,
+    clippy::useless_attribute,
+    reason = "macro expansions need public paths to these"
+)]
+pub use {alloc::format as __format, assertion::Recorder as __Recorder};
+
+#[cfg(not(feature = "no-panic"))]
+use core::{fmt::Display, ops::Not};
 
 /// `BoolExt` trait defines and implements a complete set of Boolean functional combinators.
+#[expect(
+    clippy::arbitrary_source_item_ordering,
+    reason = "the combinators are grouped by the type they produce"
+)]
 pub trait BoolExt {
     /// # Boolean to `Option` (`bool` => `Option<T>`) adapters
 
@@ -118,11 +224,11 @@ pub trait BoolExt {
     fn some_with<F: FnOnce() -> T, T>(self, some: F) -> Option<T>;
 
     /// `bool` => `Result<T, E>`
-    /// ## Transforms `true` => `Ok(())`, `false` => `Err(())`
+    /// ## Transforms `true` => `Ok(())`, `false` => `Err(FalseError)`
     /// ### Examples:
     /// ```
     /// use assert2::assert;
-    /// use bool_ext::BoolExt;
+    /// use bool_ext::{BoolExt, FalseError};
     ///
     /// let vec = vec![1, 2, 3];
     ///
@@ -130,14 +236,14 @@ pub trait BoolExt {
     /// ```
     /// ```
     /// use assert2::assert;
-    /// use bool_ext::BoolExt;
+    /// use bool_ext::{BoolExt, FalseError};
     ///
     /// let vec = vec![1, 2, 3];
     ///
-    /// assert!(vec.contains(&4).to_result() == Err(()));
+    /// assert!(vec.contains(&4).to_result() == Err(FalseError::new()));
     /// ```
-    #[allow(clippy::wrong_self_convention, clippy::result_unit_err)]
-    fn to_result(self) -> Result<(), ()>;
+    #[allow(clippy::wrong_self_convention)]
+    fn to_result(self) -> Result<(), FalseError>;
 
     /// ## Transforms `true` => `Some(T)`, `false` => `None`
     /// ### Examples:
@@ -218,7 +324,7 @@ pub trait BoolExt {
     /// assert!(vec.contains(&4).or_some(Foo) == Some(Foo));
     /// ```
     #[allow(clippy::result_unit_err)]
-    fn or_some<T>(self, none: T) -> Option<T>;
+    fn or_some<T>(self, some: T) -> Option<T>;
 
     /// ## Transforms `true` => `None`, `false` => `Some(T)`
     /// ### Examples:
@@ -247,11 +353,11 @@ pub trait BoolExt {
     #[allow(clippy::result_unit_err)]
     fn or_some_with<F: FnOnce() -> T, T>(self, some: F) -> Option<T>;
 
-    /// ## Transforms `true` => `Ok(T)`, `false` => `Err(())`
+    /// ## Transforms `true` => `Ok(T)`, `false` => `Err(FalseError)`
     /// ### Examples:
     /// ```
     /// use assert2::assert;
-    /// use bool_ext::BoolExt;
+    /// use bool_ext::{BoolExt, FalseError};
     ///
     /// #[derive(Debug, PartialEq)]
     /// struct Foo;
@@ -262,23 +368,22 @@ pub trait BoolExt {
     /// ```
     /// ```
     /// use assert2::assert;
-    /// use bool_ext::BoolExt;
+    /// use bool_ext::{BoolExt, FalseError};
     ///
     /// #[derive(Debug, PartialEq)]
     /// struct Foo;
     ///
     /// let vec = vec![1, 2, 3];
     ///
-    /// assert!(vec.contains(&4).and_ok(Foo) == Err(()));
+    /// assert!(vec.contains(&4).and_ok(Foo) == Err(FalseError::new()));
     /// ```
-    #[allow(clippy::result_unit_err)]
-    fn and_ok<T>(self, ok: T) -> Result<T, ()>;
+    fn and_ok<T>(self, ok: T) -> Result<T, FalseError>;
 
-    /// ## Transforms `true` => `Ok(T)`, `false` => `Err(())`, lazily evaluated
+    /// ## Transforms `true` => `Ok(T)`, `false` => `Err(FalseError)`, lazily evaluated
     /// ### Examples:
     /// ```
     /// use assert2::assert;
-    /// use bool_ext::BoolExt;
+    /// use bool_ext::{BoolExt, FalseError};
     ///
     /// #[derive(Debug, PartialEq)]
     /// struct Foo;
@@ -294,7 +399,7 @@ pub trait BoolExt {
     /// ```
     /// ```
     /// use assert2::assert;
-    /// use bool_ext::BoolExt;
+    /// use bool_ext::{BoolExt, FalseError};
     ///
     /// #[derive(Debug, PartialEq)]
     /// struct Foo;
@@ -307,16 +412,15 @@ pub trait BoolExt {
     /// let vec = vec![1, 2, 3];
     ///
     /// // elide `expensive_computation()`
-    /// assert!(vec.is_empty().and_ok_with(|| expensive_computation()) == Err(()));
+    /// assert!(vec.is_empty().and_ok_with(|| expensive_computation()) == Err(FalseError::new()));
     /// ```
-    #[allow(clippy::result_unit_err)]
-    fn and_ok_with<F: FnOnce() -> T, T>(self, ok: F) -> Result<T, ()>;
+    fn and_ok_with<F: FnOnce() -> T, T>(self, ok: F) -> Result<T, FalseError>;
 
-    /// ## Transforms `true` => `Err(())`, `false` => `Ok(T)`
+    /// ## Transforms `true` => `Err(TrueError)`, `false` => `Ok(T)`
     /// ### Examples:
     /// ```
     /// use assert2::assert;
-    /// use bool_ext::BoolExt;
+    /// use bool_ext::{BoolExt, TrueError};
     ///
     /// #[derive(Debug, PartialEq)]
     /// struct Foo;
@@ -327,35 +431,33 @@ pub trait BoolExt {
     /// ```
     /// ```
     /// use assert2::assert;
-    /// use bool_ext::BoolExt;
+    /// use bool_ext::{BoolExt, TrueError};
     ///
     /// #[derive(Debug, PartialEq)]
     /// struct Foo;
     ///
     /// let vec = vec![1, 2, 3];
     ///
-    /// assert!(vec.contains(&2).or_ok(Foo) == Err(()));
+    /// assert!(vec.contains(&2).or_ok(Foo) == Err(TrueError::new()));
     /// ```
+    fn or_ok<T>(self, ok: T) -> Result<T, TrueError>;
 
-    #[allow(clippy::result_unit_err)]
-    fn or_ok<T>(self, ok: T) -> Result<T, ()>;
-
-    /// ## Transforms `true` => `Err(())`, `false` => `Ok(T)`, lazily evaluated
+    /// ## Transforms `true` => `Err(TrueError)`, `false` => `Ok(T)`, lazily evaluated
     /// ### Examples:
     /// ```
     /// use assert2::assert;
-    /// use bool_ext::BoolExt;
+    /// use bool_ext::{BoolExt, TrueError};
     ///
     /// #[derive(Debug, PartialEq)]
     /// struct Foo;
     ///
     /// let event_list = vec![1, 2, 3];
     ///
-    /// assert!(event_list.contains(&2).or_ok(Foo) == Err(()));
+    /// assert!(event_list.contains(&2).or_ok(Foo) == Err(TrueError::new()));
     /// ```
     /// ```
     /// use assert2::assert;
-    /// use bool_ext::BoolExt;
+    /// use bool_ext::{BoolExt, TrueError};
     ///
     /// #[derive(Debug, PartialEq)]
     /// struct Foo;
@@ -370,8 +472,7 @@ pub trait BoolExt {
     /// // elide `expensive_computation()`
     /// assert!(event_list.contains(&4).or_ok_with(|| expensive_computation()) == Ok(Foo));
     /// ```
-    #[allow(clippy::result_unit_err)]
-    fn or_ok_with<F: FnOnce() -> T, T>(self, ok: F) -> Result<T, ()>;
+    fn or_ok_with<F: FnOnce() -> T, T>(self, ok: F) -> Result<T, TrueError>;
 
     /// ## Transforms `true` => `Err(E)`, `false` => `Ok(())`
     /// ### Examples:
@@ -789,6 +890,47 @@ pub trait BoolExt {
     /// ```
     fn or_try_do<F: FnOnce() -> Result<(), E>, E>(self, f: F) -> Result<bool, E>;
 
+    /// ## Transforms `true` => `Ok(())`, `false` => `Err(FalseError)`
+    ///
+    /// The panic-free counterpart of `expect`, for stating a condition which must hold.  With the
+    /// `no-panic` feature (for builds which must be shown to contain no panic paths), the `expect`
+    /// family is removed and `must` and [`must_not`](BoolExt::must_not) are the means of
+    /// asserting a condition.
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::{BoolExt, FalseError};
+    ///
+    /// fn arm(interlocks_closed: bool) -> Result<(), FalseError> {
+    ///     interlocks_closed.must()?;
+    ///     // ...
+    ///     Ok(())
+    /// }
+    ///
+    /// assert!(arm(true) == Ok(()));
+    /// assert!(arm(false) == Err(FalseError::new()));
+    /// ```
+    fn must(self) -> Result<(), FalseError>;
+
+    /// ## Transforms `true` => `Err(TrueError)`, `false` => `Ok(())`
+    ///
+    /// The panic-free counterpart of `expect_false`, for stating a condition which must not hold.
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::{BoolExt, TrueError};
+    ///
+    /// fn arm(fault_latched: bool) -> Result<(), TrueError> {
+    ///     fault_latched.must_not()?;
+    ///     // ...
+    ///     Ok(())
+    /// }
+    ///
+    /// assert!(arm(false) == Ok(()));
+    /// assert!(arm(true) == Err(TrueError::new()));
+    /// ```
+    fn must_not(self) -> Result<(), TrueError>;
+
     /// ## Transforms `false` => `panic!()`
     /// ## panic with message if `false`, otherwise do nothing
     /// ### Examples:    
@@ -815,6 +957,7 @@ pub trait BoolExt {
     /// // Panicked
     /// assert!(res.is_err());
     /// ```
+    #[cfg(not(feature = "no-panic"))]
     fn expect(self, msg: &str);
     /// ## Transforms `true` => `panic!()`
     /// ## panic with message if `true`, otherwise do nothing
@@ -847,11 +990,115 @@ pub trait BoolExt {
     /// // Panicked
     /// assert!(res.is_err());
     /// ```
+    #[cfg(not(feature = "no-panic"))]
     fn expect_false(self, msg: &str);
+
+    /// ## Transforms `false` => `panic!()`
+    /// ## panic with the lazily built message `msg()` if `false`, otherwise do nothing
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::BoolExt;
+    ///
+    /// let vec = vec![1, 2, 3];
+    ///
+    /// // `format!` runs only on failure
+    /// vec.contains(&2).expect_with(|| format!("{vec:?} should contain 2"));
+    ///
+    /// let res = std::panic::catch_unwind(|| {
+    ///     vec.contains(&4).expect_with(|| format!("{vec:?} should contain 4"));
+    /// });
+    /// assert!(*res.unwrap_err().downcast::<String>().unwrap() == "[1, 2, 3] should contain 4");
+    /// ```
+    /// The panic is reported at the caller's location (as for the whole `expect` family):
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::BoolExt;
+    /// use std::{panic, sync::Mutex};
+    ///
+    /// static LINE: Mutex<Option<u32>> = Mutex::new(None);
+    /// panic::set_hook(Box::new(|info| {
+    ///     *LINE.lock().unwrap() = info.location().map(|location| location.line());
+    /// }));
+    ///
+    /// let (res, line) = (panic::catch_unwind(|| false.expect_with(|| "failed")), line!());
+    /// let _ = panic::take_hook();
+    ///
+    /// assert!(res.is_err());
+    /// assert!(*LINE.lock().unwrap() == Some(line));
+    /// ```
+    #[cfg(not(feature = "no-panic"))]
+    fn expect_with<F: FnOnce() -> M, M: Display>(self, msg: F);
+
+    /// ## Transforms `true` => `panic!()`
+    /// ## panic with the lazily built message `msg()` if `true`, otherwise do nothing
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::BoolExt;
+    ///
+    /// let values_already_seen = vec![1, 2, 3, 42];
+    ///
+    /// values_already_seen
+    ///     .contains(&7)
+    ///     .expect_false_with(|| format!("7 already seen in {values_already_seen:?}"));
+    ///
+    /// let res = std::panic::catch_unwind(|| {
+    ///     values_already_seen
+    ///         .contains(&42)
+    ///         .expect_false_with(|| format!("42 already seen in {values_already_seen:?}"));
+    /// });
+    /// assert!(res.is_err());
+    /// ```
+    #[cfg(not(feature = "no-panic"))]
+    fn expect_false_with<F: FnOnce() -> M, M: Display>(self, msg: F);
+
+    /// ## Transforms `false` => `panic!()` when debug assertions are enabled
+    /// ## panic with message if `false` and `debug_assertions` is on, otherwise do nothing
+    ///
+    /// Like `debug_assert!`, the check compiles away when `debug_assertions` is off (as in a
+    /// default release build).  Unlike `debug_assert!`, which is expanded in the calling crate,
+    /// the setting is the one this crate was built with, which is the calling crate's under
+    /// Cargo's standard profiles.
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::BoolExt;
+    ///
+    /// let vec = vec![1, 2, 3];
+    /// vec.contains(&2).debug_expect("vec should contain 2");
+    ///
+    /// // Panics in a debug build; does nothing in a release build
+    /// let _ = std::panic::catch_unwind(|| vec.contains(&4).debug_expect("vec should contain 4"));
+    /// ```
+    #[cfg(not(feature = "no-panic"))]
+    fn debug_expect(self, msg: &str);
+
+    /// ## Transforms `true` => `panic!()` when debug assertions are enabled
+    /// ## panic with message if `true` and `debug_assertions` is on, otherwise do nothing
+    ///
+    /// As with [`debug_expect`](BoolExt::debug_expect), the check compiles away when
+    /// `debug_assertions` is off.
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::BoolExt;
+    ///
+    /// let vec = vec![1, 2, 3];
+    /// vec.contains(&4).debug_expect_false("vec should not contain 4");
+    ///
+    /// // Panics in a debug build; does nothing in a release build
+    /// let _ = std::panic::catch_unwind(|| {
+    ///     vec.contains(&2).debug_expect_false("vec should not contain 2")
+    /// });
+    /// ```
+    #[cfg(not(feature = "no-panic"))]
+    fn debug_expect_false(self, msg: &str);
 }
 
 // Suppress clippy::use_self warning arising from use of `panic!()`
 #[allow(clippy::use_self)]
+#[expect(clippy::arbitrary_source_item_ordering, reason = "as in the trait")]
 impl BoolExt for bool {
     #[inline]
     fn to_option(self) -> Option<()> {
@@ -878,10 +1125,11 @@ impl BoolExt for bool {
     }
 
     #[inline]
-    fn to_result(self) -> Result<(), ()> {
+    #[track_caller]
+    fn to_result(self) -> Result<(), FalseError> {
         match self {
             true => Ok(()),
-            false => Err(()),
+            false => Err(FalseError::new()),
         }
     }
 
@@ -900,18 +1148,20 @@ impl BoolExt for bool {
     }
 
     #[inline]
-    fn and_ok<T>(self, ok: T) -> Result<T, ()> {
+    #[track_caller]
+    fn and_ok<T>(self, ok: T) -> Result<T, FalseError> {
         match self {
             true => Ok(ok),
-            false => Err(()),
+            false => Err(FalseError::new()),
         }
     }
 
     #[inline]
-    fn and_ok_with<F: FnOnce() -> T, T>(self, ok: F) -> Result<T, ()> {
+    #[track_caller]
+    fn and_ok_with<F: FnOnce() -> T, T>(self, ok: F) -> Result<T, FalseError> {
         match self {
             true => Ok(ok()),
-            false => Err(()),
+            false => Err(FalseError::new()),
         }
     }
 
@@ -930,17 +1180,19 @@ impl BoolExt for bool {
     }
 
     #[inline]
-    fn or_ok<T>(self, ok: T) -> Result<T, ()> {
+    #[track_caller]
+    fn or_ok<T>(self, ok: T) -> Result<T, TrueError> {
         match self {
-            true => Err(()),
+            true => Err(TrueError::new()),
             false => Ok(ok),
         }
     }
 
     #[inline]
-    fn or_ok_with<F: FnOnce() -> T, T>(self, ok: F) -> Result<T, ()> {
+    #[track_caller]
+    fn or_ok_with<F: FnOnce() -> T, T>(self, ok: F) -> Result<T, TrueError> {
         match self {
-            true => Err(()),
+            true => Err(TrueError::new()),
             false => Ok(ok()),
         }
     }
@@ -1057,16 +1309,192 @@ impl BoolExt for bool {
     }
 
     #[inline]
+    #[track_caller]
+    fn must(self) -> Result<(), FalseError> {
+        self.to_result()
+    }
+
+    #[inline]
+    #[track_caller]
+    fn must_not(self) -> Result<(), TrueError> {
+        self.or_ok(())
+    }
+
+    #[cfg(not(feature = "no-panic"))]
+    #[inline]
+    #[track_caller]
     fn expect(self, msg: &str) {
-        #[allow(clippy::panic)]
+        self.expect_with(|| msg)
+    }
+
+    #[cfg(not(feature = "no-panic"))]
+    #[inline]
+    #[track_caller]
+    fn expect_false(self, msg: &str) {
+        self.not().expect(msg)
+    }
+
+    #[cfg(not(feature = "no-panic"))]
+    #[inline]
+    #[track_caller]
+    fn expect_with<F: FnOnce() -> M, M: Display>(self, msg: F) {
+        #[expect(
+            clippy::panic,
+            reason = "panicking on `false` is the purpose of the method"
+        )]
         match self {
             true => (),
-            false => panic!("{}", msg),
+            false => panic!("{}", msg()),
         }
     }
 
+    #[cfg(not(feature = "no-panic"))]
     #[inline]
-    fn expect_false(self, msg: &str) {
-        self.not().expect(msg)
+    #[track_caller]
+    fn expect_false_with<F: FnOnce() -> M, M: Display>(self, msg: F) {
+        self.not().expect_with(msg)
     }
+
+    #[cfg(not(feature = "no-panic"))]
+    #[inline]
+    #[track_caller]
+    fn debug_expect(self, msg: &str) {
+        if cfg!(debug_assertions) {
+            self.expect(msg);
+        }
+    }
+
+    #[cfg(not(feature = "no-panic"))]
+    #[inline]
+    #[track_caller]
+    fn debug_expect_false(self, msg: &str) {
+        if cfg!(debug_assertions) {
+            self.expect_false(msg);
+        }
+    }
+}
+
+/// Implements [`BoolExt`] for `$ty` by converting to `bool` (through `From<$ty> for bool`) and
+/// delegating.  Used by the expansion of `#[derive(BoolExt)]`; not public API.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_bool_ext {
+    ($ty:ty) => {
+        impl $crate::BoolExt for $ty {
+            $crate::__impl_bool_ext!(@delegate
+                fn to_option(self) -> Option<()> => to_option();
+                fn some<T>(self, some: T) -> Option<T> => some(some);
+                fn some_with<F: FnOnce() -> T, T>(self, some: F) -> Option<T> => some_with(some);
+                #[track_caller]
+                fn to_result(self) -> Result<(), $crate::FalseError> => to_result();
+                fn and_some<T>(self, some: T) -> Option<T> => and_some(some);
+                fn and_some_with<F: FnOnce() -> T, T>(self, some: F) -> Option<T>
+                    => and_some_with(some);
+                #[track_caller]
+                fn and_ok<T>(self, ok: T) -> Result<T, $crate::FalseError> => and_ok(ok);
+                #[track_caller]
+                fn and_ok_with<F: FnOnce() -> T, T>(self, ok: F) -> Result<T, $crate::FalseError>
+                    => and_ok_with(ok);
+                fn or_some<T>(self, some: T) -> Option<T> => or_some(some);
+                fn or_some_with<F: FnOnce() -> T, T>(self, some: F) -> Option<T>
+                    => or_some_with(some);
+                #[track_caller]
+                fn or_ok<T>(self, ok: T) -> Result<T, $crate::TrueError> => or_ok(ok);
+                #[track_caller]
+                fn or_ok_with<F: FnOnce() -> T, T>(self, ok: F) -> Result<T, $crate::TrueError>
+                    => or_ok_with(ok);
+                fn and_err<E>(self, err: E) -> Result<(), E> => and_err(err);
+                fn and_err_with<F: FnOnce() -> E, E>(self, err: F) -> Result<(), E>
+                    => and_err_with(err);
+                fn or_err<E>(self, err: E) -> Result<(), E> => or_err(err);
+                fn or_err_with<F: FnOnce() -> E, E>(self, err: F) -> Result<(), E>
+                    => or_err_with(err);
+                fn ok_or_err<T, E>(self, err: E, ok: T) -> Result<T, E> => ok_or_err(err, ok);
+                fn ok_or_err_with<F: FnOnce() -> T, G: FnOnce() -> E, T, E>(self, err: G, ok: F)
+                    -> Result<T, E> => ok_or_err_with(err, ok);
+                fn map<T>(self, f: T, t: T) -> T => map(f, t);
+                fn map_or<F: FnOnce() -> T, T>(self, f: T, t: F) -> T => map_or(f, t);
+                fn map_or_default<F: FnOnce() -> T, T: Default>(self, t: F) -> T
+                    => map_or_default(t);
+                fn map_or_else<F: FnOnce() -> T, G: FnOnce() -> T, T>(self, f: G, t: F) -> T
+                    => map_or_else(f, t);
+                fn and_do<F: FnOnce()>(self, t: F) -> bool => and_do(t);
+                fn or_do<F: FnOnce()>(self, f: F) -> bool => or_do(f);
+                fn and_try_do<F: FnOnce() -> Result<(), E>, E>(self, t: F) -> Result<bool, E>
+                    => and_try_do(t);
+                fn or_try_do<F: FnOnce() -> Result<(), E>, E>(self, f: F) -> Result<bool, E>
+                    => or_try_do(f);
+                #[track_caller]
+                fn must(self) -> Result<(), $crate::FalseError> => must();
+                #[track_caller]
+                fn must_not(self) -> Result<(), $crate::TrueError> => must_not();
+            );
+            $crate::__impl_bool_ext_expect!();
+        }
+    };
+    (@delegate $(
+        $(#[$attr:meta])*
+        fn $method:ident$(<$($param:ident$(: $bound:path)?),+>)?(self$(, $arg:ident: $arg_ty:ty)*)
+            -> $ret:ty => $call:ident($($forward:ident),*);
+    )*) => {$(
+        #[inline]
+        $(#[$attr])*
+        fn $method$(<$($param$(: $bound)?),+>)?(self$(, $arg: $arg_ty)*) -> $ret {
+            $crate::BoolExt::$call(::core::convert::Into::<bool>::into(self)$(, $forward)*)
+        }
+    )*};
+}
+
+/// Implements the panicking `expect` family of [`BoolExt`] for the expansion of
+/// [`__impl_bool_ext!`]; without the `no-panic` feature.  Not public API.
+#[cfg(not(feature = "no-panic"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_bool_ext_expect {
+    () => {
+        #[inline]
+        #[track_caller]
+        fn expect(self, msg: &str) {
+            $crate::BoolExt::expect(::core::convert::Into::<bool>::into(self), msg)
+        }
+
+        #[inline]
+        #[track_caller]
+        fn expect_false(self, msg: &str) {
+            $crate::BoolExt::expect_false(::core::convert::Into::<bool>::into(self), msg)
+        }
+
+        #[inline]
+        #[track_caller]
+        fn expect_with<F: FnOnce() -> M, M: ::core::fmt::Display>(self, msg: F) {
+            $crate::BoolExt::expect_with(::core::convert::Into::<bool>::into(self), msg)
+        }
+
+        #[inline]
+        #[track_caller]
+        fn expect_false_with<F: FnOnce() -> M, M: ::core::fmt::Display>(self, msg: F) {
+            $crate::BoolExt::expect_false_with(::core::convert::Into::<bool>::into(self), msg)
+        }
+
+        #[inline]
+        #[track_caller]
+        fn debug_expect(self, msg: &str) {
+            $crate::BoolExt::debug_expect(::core::convert::Into::<bool>::into(self), msg)
+        }
+
+        #[inline]
+        #[track_caller]
+        fn debug_expect_false(self, msg: &str) {
+            $crate::BoolExt::debug_expect_false(::core::convert::Into::<bool>::into(self), msg)
+        }
+    };
+}
+
+/// With the `no-panic` feature, [`BoolExt`] has no `expect` family to implement.  Not public
+/// API.
+#[cfg(feature = "no-panic")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_bool_ext_expect {
+    () => {};
 }
@@ -0,0 +1,1285 @@
+use crate::{BoolExt, FalseError, TrueError};
+#[cfg(not(feature = "no-panic"))]
+use core::{fmt::Display, ops::Not};
+
+/// `ResultBoolExt` lifts the [`BoolExt`] combinators over `Result<bool, E>`.
+///
+/// This lets combinator chains continue after a fallible step such as
+/// [`BoolExt::and_try_do`].  `Ok(bool)` is transformed exactly as [`BoolExt`] would transform the
+/// `bool`; `Err(E)` is carried through untouched.
+///
+/// Where the [`BoolExt`] combinator itself produces a `Result` with the same error type (e.g.
+/// [`or_err`](ResultBoolExt::or_err)), the result is flattened into a single `Result<_, E>`.
+/// Combinators producing a [`FalseError`] or [`TrueError`] are flattened in the same way, provided
+/// `E` can be constructed from it.
+///
+/// `map`, `map_or`, `map_or_default`, `map_or_else` and `expect` are named `bool_map`,
+/// `bool_map_or`, `bool_map_or_default`, `bool_map_or_else` and `bool_expect`, as the inherent
+/// methods of `Result` would otherwise shadow them (that of `map_or_default` once it is
+/// stabilised).
+#[expect(
+    clippy::arbitrary_source_item_ordering,
+    reason = "the combinators are grouped as in `BoolExt`"
+)]
+pub trait ResultBoolExt<E> {
+    /// ## Transforms `Ok(true)` => `Ok(Some(()))`, `Ok(false)` => `Ok(None)`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::ResultBoolExt;
+    ///
+    /// assert!(Ok::<_, ()>(true).to_option() == Ok(Some(())));
+    /// assert!(Err::<bool, _>(42).to_option() == Err(42));
+    /// ```
+    fn to_option(self) -> Result<Option<()>, E>;
+
+    /// ## Transforms `Ok(true)` => `Ok(Some(T))`, `Ok(false)` => `Ok(None)`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::ResultBoolExt;
+    ///
+    /// assert!(Ok::<_, ()>(true).some(42) == Ok(Some(42)));
+    /// assert!(Ok::<_, ()>(false).some(42) == Ok(None));
+    /// ```
+    fn some<T>(self, some: T) -> Result<Option<T>, E>;
+
+    /// ## Transforms `Ok(true)` => `Ok(Some(T))`, `Ok(false)` => `Ok(None)`, lazily evaluated
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::ResultBoolExt;
+    ///
+    /// assert!(Ok::<_, ()>(true).some_with(|| 42) == Ok(Some(42)));
+    /// assert!(Err::<bool, _>("err").some_with(|| 42) == Err("err"));
+    /// ```
+    fn some_with<F: FnOnce() -> T, T>(self, some: F) -> Result<Option<T>, E>;
+
+    /// ## Transforms `Ok(true)` => `Ok(())`, `Ok(false)` => `Err(E::from(FalseError))`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::{FalseError, ResultBoolExt};
+    ///
+    /// assert!(Ok::<_, FalseError>(true).to_result() == Ok(()));
+    /// assert!(Ok::<_, FalseError>(false).to_result() == Err(FalseError::new()));
+    /// ```
+    fn to_result(self) -> Result<(), E>
+    where
+        E: From<FalseError>;
+
+    /// ## Transforms `Ok(true)` => `Ok(Some(T))`, `Ok(false)` => `Ok(None)`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::ResultBoolExt;
+    ///
+    /// assert!(Ok::<_, ()>(true).and_some(42) == Ok(Some(42)));
+    /// assert!(Ok::<_, ()>(false).and_some(42) == Ok(None));
+    /// ```
+    fn and_some<T>(self, some: T) -> Result<Option<T>, E>;
+
+    /// ## Transforms `Ok(true)` => `Ok(Some(T))`, `Ok(false)` => `Ok(None)`, lazily evaluated
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::ResultBoolExt;
+    ///
+    /// assert!(Ok::<_, ()>(true).and_some_with(|| 42) == Ok(Some(42)));
+    /// assert!(Ok::<_, ()>(false).and_some_with(|| 42) == Ok(None));
+    /// ```
+    fn and_some_with<F: FnOnce() -> T, T>(self, some: F) -> Result<Option<T>, E>;
+
+    /// ## Transforms `Ok(true)` => `Ok(None)`, `Ok(false)` => `Ok(Some(T))`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::ResultBoolExt;
+    ///
+    /// assert!(Ok::<_, ()>(true).or_some(42) == Ok(None));
+    /// assert!(Ok::<_, ()>(false).or_some(42) == Ok(Some(42)));
+    /// ```
+    fn or_some<T>(self, some: T) -> Result<Option<T>, E>;
+
+    /// ## Transforms `Ok(true)` => `Ok(None)`, `Ok(false)` => `Ok(Some(T))`, lazily evaluated
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::ResultBoolExt;
+    ///
+    /// assert!(Ok::<_, ()>(true).or_some_with(|| 42) == Ok(None));
+    /// assert!(Ok::<_, ()>(false).or_some_with(|| 42) == Ok(Some(42)));
+    /// ```
+    fn or_some_with<F: FnOnce() -> T, T>(self, some: F) -> Result<Option<T>, E>;
+
+    /// ## Transforms `Ok(true)` => `Ok(T)`, `Ok(false)` => `Err(E::from(FalseError))`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::{FalseError, ResultBoolExt};
+    ///
+    /// assert!(Ok::<_, FalseError>(true).and_ok(42) == Ok(42));
+    /// assert!(Ok::<_, FalseError>(false).and_ok(42) == Err(FalseError::new()));
+    /// ```
+    fn and_ok<T>(self, ok: T) -> Result<T, E>
+    where
+        E: From<FalseError>;
+
+    /// ## Transforms `Ok(true)` => `Ok(T)`, `Ok(false)` => `Err(E::from(FalseError))`, lazily
+    /// evaluated.
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::{FalseError, ResultBoolExt};
+    ///
+    /// assert!(Ok::<_, FalseError>(true).and_ok_with(|| 42) == Ok(42));
+    /// assert!(Ok::<_, FalseError>(false).and_ok_with(|| 42) == Err(FalseError::new()));
+    /// ```
+    fn and_ok_with<F: FnOnce() -> T, T>(self, ok: F) -> Result<T, E>
+    where
+        E: From<FalseError>;
+
+    /// ## Transforms `Ok(true)` => `Err(E::from(TrueError))`, `Ok(false)` => `Ok(T)`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::{ResultBoolExt, TrueError};
+    ///
+    /// assert!(Ok::<_, TrueError>(true).or_ok(42) == Err(TrueError::new()));
+    /// assert!(Ok::<_, TrueError>(false).or_ok(42) == Ok(42));
+    /// ```
+    fn or_ok<T>(self, ok: T) -> Result<T, E>
+    where
+        E: From<TrueError>;
+
+    /// ## Transforms `Ok(true)` => `Err(E::from(TrueError))`, `Ok(false)` => `Ok(T)`, lazily
+    /// evaluated.
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::{ResultBoolExt, TrueError};
+    ///
+    /// assert!(Ok::<_, TrueError>(true).or_ok_with(|| 42) == Err(TrueError::new()));
+    /// assert!(Ok::<_, TrueError>(false).or_ok_with(|| 42) == Ok(42));
+    /// ```
+    fn or_ok_with<F: FnOnce() -> T, T>(self, ok: F) -> Result<T, E>
+    where
+        E: From<TrueError>;
+
+    /// ## Transforms `Ok(true)` => `Err(E)`, `Ok(false)` => `Ok(())`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::ResultBoolExt;
+    ///
+    /// assert!(Ok(true).and_err("found") == Err("found"));
+    /// assert!(Ok(false).and_err("found") == Ok(()));
+    /// ```
+    fn and_err(self, err: E) -> Result<(), E>;
+
+    /// ## Transforms `Ok(true)` => `Err(E)`, `Ok(false)` => `Ok(())`, lazily evaluated
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::ResultBoolExt;
+    ///
+    /// assert!(Ok(true).and_err_with(|| "found") == Err("found"));
+    /// assert!(Err("earlier").and_err_with(|| "found") == Err("earlier"));
+    /// ```
+    fn and_err_with<F: FnOnce() -> E>(self, err: F) -> Result<(), E>;
+
+    /// ## Transforms `Ok(true)` => `Ok(())`, `Ok(false)` => `Err(E)`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::ResultBoolExt;
+    ///
+    /// assert!(Ok(true).or_err("missing") == Ok(()));
+    /// assert!(Ok(false).or_err("missing") == Err("missing"));
+    /// ```
+    fn or_err(self, err: E) -> Result<(), E>;
+
+    /// ## Transforms `Ok(true)` => `Ok(())`, `Ok(false)` => `Err(E)`, lazily evaluated
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::ResultBoolExt;
+    ///
+    /// assert!(Ok(false).or_err_with(|| "missing") == Err("missing"));
+    /// assert!(Err("earlier").or_err_with(|| "missing") == Err("earlier"));
+    /// ```
+    fn or_err_with<F: FnOnce() -> E>(self, err: F) -> Result<(), E>;
+
+    /// ## Transforms `Ok(true)` => `Ok(T)`, `Ok(false)` => `Err(E)`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::ResultBoolExt;
+    ///
+    /// assert!(Ok(true).ok_or_err("missing", 42) == Ok(42));
+    /// assert!(Ok(false).ok_or_err("missing", 42) == Err("missing"));
+    /// ```
+    fn ok_or_err<T>(self, err: E, ok: T) -> Result<T, E>;
+
+    /// ## Transforms `Ok(true)` => `Ok(T)`, `Ok(false)` => `Err(E)`, lazily evaluated
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::ResultBoolExt;
+    ///
+    /// assert!(Ok(true).ok_or_err_with(|| "missing", || 42) == Ok(42));
+    /// assert!(Ok(false).ok_or_err_with(|| "missing", || 42) == Err("missing"));
+    /// ```
+    fn ok_or_err_with<F: FnOnce() -> T, G: FnOnce() -> E, T>(self, err: G, ok: F) -> Result<T, E>;
+
+    /// ## Transforms `Ok(true)` => `Ok(T)`, `Ok(false)` => `Ok(T)`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::ResultBoolExt;
+    ///
+    /// assert!(Ok::<_, ()>(true).bool_map("no", "yes") == Ok("yes"));
+    /// assert!(Ok::<_, ()>(false).bool_map("no", "yes") == Ok("no"));
+    /// ```
+    fn bool_map<T>(self, f: T, t: T) -> Result<T, E>;
+
+    /// ## Transforms `Ok(true)` => `Ok(T)`, `Ok(false)` => `Ok(T)`, lazily evaluated if `true`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::ResultBoolExt;
+    ///
+    /// assert!(Ok::<_, ()>(true).bool_map_or(0, || 42) == Ok(42));
+    /// assert!(Ok::<_, ()>(false).bool_map_or(0, || 42) == Ok(0));
+    /// ```
+    fn bool_map_or<F: FnOnce() -> T, T>(self, f: T, t: F) -> Result<T, E>;
+
+    /// ## Transforms `Ok(true)` => `Ok(T)`, `Ok(false)` => `Ok(T::default())`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::ResultBoolExt;
+    ///
+    /// assert!(Ok::<_, ()>(true).bool_map_or_default(|| 42) == Ok(42));
+    /// assert!(Ok::<_, ()>(false).bool_map_or_default(|| 42) == Ok(0));
+    /// ```
+    fn bool_map_or_default<F: FnOnce() -> T, T: Default>(self, t: F) -> Result<T, E>;
+
+    /// ## Transforms `Ok(true)` => `Ok(T)`, `Ok(false)` => `Ok(T)`, lazily evaluated
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::ResultBoolExt;
+    ///
+    /// assert!(Ok::<_, ()>(true).bool_map_or_else(|| "out", || "in") == Ok("in"));
+    /// assert!(Ok::<_, ()>(false).bool_map_or_else(|| "out", || "in") == Ok("out"));
+    /// ```
+    fn bool_map_or_else<F: FnOnce() -> T, G: FnOnce() -> T, T>(self, f: G, t: F) -> Result<T, E>;
+
+    /// ## Perform side-effect if `Ok(true)`, otherwise do nothing
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::{BoolExt, ResultBoolExt};
+    ///
+    /// let mut vec = vec![1, 2, 3];
+    /// let res = vec
+    ///     .contains(&2)
+    ///     .and_try_do(|| Ok::<_, ()>(()))
+    ///     .and_do(|| vec.push(4));
+    ///
+    /// assert!(res == Ok(true));
+    /// assert!(vec == [1, 2, 3, 4]);
+    /// ```
+    fn and_do<F: FnOnce()>(self, t: F) -> Result<bool, E>;
+
+    /// ## Perform side-effect if `Ok(false)`, otherwise do nothing
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::ResultBoolExt;
+    ///
+    /// let mut vec = vec![1, 2, 3];
+    /// let res = Ok::<_, ()>(vec.contains(&4)).or_do(|| vec.push(4));
+    ///
+    /// assert!(res == Ok(false));
+    /// assert!(vec == [1, 2, 3, 4]);
+    /// ```
+    fn or_do<F: FnOnce()>(self, f: F) -> Result<bool, E>;
+
+    /// ## Perform fallible side-effect if `Ok(true)`, otherwise do nothing
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::{BoolExt, ResultBoolExt};
+    ///
+    /// let mut vec = vec![1, 2, 3];
+    /// let res = vec
+    ///     .contains(&2)
+    ///     .and_try_do(|| Ok(()))
+    ///     .and_try_do(|| Err("failed"))
+    ///     .and_do(|| vec.push(4));
+    ///
+    /// assert!(res == Err("failed"));
+    /// assert!(vec == [1, 2, 3]);
+    /// ```
+    fn and_try_do<F: FnOnce() -> Result<(), E>>(self, t: F) -> Result<bool, E>;
+
+    /// ## Perform fallible side-effect if `Ok(false)`, otherwise do nothing
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::ResultBoolExt;
+    ///
+    /// let mut vec = vec![1, 2, 3];
+    /// let res = Ok::<_, ()>(vec.contains(&4)).or_try_do(|| {
+    ///     vec.push(4);
+    ///     Ok(())
+    /// });
+    ///
+    /// assert!(res == Ok(false));
+    /// assert!(vec == [1, 2, 3, 4]);
+    /// ```
+    fn or_try_do<F: FnOnce() -> Result<(), E>>(self, f: F) -> Result<bool, E>;
+
+    /// ## Transforms `Ok(true)` => `Ok(())`, `Ok(false)` => `Err(E::from(FalseError))`
+    ///
+    /// The panic-free counterpart of `bool_expect`; see [`BoolExt::must`].
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::{FalseError, ResultBoolExt};
+    ///
+    /// assert!(Ok::<_, FalseError>(true).must() == Ok(()));
+    /// assert!(Ok::<_, FalseError>(false).must() == Err(FalseError::new()));
+    /// ```
+    fn must(self) -> Result<(), E>
+    where
+        E: From<FalseError>;
+
+    /// ## Transforms `Ok(true)` => `Err(E::from(TrueError))`, `Ok(false)` => `Ok(())`
+    ///
+    /// The panic-free counterpart of `expect_false`; see [`BoolExt::must_not`].
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::{ResultBoolExt, TrueError};
+    ///
+    /// assert!(Ok::<_, TrueError>(false).must_not() == Ok(()));
+    /// assert!(Ok::<_, TrueError>(true).must_not() == Err(TrueError::new()));
+    /// ```
+    fn must_not(self) -> Result<(), E>
+    where
+        E: From<TrueError>;
+
+    /// ## Transforms `Ok(false)` => `panic!()`
+    /// ## panic with message if `Ok(false)`, otherwise pass `Err(E)` through
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::ResultBoolExt;
+    ///
+    /// assert!(Ok::<_, ()>(true).bool_expect("expected `true`") == Ok(()));
+    /// assert!(Err::<bool, _>(42).bool_expect("expected `true`") == Err(42));
+    /// assert!(std::panic::catch_unwind(|| Ok::<_, ()>(false).bool_expect("expected `true`"))
+    ///     .is_err());
+    /// ```
+    #[cfg(not(feature = "no-panic"))]
+    fn bool_expect(self, msg: &str) -> Result<(), E>;
+
+    /// ## Transforms `Ok(true)` => `panic!()`
+    /// ## panic with message if `Ok(true)`, otherwise pass `Err(E)` through
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::ResultBoolExt;
+    ///
+    /// assert!(Ok::<_, ()>(false).expect_false("expected `false`") == Ok(()));
+    /// assert!(std::panic::catch_unwind(|| Ok::<_, ()>(true).expect_false("expected `false`"))
+    ///     .is_err());
+    /// ```
+    #[cfg(not(feature = "no-panic"))]
+    fn expect_false(self, msg: &str) -> Result<(), E>;
+
+    /// ## Transforms `Ok(false)` => `panic!()`
+    /// ## panic with the message returned by `msg` if `Ok(false)` (calling `msg` only then),
+    /// otherwise pass `Err(E)` through.
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::ResultBoolExt;
+    ///
+    /// assert!(Ok::<_, ()>(true).bool_expect_with(|| format!("expected `{}`", true)) == Ok(()));
+    /// assert!(Err::<bool, _>(42).bool_expect_with(|| "expected `true`") == Err(42));
+    /// let res = std::panic::catch_unwind(|| {
+    ///     Ok::<_, ()>(false).bool_expect_with(|| "expected `true`")
+    /// });
+    /// assert!(res.is_err());
+    /// ```
+    #[cfg(not(feature = "no-panic"))]
+    fn bool_expect_with<F: FnOnce() -> M, M: Display>(self, msg: F) -> Result<(), E>;
+
+    /// ## Transforms `Ok(true)` => `panic!()`
+    /// ## panic with the message returned by `msg` if `Ok(true)` (calling `msg` only then),
+    /// otherwise pass `Err(E)` through.
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::ResultBoolExt;
+    ///
+    /// assert!(Ok::<_, ()>(false).expect_false_with(|| "expected `false`") == Ok(()));
+    /// let res = std::panic::catch_unwind(|| {
+    ///     Ok::<_, ()>(true).expect_false_with(|| "expected `false`")
+    /// });
+    /// assert!(res.is_err());
+    /// ```
+    #[cfg(not(feature = "no-panic"))]
+    fn expect_false_with<F: FnOnce() -> M, M: Display>(self, msg: F) -> Result<(), E>;
+}
+
+#[expect(
+    clippy::arbitrary_source_item_ordering,
+    clippy::question_mark_used,
+    reason = "ordered as in the trait; `?` carries the `Err` past the combinator"
+)]
+impl<E> ResultBoolExt<E> for Result<bool, E> {
+    #[inline]
+    fn to_option(self) -> Result<Option<()>, E> {
+        self.map(BoolExt::to_option)
+    }
+
+    #[inline]
+    fn some<T>(self, some: T) -> Result<Option<T>, E> {
+        self.map(|flag| flag.some(some))
+    }
+
+    #[inline]
+    fn some_with<F: FnOnce() -> T, T>(self, some: F) -> Result<Option<T>, E> {
+        self.map(|flag| flag.some_with(some))
+    }
+
+    #[inline]
+    #[track_caller]
+    fn to_result(self) -> Result<(), E>
+    where
+        E: From<FalseError>,
+    {
+        self.and_ok(())
+    }
+
+    #[inline]
+    fn and_some<T>(self, some: T) -> Result<Option<T>, E> {
+        self.map(|flag| flag.and_some(some))
+    }
+
+    #[inline]
+    fn and_some_with<F: FnOnce() -> T, T>(self, some: F) -> Result<Option<T>, E> {
+        self.map(|flag| flag.and_some_with(some))
+    }
+
+    #[inline]
+    fn or_some<T>(self, some: T) -> Result<Option<T>, E> {
+        self.map(|flag| flag.or_some(some))
+    }
+
+    #[inline]
+    fn or_some_with<F: FnOnce() -> T, T>(self, some: F) -> Result<Option<T>, E> {
+        self.map(|flag| flag.or_some_with(some))
+    }
+
+    #[inline]
+    #[track_caller]
+    fn and_ok<T>(self, ok: T) -> Result<T, E>
+    where
+        E: From<FalseError>,
+    {
+        self.and_ok_with(|| ok)
+    }
+
+    #[inline]
+    #[track_caller]
+    fn and_ok_with<F: FnOnce() -> T, T>(self, ok: F) -> Result<T, E>
+    where
+        E: From<FalseError>,
+    {
+        // `match` rather than `and_then` so that `#[track_caller]` reaches `FalseError::new()`
+        match self {
+            Ok(true) => Ok(ok()),
+            Ok(false) => Err(FalseError::new().into()),
+            Err(err) => Err(err),
+        }
+    }
+
+    #[inline]
+    #[track_caller]
+    fn or_ok<T>(self, ok: T) -> Result<T, E>
+    where
+        E: From<TrueError>,
+    {
+        self.or_ok_with(|| ok)
+    }
+
+    #[inline]
+    #[track_caller]
+    fn or_ok_with<F: FnOnce() -> T, T>(self, ok: F) -> Result<T, E>
+    where
+        E: From<TrueError>,
+    {
+        match self {
+            Ok(true) => Err(TrueError::new().into()),
+            Ok(false) => Ok(ok()),
+            Err(err) => Err(err),
+        }
+    }
+
+    #[inline]
+    fn and_err(self, err: E) -> Result<(), E> {
+        self?.and_err(err)
+    }
+
+    #[inline]
+    fn and_err_with<F: FnOnce() -> E>(self, err: F) -> Result<(), E> {
+        self?.and_err_with(err)
+    }
+
+    #[inline]
+    fn or_err(self, err: E) -> Result<(), E> {
+        self?.or_err(err)
+    }
+
+    #[inline]
+    fn or_err_with<F: FnOnce() -> E>(self, err: F) -> Result<(), E> {
+        self?.or_err_with(err)
+    }
+
+    #[inline]
+    fn ok_or_err<T>(self, err: E, ok: T) -> Result<T, E> {
+        self?.ok_or_err(err, ok)
+    }
+
+    #[inline]
+    fn ok_or_err_with<F: FnOnce() -> T, G: FnOnce() -> E, T>(self, err: G, ok: F) -> Result<T, E> {
+        self?.ok_or_err_with(err, ok)
+    }
+
+    #[inline]
+    fn bool_map<T>(self, f: T, t: T) -> Result<T, E> {
+        self.map(|flag| BoolExt::map(flag, f, t))
+    }
+
+    #[inline]
+    fn bool_map_or<F: FnOnce() -> T, T>(self, f: T, t: F) -> Result<T, E> {
+        self.map(|flag| BoolExt::map_or(flag, f, t))
+    }
+
+    #[inline]
+    fn bool_map_or_default<F: FnOnce() -> T, T: Default>(self, t: F) -> Result<T, E> {
+        self.map(|flag| flag.map_or_default(t))
+    }
+
+    #[inline]
+    fn bool_map_or_else<F: FnOnce() -> T, G: FnOnce() -> T, T>(self, f: G, t: F) -> Result<T, E> {
+        self.map(|flag| BoolExt::map_or_else(flag, f, t))
+    }
+
+    #[inline]
+    fn and_do<F: FnOnce()>(self, t: F) -> Self {
+        self.map(|flag| flag.and_do(t))
+    }
+
+    #[inline]
+    fn or_do<F: FnOnce()>(self, f: F) -> Self {
+        self.map(|flag| flag.or_do(f))
+    }
+
+    #[inline]
+    fn and_try_do<F: FnOnce() -> Result<(), E>>(self, t: F) -> Self {
+        self?.and_try_do(t)
+    }
+
+    #[inline]
+    fn or_try_do<F: FnOnce() -> Result<(), E>>(self, f: F) -> Self {
+        self?.or_try_do(f)
+    }
+
+    #[inline]
+    #[track_caller]
+    fn must(self) -> Result<(), E>
+    where
+        E: From<FalseError>,
+    {
+        self.to_result()
+    }
+
+    #[inline]
+    #[track_caller]
+    fn must_not(self) -> Result<(), E>
+    where
+        E: From<TrueError>,
+    {
+        self.or_ok(())
+    }
+
+    #[cfg(not(feature = "no-panic"))]
+    #[inline]
+    #[track_caller]
+    fn bool_expect(self, msg: &str) -> Result<(), E> {
+        self.bool_expect_with(|| msg)
+    }
+
+    #[cfg(not(feature = "no-panic"))]
+    #[inline]
+    #[track_caller]
+    fn expect_false(self, msg: &str) -> Result<(), E> {
+        self.expect_false_with(|| msg)
+    }
+
+    #[cfg(not(feature = "no-panic"))]
+    #[inline]
+    #[track_caller]
+    fn bool_expect_with<F: FnOnce() -> M, M: Display>(self, msg: F) -> Result<(), E> {
+        // `match` rather than `map` so that `#[track_caller]` reaches `BoolExt::expect_with()`
+        match self {
+            Ok(flag) => {
+                flag.expect_with(msg);
+                Ok(())
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    #[cfg(not(feature = "no-panic"))]
+    #[inline]
+    #[track_caller]
+    fn expect_false_with<F: FnOnce() -> M, M: Display>(self, msg: F) -> Result<(), E> {
+        self.map(Not::not).bool_expect_with(msg)
+    }
+}
+
+/// `OptionBoolExt` lifts the [`BoolExt`] combinators over `Option<bool>`.
+///
+/// `Some(bool)` is transformed exactly as [`BoolExt`] would transform the `bool`; `None` is
+/// carried through untouched.
+///
+/// Where the [`BoolExt`] combinator itself produces an `Option` (e.g.
+/// [`and_some`](OptionBoolExt::and_some)), the `Option<Option<T>>` is flattened into
+/// `Option<T>`.
+///
+/// `map`, `map_or`, `map_or_default`, `map_or_else` and `expect` are named `bool_map`,
+/// `bool_map_or`, `bool_map_or_default`, `bool_map_or_else` and `bool_expect`, as the inherent
+/// methods of `Option` would otherwise shadow them (that of `map_or_default` once it is
+/// stabilised).
+#[expect(
+    clippy::arbitrary_source_item_ordering,
+    reason = "the combinators are grouped as in `BoolExt`"
+)]
+pub trait OptionBoolExt {
+    /// ## Transforms `Some(true)` => `Some(())`, `Some(false)` => `None`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::OptionBoolExt;
+    ///
+    /// assert!(Some(true).to_option() == Some(()));
+    /// assert!(Some(false).to_option() == None);
+    /// ```
+    fn to_option(self) -> Option<()>;
+
+    /// ## Transforms `Some(true)` => `Some(T)`, `Some(false)` => `None`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::OptionBoolExt;
+    ///
+    /// assert!(Some(true).some(42) == Some(42));
+    /// assert!(None.some(42) == None);
+    /// ```
+    fn some<T>(self, some: T) -> Option<T>;
+
+    /// ## Transforms `Some(true)` => `Some(T)`, `Some(false)` => `None`, lazily evaluated
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::OptionBoolExt;
+    ///
+    /// assert!(Some(true).some_with(|| 42) == Some(42));
+    /// assert!(Some(false).some_with(|| 42) == None);
+    /// ```
+    fn some_with<F: FnOnce() -> T, T>(self, some: F) -> Option<T>;
+
+    /// ## Transforms `Some(true)` => `Some(Ok(()))`, `Some(false)` => `Some(Err(FalseError))`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::{FalseError, OptionBoolExt};
+    ///
+    /// assert!(Some(true).to_result() == Some(Ok(())));
+    /// assert!(Some(false).to_result() == Some(Err(FalseError::new())));
+    /// ```
+    fn to_result(self) -> Option<Result<(), FalseError>>;
+
+    /// ## Transforms `Some(true)` => `Some(T)`, `Some(false)` => `None`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::OptionBoolExt;
+    ///
+    /// assert!(Some(true).and_some(42) == Some(42));
+    /// assert!(Some(false).and_some(42) == None);
+    /// ```
+    fn and_some<T>(self, some: T) -> Option<T>;
+
+    /// ## Transforms `Some(true)` => `Some(T)`, `Some(false)` => `None`, lazily evaluated
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::OptionBoolExt;
+    ///
+    /// assert!(Some(true).and_some_with(|| 42) == Some(42));
+    /// assert!(None.and_some_with(|| 42) == None);
+    /// ```
+    fn and_some_with<F: FnOnce() -> T, T>(self, some: F) -> Option<T>;
+
+    /// ## Transforms `Some(true)` => `None`, `Some(false)` => `Some(T)`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::OptionBoolExt;
+    ///
+    /// assert!(Some(true).or_some(42) == None);
+    /// assert!(Some(false).or_some(42) == Some(42));
+    /// ```
+    fn or_some<T>(self, some: T) -> Option<T>;
+
+    /// ## Transforms `Some(true)` => `None`, `Some(false)` => `Some(T)`, lazily evaluated
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::OptionBoolExt;
+    ///
+    /// assert!(Some(false).or_some_with(|| 42) == Some(42));
+    /// assert!(None.or_some_with(|| 42) == None);
+    /// ```
+    fn or_some_with<F: FnOnce() -> T, T>(self, some: F) -> Option<T>;
+
+    /// ## Transforms `Some(true)` => `Some(Ok(T))`, `Some(false)` => `Some(Err(FalseError))`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::{FalseError, OptionBoolExt};
+    ///
+    /// assert!(Some(true).and_ok(42) == Some(Ok(42)));
+    /// assert!(Some(false).and_ok(42) == Some(Err(FalseError::new())));
+    /// ```
+    fn and_ok<T>(self, ok: T) -> Option<Result<T, FalseError>>;
+
+    /// ## Transforms `Some(true)` => `Some(Ok(T))`, `Some(false)` => `Some(Err(FalseError))`,
+    /// lazily evaluated.
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::OptionBoolExt;
+    ///
+    /// assert!(Some(true).and_ok_with(|| 42) == Some(Ok(42)));
+    /// assert!(None.and_ok_with(|| 42) == None);
+    /// ```
+    fn and_ok_with<F: FnOnce() -> T, T>(self, ok: F) -> Option<Result<T, FalseError>>;
+
+    /// ## Transforms `Some(true)` => `Some(Err(TrueError))`, `Some(false)` => `Some(Ok(T))`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::{OptionBoolExt, TrueError};
+    ///
+    /// assert!(Some(true).or_ok(42) == Some(Err(TrueError::new())));
+    /// assert!(Some(false).or_ok(42) == Some(Ok(42)));
+    /// ```
+    fn or_ok<T>(self, ok: T) -> Option<Result<T, TrueError>>;
+
+    /// ## Transforms `Some(true)` => `Some(Err(TrueError))`, `Some(false)` => `Some(Ok(T))`,
+    /// lazily evaluated.
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::OptionBoolExt;
+    ///
+    /// assert!(Some(false).or_ok_with(|| 42) == Some(Ok(42)));
+    /// assert!(None.or_ok_with(|| 42) == None);
+    /// ```
+    fn or_ok_with<F: FnOnce() -> T, T>(self, ok: F) -> Option<Result<T, TrueError>>;
+
+    /// ## Transforms `Some(true)` => `Some(Err(E))`, `Some(false)` => `Some(Ok(()))`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::OptionBoolExt;
+    ///
+    /// assert!(Some(true).and_err("found") == Some(Err("found")));
+    /// assert!(Some(false).and_err("found") == Some(Ok(())));
+    /// ```
+    fn and_err<E>(self, err: E) -> Option<Result<(), E>>;
+
+    /// ## Transforms `Some(true)` => `Some(Err(E))`, `Some(false)` => `Some(Ok(()))`, lazily
+    /// evaluated.
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::OptionBoolExt;
+    ///
+    /// assert!(Some(true).and_err_with(|| "found") == Some(Err("found")));
+    /// assert!(None.and_err_with(|| "found") == None);
+    /// ```
+    fn and_err_with<F: FnOnce() -> E, E>(self, err: F) -> Option<Result<(), E>>;
+
+    /// ## Transforms `Some(true)` => `Some(Ok(()))`, `Some(false)` => `Some(Err(E))`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::OptionBoolExt;
+    ///
+    /// assert!(Some(true).or_err("missing") == Some(Ok(())));
+    /// assert!(Some(false).or_err("missing") == Some(Err("missing")));
+    /// ```
+    fn or_err<E>(self, err: E) -> Option<Result<(), E>>;
+
+    /// ## Transforms `Some(true)` => `Some(Ok(()))`, `Some(false)` => `Some(Err(E))`, lazily
+    /// evaluated.
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::OptionBoolExt;
+    ///
+    /// assert!(Some(false).or_err_with(|| "missing") == Some(Err("missing")));
+    /// assert!(None.or_err_with(|| "missing") == None);
+    /// ```
+    fn or_err_with<F: FnOnce() -> E, E>(self, err: F) -> Option<Result<(), E>>;
+
+    /// ## Transforms `Some(true)` => `Some(Ok(T))`, `Some(false)` => `Some(Err(E))`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::OptionBoolExt;
+    ///
+    /// assert!(Some(true).ok_or_err("missing", 42) == Some(Ok(42)));
+    /// assert!(Some(false).ok_or_err("missing", 42) == Some(Err("missing")));
+    /// ```
+    fn ok_or_err<T, E>(self, err: E, ok: T) -> Option<Result<T, E>>;
+
+    /// ## Transforms `Some(true)` => `Some(Ok(T))`, `Some(false)` => `Some(Err(E))`, lazily
+    /// evaluated.
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::OptionBoolExt;
+    ///
+    /// assert!(Some(true).ok_or_err_with(|| "missing", || 42) == Some(Ok(42)));
+    /// assert!(None.ok_or_err_with(|| "missing", || 42) == None);
+    /// ```
+    fn ok_or_err_with<F: FnOnce() -> T, G: FnOnce() -> E, T, E>(
+        self,
+        err: G,
+        ok: F,
+    ) -> Option<Result<T, E>>;
+
+    /// ## Transforms `Some(true)` => `Some(T)`, `Some(false)` => `Some(T)`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::OptionBoolExt;
+    ///
+    /// assert!(Some(true).bool_map("no", "yes") == Some("yes"));
+    /// assert!(None.bool_map("no", "yes") == None);
+    /// ```
+    fn bool_map<T>(self, f: T, t: T) -> Option<T>;
+
+    /// ## Transforms `Some(true)` => `Some(T)`, `Some(false)` => `Some(T)`, lazily evaluated if
+    /// `true`.
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::OptionBoolExt;
+    ///
+    /// assert!(Some(true).bool_map_or(0, || 42) == Some(42));
+    /// assert!(Some(false).bool_map_or(0, || 42) == Some(0));
+    /// ```
+    fn bool_map_or<F: FnOnce() -> T, T>(self, f: T, t: F) -> Option<T>;
+
+    /// ## Transforms `Some(true)` => `Some(T)`, `Some(false)` => `Some(T::default())`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::OptionBoolExt;
+    ///
+    /// assert!(Some(true).bool_map_or_default(|| 42) == Some(42));
+    /// assert!(Some(false).bool_map_or_default(|| 42) == Some(0));
+    /// ```
+    fn bool_map_or_default<F: FnOnce() -> T, T: Default>(self, t: F) -> Option<T>;
+
+    /// ## Transforms `Some(true)` => `Some(T)`, `Some(false)` => `Some(T)`, lazily evaluated
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::OptionBoolExt;
+    ///
+    /// assert!(Some(true).bool_map_or_else(|| "out", || "in") == Some("in"));
+    /// assert!(Some(false).bool_map_or_else(|| "out", || "in") == Some("out"));
+    /// ```
+    fn bool_map_or_else<F: FnOnce() -> T, G: FnOnce() -> T, T>(self, f: G, t: F) -> Option<T>;
+
+    /// ## Perform side-effect if `Some(true)`, otherwise do nothing
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::OptionBoolExt;
+    ///
+    /// let mut vec = vec![1, 2, 3];
+    /// let res = Some(vec.contains(&2)).and_do(|| vec.push(4));
+    ///
+    /// assert!(res == Some(true));
+    /// assert!(vec == [1, 2, 3, 4]);
+    /// ```
+    fn and_do<F: FnOnce()>(self, t: F) -> Option<bool>;
+
+    /// ## Perform side-effect if `Some(false)`, otherwise do nothing
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::OptionBoolExt;
+    ///
+    /// let mut vec = vec![1, 2, 3];
+    /// let res = None.or_do(|| vec.push(4));
+    ///
+    /// assert!(res == None);
+    /// assert!(vec == [1, 2, 3]);
+    /// ```
+    fn or_do<F: FnOnce()>(self, f: F) -> Option<bool>;
+
+    /// ## Perform fallible side-effect if `Some(true)`, otherwise do nothing
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::OptionBoolExt;
+    ///
+    /// let mut vec = vec![1, 2, 3];
+    /// let res = Some(vec.contains(&2)).and_try_do(|| {
+    ///     vec.push(4);
+    ///     Ok::<_, ()>(())
+    /// });
+    ///
+    /// assert!(res == Some(Ok(true)));
+    /// assert!(vec == [1, 2, 3, 4]);
+    /// ```
+    fn and_try_do<F: FnOnce() -> Result<(), E>, E>(self, t: F) -> Option<Result<bool, E>>;
+
+    /// ## Perform fallible side-effect if `Some(false)`, otherwise do nothing
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::OptionBoolExt;
+    ///
+    /// let res = Some(false).or_try_do(|| Err("failed"));
+    ///
+    /// assert!(res == Some(Err("failed")));
+    /// ```
+    fn or_try_do<F: FnOnce() -> Result<(), E>, E>(self, f: F) -> Option<Result<bool, E>>;
+
+    /// ## Transforms `Some(true)` => `Some(Ok(()))`, `Some(false)` => `Some(Err(FalseError))`
+    ///
+    /// The panic-free counterpart of `bool_expect`; see [`BoolExt::must`].
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::{FalseError, OptionBoolExt};
+    ///
+    /// assert!(Some(true).must() == Some(Ok(())));
+    /// assert!(Some(false).must() == Some(Err(FalseError::new())));
+    /// assert!(None.must() == None);
+    /// ```
+    fn must(self) -> Option<Result<(), FalseError>>;
+
+    /// ## Transforms `Some(true)` => `Some(Err(TrueError))`, `Some(false)` => `Some(Ok(()))`
+    ///
+    /// The panic-free counterpart of `expect_false`; see [`BoolExt::must_not`].
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::{OptionBoolExt, TrueError};
+    ///
+    /// assert!(Some(false).must_not() == Some(Ok(())));
+    /// assert!(Some(true).must_not() == Some(Err(TrueError::new())));
+    /// assert!(None.must_not() == None);
+    /// ```
+    fn must_not(self) -> Option<Result<(), TrueError>>;
+
+    /// ## Transforms `Some(false)` => `panic!()`
+    /// ## panic with message if `Some(false)`, otherwise pass `None` through
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::OptionBoolExt;
+    ///
+    /// assert!(Some(true).bool_expect("expected `true`") == Some(()));
+    /// assert!(None.bool_expect("expected `true`") == None);
+    /// assert!(std::panic::catch_unwind(|| Some(false).bool_expect("expected `true`")).is_err());
+    /// ```
+    #[cfg(not(feature = "no-panic"))]
+    fn bool_expect(self, msg: &str) -> Option<()>;
+
+    /// ## Transforms `Some(true)` => `panic!()`
+    /// ## panic with message if `Some(true)`, otherwise pass `None` through
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::OptionBoolExt;
+    ///
+    /// assert!(Some(false).expect_false("expected `false`") == Some(()));
+    /// assert!(std::panic::catch_unwind(|| Some(true).expect_false("expected `false`")).is_err());
+    /// ```
+    #[cfg(not(feature = "no-panic"))]
+    fn expect_false(self, msg: &str) -> Option<()>;
+
+    /// ## Transforms `Some(false)` => `panic!()`
+    /// ## panic with the message returned by `msg` if `Some(false)` (calling `msg` only then),
+    /// otherwise pass `None` through.
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::OptionBoolExt;
+    ///
+    /// assert!(Some(true).bool_expect_with(|| format!("expected `{}`", true)) == Some(()));
+    /// assert!(None.bool_expect_with(|| "expected `true`") == None);
+    /// assert!(std::panic::catch_unwind(|| Some(false).bool_expect_with(|| "expected `true`"))
+    ///     .is_err());
+    /// ```
+    #[cfg(not(feature = "no-panic"))]
+    fn bool_expect_with<F: FnOnce() -> M, M: Display>(self, msg: F) -> Option<()>;
+
+    /// ## Transforms `Some(true)` => `panic!()`
+    /// ## panic with the message returned by `msg` if `Some(true)` (calling `msg` only then),
+    /// otherwise pass `None` through.
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::OptionBoolExt;
+    ///
+    /// assert!(Some(false).expect_false_with(|| "expected `false`") == Some(()));
+    /// assert!(std::panic::catch_unwind(|| Some(true).expect_false_with(|| "expected `false`"))
+    ///     .is_err());
+    /// ```
+    #[cfg(not(feature = "no-panic"))]
+    fn expect_false_with<F: FnOnce() -> M, M: Display>(self, msg: F) -> Option<()>;
+}
+
+#[expect(
+    clippy::arbitrary_source_item_ordering,
+    clippy::question_mark_used,
+    reason = "ordered as in the trait; `?` carries the `None` past the combinator"
+)]
+impl OptionBoolExt for Option<bool> {
+    #[inline]
+    fn to_option(self) -> Option<()> {
+        self.and_then(BoolExt::to_option)
+    }
+
+    #[inline]
+    fn some<T>(self, some: T) -> Option<T> {
+        self?.some(some)
+    }
+
+    #[inline]
+    fn some_with<F: FnOnce() -> T, T>(self, some: F) -> Option<T> {
+        self?.some_with(some)
+    }
+
+    #[inline]
+    #[track_caller]
+    fn to_result(self) -> Option<Result<(), FalseError>> {
+        self.and_ok(())
+    }
+
+    #[inline]
+    fn and_some<T>(self, some: T) -> Option<T> {
+        self?.and_some(some)
+    }
+
+    #[inline]
+    fn and_some_with<F: FnOnce() -> T, T>(self, some: F) -> Option<T> {
+        self?.and_some_with(some)
+    }
+
+    #[inline]
+    fn or_some<T>(self, some: T) -> Option<T> {
+        self?.or_some(some)
+    }
+
+    #[inline]
+    fn or_some_with<F: FnOnce() -> T, T>(self, some: F) -> Option<T> {
+        self?.or_some_with(some)
+    }
+
+    #[inline]
+    #[track_caller]
+    fn and_ok<T>(self, ok: T) -> Option<Result<T, FalseError>> {
+        self.and_ok_with(|| ok)
+    }
+
+    #[inline]
+    #[track_caller]
+    fn and_ok_with<F: FnOnce() -> T, T>(self, ok: F) -> Option<Result<T, FalseError>> {
+        // `match` rather than `map` so that `#[track_caller]` reaches `FalseError::new()`
+        #[expect(
+            clippy::manual_map,
+            clippy::option_if_let_else,
+            reason = "a closure would hide the caller's location"
+        )]
+        match self {
+            Some(flag) => Some(flag.and_ok_with(ok)),
+            None => None,
+        }
+    }
+
+    #[inline]
+    #[track_caller]
+    fn or_ok<T>(self, ok: T) -> Option<Result<T, TrueError>> {
+        self.or_ok_with(|| ok)
+    }
+
+    #[inline]
+    #[track_caller]
+    fn or_ok_with<F: FnOnce() -> T, T>(self, ok: F) -> Option<Result<T, TrueError>> {
+        #[expect(
+            clippy::manual_map,
+            clippy::option_if_let_else,
+            reason = "a closure would hide the caller's location"
+        )]
+        match self {
+            Some(flag) => Some(flag.or_ok_with(ok)),
+            None => None,
+        }
+    }
+
+    #[inline]
+    fn and_err<E>(self, err: E) -> Option<Result<(), E>> {
+        self.map(|flag| flag.and_err(err))
+    }
+
+    #[inline]
+    fn and_err_with<F: FnOnce() -> E, E>(self, err: F) -> Option<Result<(), E>> {
+        self.map(|flag| flag.and_err_with(err))
+    }
+
+    #[inline]
+    fn or_err<E>(self, err: E) -> Option<Result<(), E>> {
+        self.map(|flag| flag.or_err(err))
+    }
+
+    #[inline]
+    fn or_err_with<F: FnOnce() -> E, E>(self, err: F) -> Option<Result<(), E>> {
+        self.map(|flag| flag.or_err_with(err))
+    }
+
+    #[inline]
+    fn ok_or_err<T, E>(self, err: E, ok: T) -> Option<Result<T, E>> {
+        self.map(|flag| flag.ok_or_err(err, ok))
+    }
+
+    #[inline]
+    fn ok_or_err_with<F: FnOnce() -> T, G: FnOnce() -> E, T, E>(
+        self,
+        err: G,
+        ok: F,
+    ) -> Option<Result<T, E>> {
+        self.map(|flag| flag.ok_or_err_with(err, ok))
+    }
+
+    #[inline]
+    fn bool_map<T>(self, f: T, t: T) -> Option<T> {
+        self.map(|flag| BoolExt::map(flag, f, t))
+    }
+
+    #[inline]
+    fn bool_map_or<F: FnOnce() -> T, T>(self, f: T, t: F) -> Option<T> {
+        self.map(|flag| BoolExt::map_or(flag, f, t))
+    }
+
+    #[inline]
+    fn bool_map_or_default<F: FnOnce() -> T, T: Default>(self, t: F) -> Option<T> {
+        self.map(|flag| flag.map_or_default(t))
+    }
+
+    #[inline]
+    fn bool_map_or_else<F: FnOnce() -> T, G: FnOnce() -> T, T>(self, f: G, t: F) -> Option<T> {
+        self.map(|flag| BoolExt::map_or_else(flag, f, t))
+    }
+
+    #[inline]
+    fn and_do<F: FnOnce()>(self, t: F) -> Option<bool> {
+        self.map(|flag| flag.and_do(t))
+    }
+
+    #[inline]
+    fn or_do<F: FnOnce()>(self, f: F) -> Option<bool> {
+        self.map(|flag| flag.or_do(f))
+    }
+
+    #[inline]
+    fn and_try_do<F: FnOnce() -> Result<(), E>, E>(self, t: F) -> Option<Result<bool, E>> {
+        self.map(|flag| flag.and_try_do(t))
+    }
+
+    #[inline]
+    fn or_try_do<F: FnOnce() -> Result<(), E>, E>(self, f: F) -> Option<Result<bool, E>> {
+        self.map(|flag| flag.or_try_do(f))
+    }
+
+    #[inline]
+    #[track_caller]
+    fn must(self) -> Option<Result<(), FalseError>> {
+        self.to_result()
+    }
+
+    #[inline]
+    #[track_caller]
+    fn must_not(self) -> Option<Result<(), TrueError>> {
+        self.or_ok(())
+    }
+
+    #[cfg(not(feature = "no-panic"))]
+    #[inline]
+    #[track_caller]
+    fn bool_expect(self, msg: &str) -> Option<()> {
+        self.bool_expect_with(|| msg)
+    }
+
+    #[cfg(not(feature = "no-panic"))]
+    #[inline]
+    #[track_caller]
+    fn expect_false(self, msg: &str) -> Option<()> {
+        self.expect_false_with(|| msg)
+    }
+
+    #[cfg(not(feature = "no-panic"))]
+    #[inline]
+    #[track_caller]
+    #[expect(
+        clippy::option_if_let_else,
+        reason = "a closure would hide the caller's location"
+    )]
+    fn bool_expect_with<F: FnOnce() -> M, M: Display>(self, msg: F) -> Option<()> {
+        // `match` rather than `map` so that `#[track_caller]` reaches `BoolExt::expect_with()`
+        match self {
+            Some(flag) => {
+                flag.expect_with(msg);
+                Some(())
+            }
+            None => None,
+        }
+    }
+
+    #[cfg(not(feature = "no-panic"))]
+    #[inline]
+    #[track_caller]
+    fn expect_false_with<F: FnOnce() -> M, M: Display>(self, msg: F) -> Option<()> {
+        self.map(Not::not).bool_expect_with(msg)
+    }
+}
@@ -0,0 +1,772 @@
+//! Modified condition/decision coverage (MC/DC).
+//!
+//! A *decision* is a Boolean expression whose *conditions* are its variables.  MC/DC requires,
+//! for each condition, an *independence pair*: two observed evaluations of the decision with
+//! different outcomes, in which the condition took different values and every other condition
+//! evaluated in both took the same value.  (Conditions masked by short-circuiting may differ, as
+//! in the unique-cause, masking form of MC/DC.)
+//!
+//! A [`Coverage`] records evaluations, either of an [`Expr`] via [`Coverage::eval`] or of an
+//! instrumented decision in code via [`Coverage::decision`], and its [`Report`] states, per
+//! condition, whether an independence pair was observed, and if not, which input vectors would
+//! complete one.
+//! ### Examples:
+//! ```
+//! use assert2::assert;
+//! use bool_ext::mcdc::Coverage;
+//! use std::sync::Mutex;
+//!
+//! // Shared across a test run
+//! static COVERAGE: Mutex<Coverage> = Mutex::new(Coverage::new());
+//!
+//! fn can_post(premium: bool, verified: bool) -> bool {
+//!     let mut coverage = COVERAGE.lock().unwrap();
+//!     let mut decision = coverage.decision();
+//!     let can_post = decision.condition("premium", premium) && decision.condition("verified", verified);
+//!     decision.outcome(can_post)
+//! }
+//!
+//! assert!(can_post(true, true));
+//! assert!(!can_post(false, true));
+//!
+//! let report = COVERAGE.lock().unwrap().report();
+//! assert!(report.to_string() == "\
+//! premium: covered by observations 0 and 1
+//! verified: missing; add (premium = true, verified = false)
+//! MC/DC: 1 of 2 conditions covered
+//! ");
+//! ```
+#![expect(
+    clippy::arbitrary_source_item_ordering,
+    reason = "types follow the flow from recording evaluations to reporting coverage, and methods the same within each"
+)]
+#![expect(
+    clippy::pattern_type_mismatch,
+    reason = "trees and observations are matched by reference, and `ref_patterns` rejects the `ref` bindings of the alternative"
+)]
+#![expect(
+    clippy::question_mark_used,
+    reason = "missing inputs and unsatisfiable vectors end the search early, and reports are written piecewise"
+)]
+
+use crate::{
+    expr::{Env, Explanation, Expr, UnboundVariable},
+    sat::Cnf,
+};
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    string::String,
+    vec,
+    vec::Vec,
+};
+use core::fmt::{Display, Formatter, Result as FmtResult};
+
+/// `Observation` is one recorded evaluation of a decision.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Observation {
+    /// Indexed by condition; trailing unevaluated conditions are omitted.
+    inputs: Vec<Option<bool>>,
+    /// The decision's outcome.
+    outcome: bool,
+}
+
+impl Observation {
+    /// Creates an observation, trimming the trailing unevaluated conditions.
+    fn new(mut inputs: Vec<Option<bool>>, outcome: bool) -> Self {
+        while inputs.last() == Some(&None) {
+            inputs.pop();
+        }
+        Self { inputs, outcome }
+    }
+
+    /// ## Returns the value of `condition` (an index into [`Coverage::conditions`]), or `None` if
+    /// it was not evaluated.
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::{expr::Expr, mcdc::Coverage};
+    ///
+    /// let rule = "a && b".parse::<Expr>().unwrap();
+    /// let mut coverage = Coverage::for_expr(&rule);
+    /// coverage.eval(&rule, &|_: &str| Some(false)).unwrap();
+    ///
+    /// let observation = &coverage.observations()[0];
+    /// assert!(observation.input(0) == Some(false));
+    /// assert!(observation.input(1) == None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn input(&self, condition: usize) -> Option<bool> {
+        self.inputs.get(condition).copied().flatten()
+    }
+
+    /// ## Returns the outcome of the decision
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::{expr::Expr, mcdc::Coverage};
+    ///
+    /// let rule = "a && b".parse::<Expr>().unwrap();
+    /// let mut coverage = Coverage::for_expr(&rule);
+    /// coverage.eval(&rule, &|_: &str| Some(true)).unwrap();
+    ///
+    /// assert!(coverage.observations()[0].outcome());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn outcome(&self) -> bool {
+        self.outcome
+    }
+
+    /// Transforms `self` and `other` form an independence pair for `condition` => `true`,
+    /// otherwise `false`.
+    fn is_independence_pair(&self, other: &Self, condition: usize) -> bool {
+        let len = self.inputs.len().max(other.inputs.len());
+        self.outcome != other.outcome
+            && matches!(
+                (self.input(condition), other.input(condition)),
+                (Some(lhs), Some(rhs)) if lhs != rhs
+            )
+            && (0..len).filter(|&index| index != condition).all(|index| {
+                match (self.input(index), other.input(index)) {
+                    (Some(lhs), Some(rhs)) => lhs == rhs,
+                    _ => true,
+                }
+            })
+    }
+}
+
+/// `Coverage` records the evaluations of a decision, for MC/DC analysis.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Coverage {
+    /// The names of the decision's conditions.
+    conditions: Vec<String>,
+    /// The observations, in recording order.
+    observations: Vec<Observation>,
+    /// Distinct observations, so that repeated evaluations are recorded once.
+    seen: BTreeSet<Observation>,
+}
+
+impl Coverage {
+    /// ## Constructs a `Coverage` with no conditions and no observations
+    ///
+    /// Conditions are registered as they are first evaluated.
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::mcdc::Coverage;
+    ///
+    /// assert!(Coverage::new().observations().is_empty());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            conditions: Vec::new(),
+            observations: Vec::new(),
+            seen: BTreeSet::new(),
+        }
+    }
+
+    /// ## Constructs a `Coverage` whose conditions are the variables of `expr`, so that those
+    /// never evaluated are reported too.
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::{expr::Expr, mcdc::Coverage};
+    ///
+    /// let rule = "b || a".parse::<Expr>().unwrap();
+    /// assert!(Coverage::for_expr(&rule).conditions() == ["a", "b"]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn for_expr(expr: &Expr) -> Self {
+        Self {
+            conditions: expr.variables().into_iter().map(String::from).collect(),
+            ..Self::new()
+        }
+    }
+
+    /// ## Returns the names of the conditions, in registration order
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::mcdc::Coverage;
+    ///
+    /// let mut coverage = Coverage::new();
+    /// coverage.record([("verified", true), ("admin", false)], true);
+    ///
+    /// assert!(coverage.conditions() == ["verified", "admin"]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn conditions(&self) -> &[String] {
+        &self.conditions
+    }
+
+    /// ## Returns the distinct observations, in the order first recorded
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::mcdc::Coverage;
+    ///
+    /// let mut coverage = Coverage::new();
+    /// coverage.record([("admin", true)], true);
+    /// coverage.record([("admin", true)], true);
+    ///
+    /// assert!(coverage.observations().len() == 1);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn observations(&self) -> &[Observation] {
+        &self.observations
+    }
+
+    /// Returns the index of the condition named `name`, registering it if necessary.
+    fn register(&mut self, name: &str) -> usize {
+        self.conditions
+            .iter()
+            .position(|condition| condition == name)
+            .unwrap_or_else(|| {
+                self.conditions.push(String::from(name));
+                self.conditions.len().saturating_sub(1)
+            })
+    }
+
+    /// Records `observation` unless it was already seen.
+    fn insert(&mut self, observation: Observation) {
+        if self.seen.insert(observation.clone()) {
+            self.observations.push(observation);
+        }
+    }
+
+    /// ## Records an evaluation of the decision, in which `inputs` are the evaluated conditions
+    /// and their values.
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::mcdc::Coverage;
+    ///
+    /// let mut coverage = Coverage::new();
+    /// coverage.record([("admin", true)], true);
+    /// coverage.record([("admin", false), ("staff", false)], false);
+    ///
+    /// assert!(coverage.report().conditions()[0].is_covered());
+    /// ```
+    #[inline]
+    pub fn record<'name, I: IntoIterator<Item = (&'name str, bool)>>(
+        &mut self,
+        inputs: I,
+        outcome: bool,
+    ) {
+        let mut values = vec![None; self.conditions.len()];
+        for (name, value) in inputs {
+            let condition = self.register(name);
+            if values.len() <= condition {
+                values.resize(condition.saturating_add(1), None);
+            }
+            if let Some(slot) = values.get_mut(condition) {
+                *slot = Some(value);
+            }
+        }
+        self.insert(Observation::new(values, outcome));
+    }
+
+    /// ## Evaluates `expr` against `env`, as [`Expr::eval`] does, recording the evaluation
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::{expr::Expr, mcdc::Coverage};
+    ///
+    /// let rule = "premium && verified".parse::<Expr>().unwrap();
+    /// let mut coverage = Coverage::for_expr(&rule);
+    ///
+    /// assert!(coverage.eval(&rule, &|_: &str| Some(true)) == Ok(true));
+    /// assert!(coverage.observations().len() == 1);
+    /// ```
+    #[inline]
+    pub fn eval<E: Env + ?Sized>(&mut self, expr: &Expr, env: &E) -> Result<bool, UnboundVariable> {
+        let explanation = expr.eval_explained(env)?;
+        let mut inputs = Vec::new();
+        collect_inputs(&explanation, &mut inputs);
+        self.record(inputs, explanation.value());
+        Ok(explanation.value())
+    }
+
+    /// ## Starts recording an evaluation of a decision in code
+    ///
+    /// Pass each condition through [`Decision::condition`] as it is evaluated, then the result
+    /// through [`Decision::outcome`], which records the evaluation.
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::{mcdc::Coverage, BoolExt};
+    ///
+    /// let mut coverage = Coverage::new();
+    /// let mut decision = coverage.decision();
+    /// let allowed = decision.condition("admin", false) || decision.condition("owner", true);
+    ///
+    /// assert!(decision.outcome(allowed).some("allowed") == Some("allowed"));
+    /// assert!(coverage.observations()[0].input(1) == Some(true));
+    /// ```
+    #[inline]
+    pub fn decision(&mut self) -> Decision<'_> {
+        Decision {
+            inputs: vec![None; self.conditions.len()],
+            coverage: self,
+        }
+    }
+
+    /// Returns the indices of the first observed independence pair for `condition`, if any.
+    fn independence_pair(&self, condition: usize) -> Option<(usize, usize)> {
+        self.observations
+            .iter()
+            .enumerate()
+            .find_map(|(first, lhs)| {
+                self.observations
+                    .iter()
+                    .enumerate()
+                    .skip(first.saturating_add(1))
+                    .find(|(_, rhs)| lhs.is_independence_pair(rhs, condition))
+                    .map(|(second, _)| (first, second))
+            })
+    }
+
+    /// Returns the evaluated inputs of `observation`, keyed by name.
+    fn named_inputs(&self, observation: &Observation) -> BTreeMap<String, bool> {
+        self.conditions
+            .iter()
+            .enumerate()
+            .filter_map(|(condition, name)| Some((name.clone(), observation.input(condition)?)))
+            .collect()
+    }
+
+    /// Returns the outcome of the decision on `vector`, if an observation determines it: one
+    /// whose evaluated conditions all take their values in `vector`.
+    fn known_outcome(&self, vector: &BTreeMap<String, bool>) -> Option<bool> {
+        self.observations
+            .iter()
+            .find(|observation| {
+                self.named_inputs(observation)
+                    .iter()
+                    .all(|(name, value)| vector.get(name) == Some(value))
+            })
+            .map(Observation::outcome)
+    }
+
+    /// ## Reports MC/DC coverage of the decision
+    ///
+    /// Without the decision's expression, the outcome of an input vector is known only where an
+    /// observation determines it, so the vectors suggested for an uncovered condition are
+    /// candidates.  The suggestion is an observation with the condition flipped, skipping those
+    /// already observed to leave the outcome unchanged; failing that, it is a pair of vectors
+    /// differing from an observation in another condition too, and in the condition itself.  Use
+    /// [`report_for`](Self::report_for) for exact suggestions.
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::mcdc::{Coverage, Status};
+    ///
+    /// let mut coverage = Coverage::new();
+    /// coverage.record([("admin", false), ("owner", true)], true);
+    /// coverage.record([("admin", true), ("owner", true)], true);
+    /// coverage.record([("admin", false), ("owner", false)], false);
+    ///
+    /// let report = coverage.report();
+    /// assert!(!report.is_complete());
+    /// assert!(report.conditions()[0].status() == &Status::Missing(vec![
+    ///     [(String::from("admin"), true), (String::from("owner"), false)].into(),
+    /// ]));
+    /// assert!(report.conditions()[1].is_covered());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn report(&self) -> Report {
+        self.report_with(|condition| {
+            let Some(name) = self.conditions.get(condition) else {
+                return Status::Missing(Vec::new());
+            };
+            let evaluated = self
+                .observations
+                .iter()
+                .filter(|observation| observation.input(condition).is_some());
+            // A vector completing a pair with an observation, unless observed not to
+            let completion = evaluated.clone().find_map(|observation| {
+                let vector = flip(self.named_inputs(observation), name);
+                (self.known_outcome(&vector) != Some(observation.outcome)).then_some(vector)
+            });
+            if let Some(vector) = completion {
+                return Status::Missing(vec![vector]);
+            }
+            // A pair differing from an observation in another condition, unless observed not to
+            let pair = evaluated
+                .flat_map(|observation| {
+                    let inputs = self.named_inputs(observation);
+                    inputs
+                        .keys()
+                        .filter(|other| *other != name)
+                        .map(|other| flip(inputs.clone(), other))
+                        .collect::<Vec<_>>()
+                })
+                .find_map(|vector| {
+                    let flipped = flip(vector.clone(), name);
+                    match (self.known_outcome(&vector), self.known_outcome(&flipped)) {
+                        (Some(lhs), Some(rhs)) if lhs == rhs => None,
+                        _ => Some(vec![vector, flipped]),
+                    }
+                });
+            Status::Missing(pair.unwrap_or_default())
+        })
+    }
+
+    /// ## Reports MC/DC coverage of the decision `expr`
+    ///
+    /// For an uncovered condition, the suggestion is a single input vector completing a pair with
+    /// an existing observation where there is one; otherwise, it is a pair of vectors under which
+    /// the condition alone decides the outcome.  Either is found with the [SAT solver](crate::sat),
+    /// and omits conditions which short-circuiting leaves unevaluated.  If there is no such pair,
+    /// the condition is [`Status::Infeasible`].
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::{expr::Expr, mcdc::Coverage};
+    ///
+    /// let rule = "premium && (verified || admin)".parse::<Expr>().unwrap();
+    /// let mut coverage = Coverage::for_expr(&rule);
+    /// coverage.eval(&rule, &|_: &str| Some(true)).unwrap();
+    /// coverage.eval(&rule, &|name: &str| Some(name != "premium")).unwrap();
+    ///
+    /// assert!(coverage.report_for(&rule).to_string() == "\
+    /// admin: missing; add (admin = false, premium = true, verified = false) \
+    ///     and (admin = true, premium = true, verified = false)
+    /// premium: covered by observations 0 and 1
+    /// verified: missing; add (admin = false, premium = true, verified = false)
+    /// MC/DC: 1 of 3 conditions covered
+    /// ");
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn report_for(&self, expr: &Expr) -> Report {
+        self.report_with(|condition| {
+            let Some(name) = self.conditions.get(condition) else {
+                return Status::Infeasible;
+            };
+            // A vector agreeing with an observation, except in the condition, but not in outcome
+            let completion = self.observations.iter().find_map(|observation| {
+                observation.input(condition)?;
+                let outcome = Expr::Const(!observation.outcome).iff(expr.clone());
+                let constraint = self.named_inputs(observation).into_iter().fold(
+                    outcome,
+                    |constraint, (variable, value)| {
+                        let flip = variable == *name;
+                        constraint & Expr::var(variable).iff(Expr::Const(value != flip))
+                    },
+                );
+                let assignment = Cnf::from(&constraint).solve().ok()?;
+                evaluated_inputs(expr, &assignment)
+            });
+            if let Some(vector) = completion {
+                return Status::Missing(vec![vector]);
+            }
+            let difference = assign(expr, name, false) ^ assign(expr, name, true);
+            let Ok(mut assignment) = Cnf::from(&difference).solve() else {
+                return Status::Infeasible;
+            };
+            let vectors = [false, true]
+                .into_iter()
+                .filter_map(|value| {
+                    assignment.insert(name.clone(), value);
+                    evaluated_inputs(expr, &assignment)
+                })
+                .collect();
+            Status::Missing(vectors)
+        })
+    }
+
+    /// Builds the report, asking `missing` for the status of each condition without an
+    /// independence pair.
+    fn report_with(&self, mut missing: impl FnMut(usize) -> Status) -> Report {
+        let conditions = self
+            .conditions
+            .iter()
+            .enumerate()
+            .map(|(condition, name)| ConditionCoverage {
+                name: name.clone(),
+                status: self.independence_pair(condition).map_or_else(
+                    || missing(condition),
+                    |(lhs, rhs)| Status::Covered(lhs, rhs),
+                ),
+            })
+            .collect();
+        Report { conditions }
+    }
+}
+
+/// Collects the variables evaluated in `explanation`, with their values.
+fn collect_inputs<'expr>(explanation: &Explanation<'expr>, inputs: &mut Vec<(&'expr str, bool)>) {
+    if let Expr::Var(name) = explanation.expr() {
+        inputs.push((name, explanation.value()));
+    }
+    explanation
+        .operands()
+        .iter()
+        .for_each(|operand| collect_inputs(operand, inputs));
+}
+
+/// Returns the inputs evaluated by `expr` against `env`, keyed by name, or `None` if it evaluates an
+/// unbound variable.
+fn evaluated_inputs<E: Env + ?Sized>(expr: &Expr, env: &E) -> Option<BTreeMap<String, bool>> {
+    let mut inputs = Vec::new();
+    collect_inputs(&expr.eval_explained(env).ok()?, &mut inputs);
+    Some(
+        inputs
+            .into_iter()
+            .map(|(name, value)| (String::from(name), value))
+            .collect(),
+    )
+}
+
+/// Returns `vector` with the value of `name` negated.
+fn flip(mut vector: BTreeMap<String, bool>, name: &str) -> BTreeMap<String, bool> {
+    if let Some(value) = vector.get_mut(name) {
+        *value = !*value;
+    }
+    vector
+}
+
+/// Returns `expr` with the variable `name` replaced by `value`.
+fn assign(expr: &Expr, name: &str, value: bool) -> Expr {
+    let assign = |operand| assign(operand, name, value);
+    match expr {
+        Expr::Var(var) if var == name => Expr::Const(value),
+        Expr::Var(_) | Expr::Const(_) => expr.clone(),
+        Expr::Not(operand) => !assign(operand),
+        Expr::And(lhs, rhs) => assign(lhs) & assign(rhs),
+        Expr::Or(lhs, rhs) => assign(lhs) | assign(rhs),
+        Expr::Xor(lhs, rhs) => assign(lhs) ^ assign(rhs),
+        Expr::Implies(lhs, rhs) => assign(lhs).implies(assign(rhs)),
+        Expr::Iff(lhs, rhs) => assign(lhs).iff(assign(rhs)),
+    }
+}
+
+/// `Decision` records one evaluation of an instrumented decision in code, started by
+/// [`Coverage::decision`].
+#[derive(Debug)]
+#[must_use = "an evaluation is recorded only by `Decision::outcome`"]
+pub struct Decision<'coverage> {
+    /// The coverage to record into.
+    coverage: &'coverage mut Coverage,
+    /// The values of the conditions evaluated so far.
+    inputs: Vec<Option<bool>>,
+}
+
+impl Decision<'_> {
+    /// ## Records that the condition `name` evaluated to `value`, and returns `value`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::mcdc::Coverage;
+    ///
+    /// let mut coverage = Coverage::new();
+    /// let mut decision = coverage.decision();
+    ///
+    /// assert!(decision.condition("admin", true));
+    /// ```
+    #[inline]
+    pub fn condition(&mut self, name: &str, value: bool) -> bool {
+        let condition = self.coverage.register(name);
+        if self.inputs.len() <= condition {
+            self.inputs.resize(condition.saturating_add(1), None);
+        }
+        if let Some(slot) = self.inputs.get_mut(condition) {
+            *slot = Some(value);
+        }
+        value
+    }
+
+    /// ## Records the evaluation, with outcome `outcome`, and returns `outcome`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::mcdc::Coverage;
+    ///
+    /// let mut coverage = Coverage::new();
+    /// let mut decision = coverage.decision();
+    /// let admin = decision.condition("admin", true);
+    ///
+    /// assert!(decision.outcome(admin));
+    /// assert!(coverage.observations()[0].outcome());
+    /// ```
+    #[expect(
+        clippy::must_use_candidate,
+        reason = "the outcome is passed through so that a condition can be recorded in place"
+    )]
+    #[inline]
+    pub fn outcome(self, outcome: bool) -> bool {
+        self.coverage.insert(Observation::new(self.inputs, outcome));
+        outcome
+    }
+}
+
+/// MC/DC status of a condition.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[expect(
+    clippy::exhaustive_enums,
+    reason = "a condition is either covered or not, and if not, coverable or not"
+)]
+pub enum Status {
+    /// An independence pair was observed: the indices of its observations.
+    Covered(usize, usize),
+    /// No independence pair was observed; recording the suggested input vectors (if any) would
+    /// complete one.  Conditions absent from a vector are don't-cares.
+    Missing(Vec<BTreeMap<String, bool>>),
+    /// The condition can never independently affect the decision's outcome.
+    Infeasible,
+}
+
+/// MC/DC coverage of a single condition.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ConditionCoverage {
+    /// The condition's name.
+    name: String,
+    /// Whether the condition is covered.
+    status: Status,
+}
+
+impl ConditionCoverage {
+    /// Name of the condition.
+    #[must_use]
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// MC/DC status of the condition.
+    #[must_use]
+    #[inline]
+    pub const fn status(&self) -> &Status {
+        &self.status
+    }
+
+    /// Transforms an independence pair was observed => `true`, otherwise `false`.
+    #[must_use]
+    #[inline]
+    pub const fn is_covered(&self) -> bool {
+        matches!(self.status, Status::Covered(..))
+    }
+}
+
+impl Display for ConditionCoverage {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}: ", self.name)?;
+        match &self.status {
+            Status::Covered(lhs, rhs) => write!(f, "covered by observations {lhs} and {rhs}"),
+            Status::Missing(vectors) if vectors.is_empty() => {
+                f.write_str("missing; no input vector to suggest")
+            }
+            Status::Missing(vectors) => {
+                f.write_str("missing; add ")?;
+                for (index, vector) in vectors.iter().enumerate() {
+                    if index > 0 {
+                        f.write_str(" and ")?;
+                    }
+                    f.write_str("(")?;
+                    for (position, (name, value)) in vector.iter().enumerate() {
+                        if position > 0 {
+                            f.write_str(", ")?;
+                        }
+                        write!(f, "{name} = {value}")?;
+                    }
+                    f.write_str(")")?;
+                }
+                Ok(())
+            }
+            Status::Infeasible => {
+                f.write_str("infeasible; it never independently decides the outcome")
+            }
+        }
+    }
+}
+
+/// `Report` is the MC/DC coverage of each condition of a decision, produced by
+/// [`Coverage::report`] or [`Coverage::report_for`].
+///
+/// `Display` renders one line per condition, then a summary line.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Report {
+    /// The coverage of each condition, in order.
+    conditions: Vec<ConditionCoverage>,
+}
+
+impl Report {
+    /// ## Returns the coverage of each condition, in the order of [`Coverage::conditions`]
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::{expr::Expr, mcdc::{Coverage, Status}};
+    ///
+    /// let rule = "a || !a".parse::<Expr>().unwrap();
+    /// let report = Coverage::for_expr(&rule).report_for(&rule);
+    ///
+    /// assert!(report.conditions()[0].status() == &Status::Infeasible);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn conditions(&self) -> &[ConditionCoverage] {
+        &self.conditions
+    }
+
+    /// ## Returns the number of covered conditions
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::mcdc::Coverage;
+    ///
+    /// let mut coverage = Coverage::new();
+    /// coverage.record([("a", true)], true);
+    /// coverage.record([("a", false)], false);
+    ///
+    /// assert!(coverage.report().covered() == 1);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn covered(&self) -> usize {
+        self.conditions
+            .iter()
+            .filter(|condition| condition.is_covered())
+            .count()
+    }
+
+    /// ## Transforms every condition covered => `true`, otherwise `false`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::mcdc::Coverage;
+    ///
+    /// let mut coverage = Coverage::new();
+    /// coverage.record([("a", true)], true);
+    ///
+    /// assert!(!coverage.report().is_complete());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn is_complete(&self) -> bool {
+        self.conditions.iter().all(ConditionCoverage::is_covered)
+    }
+}
+
+impl Display for Report {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        for condition in &self.conditions {
+            writeln!(f, "{condition}")?;
+        }
+        writeln!(
+            f,
+            "MC/DC: {} of {} conditions covered",
+            self.covered(),
+            self.conditions.len()
+        )
+    }
+}
@@ -0,0 +1,872 @@
+//! Two-level (sum-of-products) logic minimization.
+//!
+//! A [`Function`] is a Boolean function of named variables, given as a [`TruthTable`] (or an
+//! [`Expr`]) together with an optional don't-care set: the assignments which can never occur, and
+//! whose output may therefore be chosen freely.  [`Function::minimize`] returns a [`Cover`], a
+//! sum of products which agrees with the function everywhere except the don't-care set.
+//!
+//! Functions of up to [`EXACT_VARIABLES`] variables are minimized exactly, by the Quine–McCluskey
+//! method and Petrick's method; larger functions are minimized heuristically, by Espresso-style
+//! expand, irredundant and reduce passes over cubes (products of literals).
+#![expect(
+    clippy::arbitrary_source_item_ordering,
+    reason = "types and fields follow the minimisation from the function to its cover, and helpers follow the steps which use them"
+)]
+#![expect(
+    clippy::single_call_fn,
+    reason = "each step of the minimisation is a function of its own"
+)]
+
+use crate::{
+    expr::{
+        truth_table::{assignments, bit},
+        Expr, TruthTable,
+    },
+    BoolVec,
+};
+use alloc::{
+    collections::BTreeSet,
+    string::{String, ToString as _},
+    vec::Vec,
+};
+use core::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    hash::{Hash, Hasher},
+    iter,
+};
+
+/// Largest number of variables for which [`Function::minimize`] finds an exact minimum.
+pub const EXACT_VARIABLES: usize = 8;
+
+/// `Function` is a Boolean function of named variables, with an optional don't-care set.
+/// ### Examples:
+/// ```
+/// use assert2::assert;
+/// use bool_ext::{expr::Expr, minimize::Function};
+///
+/// let rule = "a && b && !c || a && b && c || !a && b && c".parse::<Expr>().unwrap();
+///
+/// assert!(Function::from(&rule).minimize().to_string() == "b && c || a && b");
+/// ```
+#[derive(Clone, Debug)]
+pub struct Function {
+    /// The variables, in sorted order (the first being the most significant bit of an assignment).
+    variables: Vec<String>,
+    /// Indexed by assignment, in binary order: whether the function is `true` there.  Disjoint
+    /// from `dont_care`.
+    on: BoolVec,
+    /// Indexed by assignment, in binary order: whether the assignment can never occur.
+    dont_care: BoolVec,
+    /// The on-set as a sum of products, from which heuristic minimization starts.
+    on_cubes: Vec<Cube>,
+    /// The don't-care set as a sum of products.
+    dont_care_cubes: Vec<Cube>,
+}
+
+impl Function {
+    /// ## Constructs the function with on-set `on` and don't-care set `dont_care`
+    ///
+    /// The function is over the union of the tables' variables.  Assignments in both sets are
+    /// don't-cares.
+    /// ### Panics
+    /// Panics if the union of the tables' variables has `usize::BITS` or more members.
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::{expr::Expr, minimize::Function};
+    ///
+    /// // Digits 5..=9 of a 4-bit BCD digit `d3 d2 d1 d0` (values 10..=15 never occur)
+    /// let at_least_5 = "d3 || d2 && (d1 || d0)".parse::<Expr>().unwrap();
+    /// let invalid = "d3 && (d2 || d1)".parse::<Expr>().unwrap();
+    /// let function = Function::with_dont_cares(&at_least_5.truth_table(), &invalid.truth_table());
+    ///
+    /// assert!(function.minimize().to_string() == "d3 || d1 && d2 || d0 && d2");
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn with_dont_cares(on: &TruthTable, dont_care: &TruthTable) -> Self {
+        let variables = on
+            .variables()
+            .iter()
+            .chain(dont_care.variables())
+            .map(String::as_str)
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .map(String::from)
+            .collect::<Vec<_>>();
+        let mut on_set = BoolVec::new();
+        let mut dont_care_set = BoolVec::new();
+        let full = full_mask(variables.len());
+        for assignment in assignments(variables.len()) {
+            let value = |name: &str| {
+                variables
+                    .iter()
+                    .position(|variable| variable == name)
+                    .is_some_and(|index| bit(assignment, variables.len(), index))
+            };
+            let ignored = dont_care.output_under(value);
+            on_set.push(on.output_under(value) && !ignored);
+            dont_care_set.push(ignored);
+        }
+        let minterms = |set: &BoolVec| {
+            set.true_indices()
+                .map(|minterm| Cube {
+                    care: full,
+                    bits: minterm,
+                })
+                .collect()
+        };
+        Self {
+            on_cubes: minterms(&on_set),
+            dont_care_cubes: minterms(&dont_care_set),
+            variables,
+            on: on_set,
+            dont_care: dont_care_set,
+        }
+    }
+
+    /// ## Returns the variable names, in sorted order
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::{expr::Expr, minimize::Function};
+    ///
+    /// let rule = "b || a".parse::<Expr>().unwrap();
+    ///
+    /// assert!(Function::from(&rule).variables() == ["a", "b"]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn variables(&self) -> &[String] {
+        &self.variables
+    }
+
+    /// ## Returns a minimal sum of products: exact for up to [`EXACT_VARIABLES`] variables,
+    /// otherwise heuristic.
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::{expr::Expr, minimize::Function};
+    ///
+    /// let rule = "a && (b || !b && c) || !a && c".parse::<Expr>().unwrap();
+    /// let cover = Function::from(&rule).minimize();
+    ///
+    /// assert!(cover.is_exact());
+    /// assert!(cover.to_string() == "c || a && b");
+    /// assert!(cover.to_expr().truth_table().diff(&rule.truth_table()).is_empty());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn minimize(&self) -> Cover {
+        match self.variables.len() <= EXACT_VARIABLES {
+            true => self.minimize_exact(),
+            false => self.minimize_heuristic(),
+        }
+    }
+
+    /// ## Returns a sum of products with the fewest products, and among those the fewest
+    /// literals (Quine–McCluskey, then Petrick's method by branch and bound).
+    ///
+    /// Run time grows exponentially with the number of variables; prefer
+    /// [`minimize`](Self::minimize) unless an exact result is required.
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::{expr::Expr, minimize::Function};
+    ///
+    /// // The classic cyclic cover: six prime implicants, no essential ones, minimum of three
+    /// let rule = "!a && b || a && !b || !b && c || b && !c".parse::<Expr>().unwrap();
+    /// let cover = Function::from(&rule).minimize_exact();
+    ///
+    /// assert!(cover.len() == 3);
+    /// assert!(cover.to_expr().truth_table().diff(&rule.truth_table()).is_empty());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn minimize_exact(&self) -> Cover {
+        let primes = self.prime_implicants();
+        let minterms = self.on.true_indices().collect::<Vec<_>>();
+        self.cover(Petrick::new(&primes, &minterms).solve(), true)
+    }
+
+    /// ## Returns a small (though not necessarily minimal) sum of products, by Espresso-style
+    /// expand, irredundant and reduce passes.
+    ///
+    /// Starts from the on-set as given: the terms of [`Expr::to_dnf`] for a function constructed
+    /// from an [`Expr`], or the on-set's minterms for one constructed from [`TruthTable`]s.  Each
+    /// pass works on cubes (products of literals) alone.  Expand checks a cube against each cube of
+    /// a cover of the off-set (computed once, by recursive Shannon expansion), and irredundant
+    /// and reduce check whether a cube is contained in other cubes by a tautology check of their
+    /// cofactor.  The off-set cover and tautology checks take time exponential in the number of
+    /// variables in the worst case, but typically far less; every pass is otherwise polynomial
+    /// in the number of cubes and variables.  (Constructing the `Function` itself evaluates its
+    /// truth table, which has `2^n` rows for `n` variables.)
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::{expr::Expr, minimize::Function};
+    ///
+    /// let rule = "a && b && c || a && b && !c || a && !b && c".parse::<Expr>().unwrap();
+    /// let cover = Function::from(&rule).minimize_heuristic();
+    ///
+    /// assert!(!cover.is_exact());
+    /// assert!(cover.to_expr().truth_table().diff(&rule.truth_table()).is_empty());
+    /// assert!(cover.literal_count() == 4);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn minimize_heuristic(&self) -> Cover {
+        let full = full_mask(self.variables.len());
+        let dont_care = &self.dont_care_cubes;
+        let off = complement(&[self.on_cubes.as_slice(), dont_care].concat());
+        let mut cubes = self.on_cubes.clone();
+        let mut best: Option<(Cost, Vec<Cube>)> = None;
+        loop {
+            cubes = expand(cubes, &off);
+            cubes = irredundant(cubes, dont_care);
+            let cost = cost(&cubes);
+            if best
+                .as_ref()
+                .is_some_and(|&(best_cost, _)| cost >= best_cost)
+            {
+                break;
+            }
+            best = Some((cost, cubes.clone()));
+            cubes = reduce(cubes, dont_care, full);
+        }
+        self.cover(best.map(|(_, cover)| cover).unwrap_or_default(), false)
+    }
+
+    /// Returns the prime implicants of the on-set together with the don't-care set.
+    fn prime_implicants(&self) -> Vec<Cube> {
+        let full = full_mask(self.variables.len());
+        let mut current = (self.on.clone() | &self.dont_care)
+            .true_indices()
+            .map(|minterm| Cube {
+                care: full,
+                bits: minterm,
+            })
+            .collect::<BTreeSet<_>>();
+        let mut primes = Vec::new();
+        while !current.is_empty() {
+            let mut next = BTreeSet::new();
+            let mut combined = BTreeSet::new();
+            for &cube in &current {
+                for variable in single_bits(cube.care).filter(|variable| cube.bits & variable == 0)
+                {
+                    let partner = Cube {
+                        care: cube.care,
+                        bits: cube.bits | variable,
+                    };
+                    if current.contains(&partner) {
+                        next.insert(Cube {
+                            care: cube.care & !variable,
+                            bits: cube.bits,
+                        });
+                        combined.insert(cube);
+                        combined.insert(partner);
+                    }
+                }
+            }
+            primes.extend(current.difference(&combined).copied());
+            current = next;
+        }
+        primes
+    }
+
+    /// Returns the cover of `cubes`, which is minimum if `exact`.
+    fn cover(&self, mut cubes: Vec<Cube>, exact: bool) -> Cover {
+        // Larger cubes (fewer literals) first, then in truth-table order
+        cubes.sort_unstable_by_key(|cube| (cube.care.count_ones(), cube.care, cube.bits));
+        Cover {
+            variables: self.variables.clone(),
+            cubes,
+            exact,
+        }
+    }
+}
+
+/// Functions are equal when they have the same variables, on-set and don't-care set, however they
+/// were constructed.
+#[expect(clippy::missing_trait_methods, reason = "`ne` is the negation of `eq`")]
+impl PartialEq for Function {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        (&self.variables, &self.on, &self.dont_care)
+            == (&other.variables, &other.on, &other.dont_care)
+    }
+}
+
+#[expect(clippy::missing_trait_methods, reason = "a marker impl")]
+impl Eq for Function {}
+
+#[expect(
+    clippy::missing_trait_methods,
+    reason = "`hash_slice` hashes each element with `hash`"
+)]
+impl Hash for Function {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (&self.variables, &self.on, &self.dont_care).hash(state);
+    }
+}
+
+impl From<&TruthTable> for Function {
+    /// Constructs the function with on-set `table` and an empty don't-care set.
+    #[inline]
+    fn from(table: &TruthTable) -> Self {
+        Self::with_dont_cares(table, &Expr::Const(false).truth_table())
+    }
+}
+
+impl From<&Expr> for Function {
+    /// Constructs the function with on-set `expr` and an empty don't-care set.
+    ///
+    /// ### Panics
+    /// Panics if the expression has `usize::BITS` or more distinct variables.
+    #[expect(
+        clippy::pattern_type_mismatch,
+        reason = "`ref_patterns` rejects the `ref` binding of the alternative"
+    )]
+    #[inline]
+    fn from(expr: &Expr) -> Self {
+        let mut function = Self::from(&expr.truth_table());
+        let count = function.variables.len();
+        let mask_of = |name: &str| {
+            function
+                .variables
+                .iter()
+                .position(|variable| variable == name)
+                .map_or(0, |index| variable_mask(count, index))
+        };
+        let on_cubes = expr
+            .dnf_terms()
+            .iter()
+            .map(|term| {
+                term.iter().fold(Cube::UNIVERSE, |cube, (name, polarity)| {
+                    let mask = mask_of(name);
+                    Cube {
+                        care: cube.care | mask,
+                        bits: match polarity {
+                            true => cube.bits | mask,
+                            false => cube.bits,
+                        },
+                    }
+                })
+            })
+            .collect();
+        function.on_cubes = on_cubes;
+        function
+    }
+}
+
+/// `Cover` is a sum of products: an `||` of `&&`s of (possibly negated) variables.  See
+/// [`Function::minimize`].
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Cover {
+    /// The variables of the function covered.
+    variables: Vec<String>,
+    /// The products, larger (fewer literals) first.
+    cubes: Vec<Cube>,
+    /// Whether the cover is known to be minimum.
+    exact: bool,
+}
+
+impl Cover {
+    /// ## Returns the number of products
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::{expr::Expr, minimize::Function};
+    ///
+    /// let rule = "a && b || a && !b || c".parse::<Expr>().unwrap();
+    ///
+    /// assert!(Function::from(&rule).minimize().len() == 2);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.cubes.len()
+    }
+
+    /// ## Transforms no products (the constant `false`) => `true`, otherwise `false`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::{expr::Expr, minimize::Function};
+    ///
+    /// let rule = "a && !a".parse::<Expr>().unwrap();
+    ///
+    /// assert!(Function::from(&rule).minimize().is_empty());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.cubes.is_empty()
+    }
+
+    /// ## Returns the total number of literals over all products
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::{expr::Expr, minimize::Function};
+    ///
+    /// let rule = "a && b || !a && !b".parse::<Expr>().unwrap();
+    ///
+    /// assert!(Function::from(&rule).minimize().literal_count() == 4);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn literal_count(&self) -> usize {
+        self.cubes.iter().map(|cube| cube.literal_count()).sum()
+    }
+
+    /// ## Transforms a guaranteed-minimum cover => `true`, otherwise `false`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::{expr::Expr, minimize::Function};
+    ///
+    /// let rule = "a || b".parse::<Expr>().unwrap();
+    ///
+    /// assert!(Function::from(&rule).minimize_exact().is_exact());
+    /// assert!(!Function::from(&rule).minimize_heuristic().is_exact());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn is_exact(&self) -> bool {
+        self.exact
+    }
+
+    /// ## Returns the cover as an expression
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::{expr::Expr, minimize::Function};
+    ///
+    /// let rule = "a && !b || a && b".parse::<Expr>().unwrap();
+    ///
+    /// assert!(Function::from(&rule).minimize().to_expr() == Expr::var("a"));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn to_expr(&self) -> Expr {
+        let count = self.variables.len();
+        self.cubes
+            .iter()
+            .map(|cube| {
+                self.variables
+                    .iter()
+                    .enumerate()
+                    .filter(|&(index, _)| bit(cube.care, count, index))
+                    .map(|(index, name)| match bit(cube.bits, count, index) {
+                        true => Expr::var(name.as_str()),
+                        false => !Expr::var(name.as_str()),
+                    })
+                    .reduce(|lhs, rhs| lhs & rhs)
+                    .unwrap_or(Expr::Const(true))
+            })
+            .reduce(|lhs, rhs| lhs | rhs)
+            .unwrap_or(Expr::Const(false))
+    }
+}
+
+/// Renders the cover as an [`Expr`] (in C-style syntax).
+impl Display for Cover {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(&self.to_expr().to_string())
+    }
+}
+
+impl From<Cover> for Expr {
+    #[inline]
+    fn from(cover: Cover) -> Self {
+        cover.to_expr()
+    }
+}
+
+/// A product of literals: the set of assignments `a` with `a & care == bits`.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+struct Cube {
+    /// One bit per variable (the first variable most significant), as in `TruthTable`: whether the
+    /// variable is a literal of the cube.
+    care: usize,
+    /// One bit per variable: the value of each literal of the cube (`0` where not cared about).
+    bits: usize,
+}
+
+impl Cube {
+    /// The cube with no literals, containing every assignment.
+    const UNIVERSE: Self = Self { care: 0, bits: 0 };
+
+    /// Number of literals in the cube.
+    #[inline]
+    fn literal_count(self) -> usize {
+        // At most `usize::BITS`, so always representable
+        usize::try_from(self.care.count_ones()).unwrap_or(usize::MAX)
+    }
+
+    /// Transforms `minterm` belonging to the cube => `true`, otherwise `false`.
+    #[inline]
+    const fn contains(self, minterm: usize) -> bool {
+        minterm & self.care == self.bits
+    }
+
+    /// Transforms every assignment of `other` belonging to `self` => `true`, otherwise `false`.
+    #[inline]
+    const fn covers(self, other: Self) -> bool {
+        other.care & self.care == self.care && other.bits & self.care == self.bits
+    }
+
+    /// Transforms cubes sharing an assignment => `true`, otherwise `false`.
+    #[inline]
+    const fn intersects(self, other: Self) -> bool {
+        (self.bits ^ other.bits) & self.care & other.care == 0
+    }
+
+    /// Returns the cube restricted to the assignments of `other` (with `other`'s variables
+    /// dropped), assuming the two intersect.
+    #[inline]
+    const fn cofactor(self, other: Self) -> Self {
+        Self {
+            care: self.care & !other.care,
+            bits: self.bits & !other.care,
+        }
+    }
+}
+
+/// Mask with one bit per variable, of `count` variables.
+fn full_mask(count: usize) -> usize {
+    u32::try_from(count)
+        .ok()
+        .and_then(|bits| usize::checked_shl(1, bits))
+        .map_or(usize::MAX, |rows| rows.wrapping_sub(1))
+}
+
+/// Mask selecting variable `index` of `count` (the first variable being the most significant bit).
+fn variable_mask(count: usize, index: usize) -> usize {
+    count
+        .checked_sub(index)
+        .and_then(|shift| shift.checked_sub(1))
+        .and_then(|shift| u32::try_from(shift).ok())
+        .and_then(|shift| usize::checked_shl(1, shift))
+        .unwrap_or_default()
+}
+
+/// Returns the cofactor of each cube of `cubes` intersecting `cube`.
+fn cofactor(cubes: &[Cube], cube: Cube) -> Vec<Cube> {
+    cubes
+        .iter()
+        .filter(|other| other.intersects(cube))
+        .map(|other| other.cofactor(cube))
+        .collect()
+}
+
+/// Returns the lowest variable on which some cube of `cubes` depends, if any.
+fn split_variable(cubes: &[Cube]) -> Option<usize> {
+    let care = cubes.iter().fold(0, |care, cube| care | cube.care);
+    single_bits(care).next()
+}
+
+/// Transforms `cubes` covering every assignment => `true`, otherwise `false` (by Shannon
+/// expansion).
+fn is_tautology(cubes: &[Cube]) -> bool {
+    if cubes.contains(&Cube::UNIVERSE) {
+        return true;
+    }
+    split_variable(cubes).is_some_and(|variable| {
+        [0, variable].into_iter().all(|bits| {
+            is_tautology(&cofactor(
+                cubes,
+                Cube {
+                    care: variable,
+                    bits,
+                },
+            ))
+        })
+    })
+}
+
+/// Transforms `cube` contained in the union of `cubes` => `true`, otherwise `false`.
+fn is_covered(cube: Cube, cubes: &[Cube]) -> bool {
+    is_tautology(&cofactor(cubes, cube))
+}
+
+/// Returns a sum of products covering exactly the assignments which `cubes` does not (by Shannon
+/// expansion).
+fn complement(cubes: &[Cube]) -> Vec<Cube> {
+    if cubes.contains(&Cube::UNIVERSE) {
+        return Vec::new();
+    }
+    let Some(variable) = split_variable(cubes) else {
+        return alloc::vec![Cube::UNIVERSE];
+    };
+    [0, variable]
+        .into_iter()
+        .flat_map(|bits| {
+            let half = Cube {
+                care: variable,
+                bits,
+            };
+            complement(&cofactor(cubes, half))
+                .into_iter()
+                .map(move |cube| Cube {
+                    care: cube.care | variable,
+                    bits: cube.bits | bits,
+                })
+        })
+        .collect()
+}
+
+/// Returns the cubes of `cubes` other than the one at `index`, followed by `rest`.
+fn others(cubes: &[Cube], index: usize, rest: &[Cube]) -> Vec<Cube> {
+    cubes
+        .iter()
+        .enumerate()
+        .filter(|&(other, _)| other != index)
+        .map(|(_, &cube)| cube)
+        .chain(rest.iter().copied())
+        .collect()
+}
+
+/// Number of products, then number of literals.
+type Cost = (usize, usize);
+
+/// Returns the cost of the sum of products `cubes`.
+fn cost(cubes: &[Cube]) -> Cost {
+    (
+        cubes.len(),
+        cubes.iter().map(|cube| cube.literal_count()).sum(),
+    )
+}
+
+/// Returns an iterator over the set bits of `mask`, each as a single-bit mask.
+fn single_bits(mut mask: usize) -> impl Iterator<Item = usize> {
+    iter::from_fn(move || {
+        let lowest = mask & mask.wrapping_neg();
+        mask &= !lowest;
+        (lowest != 0).then_some(lowest)
+    })
+}
+
+/// Petrick's method, solved by branch and bound over the choice of prime implicants.
+struct Petrick<'primes> {
+    /// The prime implicants.
+    primes: &'primes [Cube],
+    /// Indexed by minterm: the primes covering it.
+    covering: Vec<Vec<usize>>,
+    /// Indexed by prime: the minterms it covers.
+    covered_by: Vec<Vec<usize>>,
+    /// Indexed by minterm: the number of chosen primes covering it.
+    coverage: Vec<usize>,
+    /// The primes chosen so far.
+    chosen: Vec<usize>,
+    /// Cost of the primes chosen so far.
+    cost: Cost,
+    /// The cheapest cover found, and its cost.
+    best: Option<(Cost, Vec<usize>)>,
+}
+
+impl<'primes> Petrick<'primes> {
+    /// Returns a search for the cheapest cover of `minterms` by `primes`.
+    fn new(primes: &'primes [Cube], minterms: &[usize]) -> Self {
+        let covering = minterms
+            .iter()
+            .map(|&minterm| {
+                primes
+                    .iter()
+                    .enumerate()
+                    .filter(|&(_, prime)| prime.contains(minterm))
+                    .map(|(index, _)| index)
+                    .collect()
+            })
+            .collect();
+        let covered_by = primes
+            .iter()
+            .map(|prime| {
+                minterms
+                    .iter()
+                    .enumerate()
+                    .filter(|&(_, &minterm)| prime.contains(minterm))
+                    .map(|(index, _)| index)
+                    .collect()
+            })
+            .collect();
+        Self {
+            primes,
+            covering,
+            covered_by,
+            coverage: alloc::vec![0; minterms.len()],
+            chosen: Vec::new(),
+            cost: (0, 0),
+            best: None,
+        }
+    }
+
+    /// Returns the chosen primes of the cheapest cover.
+    fn solve(mut self) -> Vec<Cube> {
+        self.search();
+        self.best
+            .map(|(_, chosen)| {
+                chosen
+                    .into_iter()
+                    .filter_map(|index| self.primes.get(index).copied())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Returns the covering primes of each minterm not covered by a chosen prime.
+    fn uncovered(&self) -> impl Iterator<Item = &Vec<usize>> {
+        self.covering
+            .iter()
+            .zip(&self.coverage)
+            .filter(|&(_, &coverage)| coverage == 0)
+            .map(|(covering, _)| covering)
+    }
+
+    /// Lower bound on the number of further primes needed: uncovered minterms with pairwise
+    /// disjoint sets of covering primes each need a distinct prime.
+    fn bound(&self) -> usize {
+        let mut used = alloc::vec![false; self.primes.len()];
+        self.uncovered()
+            .filter(|covering| {
+                let disjoint = covering
+                    .iter()
+                    .all(|&prime| !used.get(prime).copied().unwrap_or_default());
+                if disjoint {
+                    for &prime in *covering {
+                        if let Some(flag) = used.get_mut(prime) {
+                            *flag = true;
+                        }
+                    }
+                }
+                disjoint
+            })
+            .count()
+    }
+
+    /// Adds `prime` to the chosen primes.
+    fn choose(&mut self, prime: usize) {
+        self.update(prime, usize::saturating_add);
+        self.chosen.push(prime);
+    }
+
+    /// Removes the last chosen prime.
+    fn unchoose(&mut self) {
+        if let Some(prime) = self.chosen.pop() {
+            self.update(prime, usize::saturating_sub);
+        }
+    }
+
+    /// Applies `op(_, 1)` to the coverage of each minterm covered by `prime`, and
+    /// `op(_, literals)` to the cost.
+    fn update(&mut self, prime: usize, op: fn(usize, usize) -> usize) {
+        for &minterm in self.covered_by.get(prime).into_iter().flatten() {
+            if let Some(coverage) = self.coverage.get_mut(minterm) {
+                *coverage = op(*coverage, 1);
+            }
+        }
+        let literals = self
+            .primes
+            .get(prime)
+            .map_or(0, |cube| cube.literal_count());
+        self.cost = (op(self.cost.0, 1), op(self.cost.1, literals));
+    }
+
+    /// Extends the chosen primes to every cover cheaper than the best found, recording each.
+    fn search(&mut self) {
+        // Branch on the uncovered minterm with the fewest covering primes, so that essential
+        // primes are chosen first
+        let Some(candidates) = self
+            .uncovered()
+            .min_by_key(|covering| covering.len())
+            .cloned()
+        else {
+            if self.best.as_ref().is_none_or(|&(best, _)| self.cost < best) {
+                self.best = Some((self.cost, self.chosen.clone()));
+            }
+            return;
+        };
+        let bound = (self.cost.0.saturating_add(self.bound()), self.cost.1);
+        if self.best.as_ref().is_some_and(|&(best, _)| bound >= best) {
+            return;
+        }
+        for candidate in candidates {
+            self.choose(candidate);
+            self.search();
+            self.unchoose();
+        }
+    }
+}
+
+/// Expands each cube to a prime implicant by dropping literals while it remains disjoint from
+/// every cube of the `off`-set cover, then removes cubes covered by another.
+fn expand(cubes: Vec<Cube>, off: &[Cube]) -> Vec<Cube> {
+    let expanded = cubes
+        .into_iter()
+        .map(|cube| {
+            single_bits(cube.care).fold(cube, |current, variable| {
+                let raised = Cube {
+                    care: current.care & !variable,
+                    bits: current.bits & !variable,
+                };
+                match off.iter().any(|&blocked| raised.intersects(blocked)) {
+                    true => current,
+                    false => raised,
+                }
+            })
+        })
+        .collect::<BTreeSet<_>>();
+    expanded
+        .iter()
+        .filter(|&&cube| {
+            !expanded
+                .iter()
+                .any(|&other| other != cube && other.covers(cube))
+        })
+        .copied()
+        .collect()
+}
+
+/// Removes cubes, those with the most literals first, contained in the union of the remaining
+/// cubes and the `dont_care` cubes.
+fn irredundant(mut cubes: Vec<Cube>, dont_care: &[Cube]) -> Vec<Cube> {
+    cubes.sort_unstable_by_key(|cube| cube.care.count_ones());
+    let mut index = cubes.len();
+    while let Some(current) = index.checked_sub(1) {
+        index = current;
+        let redundant = cubes
+            .get(current)
+            .is_some_and(|&cube| is_covered(cube, &others(&cubes, current, dont_care)));
+        if redundant {
+            cubes.remove(current);
+        }
+    }
+    cubes
+}
+
+/// Shrinks each cube in turn to the smallest cube containing the assignments which neither the
+/// other cubes nor the `dont_care` cubes cover (dropping, one variable at a time, a half which
+/// they do cover), so that a subsequent [`expand`] may grow it in a different direction.
+fn reduce(mut cubes: Vec<Cube>, dont_care: &[Cube], full: usize) -> Vec<Cube> {
+    for current in 0..cubes.len() {
+        let others = others(&cubes, current, dont_care);
+        let Some(slot) = cubes.get_mut(current) else {
+            continue;
+        };
+        for variable in single_bits(full & !slot.care) {
+            let [low, high] = [0, variable].map(|bits| Cube {
+                care: slot.care | variable,
+                bits: slot.bits | bits,
+            });
+            match (is_covered(high, &others), is_covered(low, &others)) {
+                (true, _) => *slot = low,
+                (false, true) => *slot = high,
+                (false, false) => (),
+            }
+        }
+    }
+    cubes
+}
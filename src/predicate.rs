@@ -0,0 +1,340 @@
+//! Boolean combinators over predicates (`Fn(&T) -> bool`).
+//!
+//! [`PredicateExt`] composes predicates into named, zero-cost combinator types ([`And`], [`Or`],
+//! etc.), each of which is itself a [`Predicate`] and may be composed further.  Use
+//! [`PredicateExt::into_fn`] to hand a composed predicate to APIs expecting a closure, such as
+//! [`Iterator::filter`] or `Vec::retain`.
+
+use crate::BoolExt as _;
+
+/// Defines a predicate combining two predicates, `$lhs` and `$rhs`, by `$body`.
+macro_rules! binary_predicate {
+    ($(#[$meta:meta])* $name:ident, |$lhs:ident, $rhs:ident, $value:ident| $body:expr) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug)]
+        pub struct $name<P, Q> {
+            lhs: P,
+            rhs: Q,
+        }
+
+        impl<T: ?Sized, P: Predicate<T>, Q: Predicate<T>> Predicate<T> for $name<P, Q> {
+            #[inline]
+            fn test(&self, $value: &T) -> bool {
+                let ($lhs, $rhs) = (&self.lhs, &self.rhs);
+                $body
+            }
+        }
+    };
+}
+
+/// A condition which can be tested against a value of type `T`.
+///
+/// `Predicate` is implemented for every `Fn(&T) -> bool` and for each of the combinator types in
+/// this module.
+pub trait Predicate<T: ?Sized> {
+    /// Evaluates the predicate against `value`.
+    fn test(&self, value: &T) -> bool;
+}
+
+impl<T: ?Sized, F: Fn(&T) -> bool> Predicate<T> for F {
+    #[inline]
+    fn test(&self, value: &T) -> bool {
+        self(value)
+    }
+}
+
+binary_predicate!(
+    /// Predicate which holds if both operands hold (short-circuiting).  See
+    /// [`PredicateExt::and`].
+    And,
+    |lhs, rhs, value| lhs.test(value) && rhs.test(value)
+);
+
+binary_predicate!(
+    /// Predicate which holds if either operand holds (short-circuiting).  See
+    /// [`PredicateExt::or`].
+    Or,
+    |lhs, rhs, value| lhs.test(value) || rhs.test(value)
+);
+
+binary_predicate!(
+    /// Predicate which holds if exactly one operand holds.  See [`PredicateExt::xor`].
+    Xor,
+    |lhs, rhs, value| lhs.test(value) ^ rhs.test(value)
+);
+
+binary_predicate!(
+    /// Predicate which holds unless the left operand holds and the right operand does not
+    /// (short-circuiting).  See [`PredicateExt::implies`].
+    Implies,
+    |lhs, rhs, value| !lhs.test(value) || rhs.test(value)
+);
+
+binary_predicate!(
+    /// Predicate which holds unless both operands hold (short-circuiting).  See
+    /// [`PredicateExt::nand`].
+    Nand,
+    |lhs, rhs, value| !(lhs.test(value) && rhs.test(value))
+);
+
+binary_predicate!(
+    /// Predicate which holds if neither operand holds (short-circuiting).  See
+    /// [`PredicateExt::nor`].
+    Nor,
+    |lhs, rhs, value| !(lhs.test(value) || rhs.test(value))
+);
+
+/// Predicate which holds if its operand does not.  See [`PredicateExt::not`].
+#[derive(Clone, Copy, Debug)]
+pub struct Not<P> {
+    /// The negated predicate.
+    pred: P,
+}
+
+impl<T: ?Sized, P: Predicate<T>> Predicate<T> for Not<P> {
+    #[inline]
+    fn test(&self, value: &T) -> bool {
+        !self.pred.test(value)
+    }
+}
+
+/// `PredicateExt` trait defines Boolean functional combinators over [`Predicate`]s.
+#[expect(
+    clippy::module_name_repetitions,
+    clippy::arbitrary_source_item_ordering,
+    reason = "named and grouped after `BoolExt`"
+)]
+pub trait PredicateExt<T: ?Sized>: Predicate<T> + Sized {
+    /// ## Composes `self(x) && other(x)`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::{Predicate, PredicateExt};
+    ///
+    /// let is_even = |n: &i32| n % 2 == 0;
+    /// let is_positive = |n: &i32| *n > 0;
+    ///
+    /// let pred = is_even.and(is_positive);
+    /// assert!(pred.test(&2));
+    /// assert!(!pred.test(&-2));
+    /// ```
+    #[inline]
+    fn and<Q: Predicate<T>>(self, other: Q) -> And<Self, Q> {
+        And {
+            lhs: self,
+            rhs: other,
+        }
+    }
+
+    /// ## Composes `self(x) || other(x)`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::{Predicate, PredicateExt};
+    ///
+    /// let is_even = |n: &i32| n % 2 == 0;
+    /// let is_positive = |n: &i32| *n > 0;
+    ///
+    /// let pred = is_even.or(is_positive);
+    /// assert!(pred.test(&-2));
+    /// assert!(!pred.test(&-3));
+    /// ```
+    #[inline]
+    fn or<Q: Predicate<T>>(self, other: Q) -> Or<Self, Q> {
+        Or {
+            lhs: self,
+            rhs: other,
+        }
+    }
+
+    /// ## Composes `self(x) ^ other(x)`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::{Predicate, PredicateExt};
+    ///
+    /// let is_even = |n: &i32| n % 2 == 0;
+    /// let is_positive = |n: &i32| *n > 0;
+    ///
+    /// let pred = is_even.xor(is_positive);
+    /// assert!(pred.test(&3));
+    /// assert!(!pred.test(&2));
+    /// ```
+    #[inline]
+    fn xor<Q: Predicate<T>>(self, other: Q) -> Xor<Self, Q> {
+        Xor {
+            lhs: self,
+            rhs: other,
+        }
+    }
+
+    /// ## Composes `!self(x)`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::{Predicate, PredicateExt};
+    ///
+    /// let is_even = |n: &i32| n % 2 == 0;
+    ///
+    /// let pred = is_even.not();
+    /// assert!(pred.test(&3));
+    /// assert!(!pred.test(&2));
+    /// ```
+    #[inline]
+    fn not(self) -> Not<Self> {
+        Not { pred: self }
+    }
+
+    /// ## Composes `!self(x) || other(x)`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::{Predicate, PredicateExt};
+    ///
+    /// let is_admin = |user: &(&str, bool)| user.0 == "admin";
+    /// let is_verified = |user: &(&str, bool)| user.1;
+    ///
+    /// let pred = is_admin.implies(is_verified);
+    /// assert!(pred.test(&("guest", false)));
+    /// assert!(!pred.test(&("admin", false)));
+    /// ```
+    #[inline]
+    fn implies<Q: Predicate<T>>(self, other: Q) -> Implies<Self, Q> {
+        Implies {
+            lhs: self,
+            rhs: other,
+        }
+    }
+
+    /// ## Composes `!(self(x) && other(x))`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::{Predicate, PredicateExt};
+    ///
+    /// let is_even = |n: &i32| n % 2 == 0;
+    /// let is_positive = |n: &i32| *n > 0;
+    ///
+    /// let pred = is_even.nand(is_positive);
+    /// assert!(pred.test(&-2));
+    /// assert!(!pred.test(&2));
+    /// ```
+    #[inline]
+    fn nand<Q: Predicate<T>>(self, other: Q) -> Nand<Self, Q> {
+        Nand {
+            lhs: self,
+            rhs: other,
+        }
+    }
+
+    /// ## Composes `!(self(x) || other(x))`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::{Predicate, PredicateExt};
+    ///
+    /// let is_even = |n: &i32| n % 2 == 0;
+    /// let is_positive = |n: &i32| *n > 0;
+    ///
+    /// let pred = is_even.nor(is_positive);
+    /// assert!(pred.test(&-3));
+    /// assert!(!pred.test(&-2));
+    /// ```
+    #[inline]
+    fn nor<Q: Predicate<T>>(self, other: Q) -> Nor<Self, Q> {
+        Nor {
+            lhs: self,
+            rhs: other,
+        }
+    }
+
+    /// ## Transforms predicate into a closure, for use with `filter`, `retain`, etc.
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::PredicateExt;
+    ///
+    /// let is_even = |n: &i32| n % 2 == 0;
+    /// let is_positive = |n: &i32| *n > 0;
+    ///
+    /// let mut vec = vec![-2, -1, 0, 1, 2];
+    /// vec.retain(is_even.and(is_positive).into_fn());
+    /// assert!(vec == [2]);
+    /// ```
+    #[inline]
+    fn into_fn(self) -> impl Fn(&T) -> bool {
+        move |value| self.test(value)
+    }
+
+    /// ## Lifts predicate into `x` => `Some(some(x))` if `self(x)`, `None` otherwise
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::PredicateExt;
+    ///
+    /// let is_even = |n: &i32| n % 2 == 0;
+    ///
+    /// let halves = [1, 2, 3, 4].iter().filter_map(is_even.and_some_with(|n| n / 2));
+    /// assert!(halves.eq([1, 2]));
+    /// ```
+    #[inline]
+    fn and_some_with<F: Fn(&T) -> U, U>(self, some: F) -> impl Fn(&T) -> Option<U> {
+        move |value| self.test(value).and_some_with(|| some(value))
+    }
+
+    /// ## Lifts predicate into `x` => `None` if `self(x)`, `Some(some(x))` otherwise
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::PredicateExt;
+    ///
+    /// let is_even = |n: &i32| n % 2 == 0;
+    ///
+    /// let doubled_odds = [1, 2, 3, 4].iter().filter_map(is_even.or_some_with(|n| n * 2));
+    /// assert!(doubled_odds.eq([2, 6]));
+    /// ```
+    #[inline]
+    fn or_some_with<F: Fn(&T) -> U, U>(self, some: F) -> impl Fn(&T) -> Option<U> {
+        move |value| self.test(value).or_some_with(|| some(value))
+    }
+
+    /// ## Lifts predicate into `x` => `Err(err(x))` if `self(x)`, `Ok(())` otherwise
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::PredicateExt;
+    ///
+    /// let is_negative = |n: &i32| *n < 0;
+    /// let check = is_negative.and_err_with(|n| format!("{n} is negative"));
+    ///
+    /// assert!(check(&1) == Ok(()));
+    /// assert!(check(&-1) == Err(String::from("-1 is negative")));
+    /// ```
+    #[inline]
+    fn and_err_with<F: Fn(&T) -> E, E>(self, err: F) -> impl Fn(&T) -> Result<(), E> {
+        move |value| self.test(value).and_err_with(|| err(value))
+    }
+
+    /// ## Lifts predicate into `x` => `Ok(())` if `self(x)`, `Err(err(x))` otherwise
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::PredicateExt;
+    ///
+    /// let is_positive = |n: &i32| *n > 0;
+    /// let check = is_positive.or_err_with(|n| format!("{n} is not positive"));
+    ///
+    /// let res = [1, 2, 0, 3].iter().try_for_each(check);
+    /// assert!(res == Err(String::from("0 is not positive")));
+    /// ```
+    #[inline]
+    fn or_err_with<F: Fn(&T) -> E, E>(self, err: F) -> impl Fn(&T) -> Result<(), E> {
+        move |value| self.test(value).or_err_with(|| err(value))
+    }
+}
+
+#[expect(
+    clippy::missing_trait_methods,
+    reason = "the combinators are all provided"
+)]
+impl<T: ?Sized, P: Predicate<T>> PredicateExt<T> for P {}
@@ -0,0 +1,569 @@
+#![expect(
+    clippy::arbitrary_source_item_ordering,
+    reason = "parsing precedes writing, each followed by its helpers, and the error kinds follow the parse of a file from its header"
+)]
+#![expect(
+    clippy::question_mark_used,
+    reason = "the parser stops at the first malformed token, and the writer at the first failed write"
+)]
+#![expect(
+    clippy::single_call_fn,
+    reason = "the parser and writer are split into a function per piece of syntax"
+)]
+use super::{Cnf, Lit};
+use alloc::{string::String, vec::Vec};
+use core::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult, Write},
+    iter, mem,
+};
+
+/// Prefix of the comment lines recording variable names, as `c var <variable> <name>`.
+const NAME_COMMENT: &str = "var";
+
+/// Most variables a DIMACS header may declare, bounding the memory allocated for the variables
+/// occurring in the formula.
+pub const MAX_DIMACS_VARS: usize = 1 << 20;
+
+impl Lit {
+    /// ## Constructs the literal denoted by the DIMACS integer `lit` (`n` for variable `n - 1`,
+    /// `-n` for its negation), or `None` if `lit` is `0`.
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::sat::Lit;
+    ///
+    /// assert!(Lit::from_dimacs(-3) == Some(Lit::negative(2)));
+    /// assert!(Lit::from_dimacs(0) == None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn from_dimacs(lit: i64) -> Option<Self> {
+        let var = usize::try_from(lit.unsigned_abs()).ok()?.checked_sub(1)?;
+        Some(Self::new(var, lit > 0))
+    }
+
+    /// ## Returns the DIMACS integer denoting the literal (saturating at `i64::MAX`)
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::sat::Lit;
+    ///
+    /// assert!(Lit::negative(2).to_dimacs() == -3);
+    /// assert!(Lit::positive(0).to_dimacs() == 1);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn to_dimacs(self) -> i64 {
+        let var = i64::try_from(self.var())
+            .unwrap_or(i64::MAX)
+            .saturating_add(1);
+        match self.is_positive() {
+            true => var,
+            false => var.saturating_neg(),
+        }
+    }
+}
+
+impl Cnf {
+    /// ## Parses a formula in DIMACS CNF format
+    ///
+    /// Comment lines (`c ...`) are ignored, except those of the form `c var <variable> <name>`
+    /// written by [`write_dimacs`](Self::write_dimacs), which name a variable (see there for the
+    /// escapes a name may contain).  If there are none, each variable occurring in a clause is
+    /// named by its DIMACS number, so that it appears in the [`Assignment`](super::Assignment)
+    /// returned by [`solve`](Self::solve).  Input ends at a line starting with `%`, if any.
+    ///
+    /// The header may declare at most [`MAX_DIMACS_VARS`] variables, but the formula has only as
+    /// many as the greatest occurring in a clause or named, so that a header alone cannot cause a
+    /// large allocation.  Naming a variable twice, or giving two variables the same name, is an
+    /// error.
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::sat::{Cnf, DimacsErrorKind};
+    ///
+    /// let cnf = Cnf::parse_dimacs("c example\np cnf 4 2\n1 -3 0\n2 3 -1 0\n").unwrap();
+    /// let assignment = cnf.solve().unwrap();
+    ///
+    /// assert!(cnf.var_count() == 3);
+    /// assert!(assignment["1"] || !assignment["3"]);
+    ///
+    /// let cnf = Cnf::parse_dimacs("c var 2 x\np cnf 3 1\n-2 3 0\n").unwrap();
+    /// assert!(cnf.name(1) == Some("x"));
+    /// assert!(cnf.name(2) == None);
+    ///
+    /// let cnf = Cnf::parse_dimacs("p cnf 1048576 0\n").unwrap();
+    /// assert!(cnf.var_count() == 0);
+    ///
+    /// let err = Cnf::parse_dimacs("p cnf 2 1\n1 -3 0\n").unwrap_err();
+    /// assert!(err.to_string() == "2:3: variable 3 exceeds the 2 declared in the header");
+    ///
+    /// let err = Cnf::parse_dimacs("p cnf 99999999999999 0\n").unwrap_err();
+    /// assert!(err.kind() == &DimacsErrorKind::TooManyVariables(99_999_999_999_999));
+    ///
+    /// let err = Cnf::parse_dimacs("c var 1 x\nc var 1 y\np cnf 1 0\n").unwrap_err();
+    /// assert!(err.to_string() == "2:7: variable 1 is named twice");
+    ///
+    /// let err = Cnf::parse_dimacs("c var 1 x\nc var 2 x\np cnf 2 0\n").unwrap_err();
+    /// assert!(err.to_string() == "2:7: name `x` is already in use");
+    ///
+    /// let err = Cnf::parse_dimacs("c var 1 a\\b\np cnf 1 0\n").unwrap_err();
+    /// assert!(err.kind() == &DimacsErrorKind::InvalidName("a\\b".into()));
+    /// ```
+    #[inline]
+    pub fn parse_dimacs(input: &str) -> Result<Self, DimacsError> {
+        let mut cnf = Self::new();
+        let mut header = None;
+        let mut names = Vec::new();
+        let mut clause = Vec::new();
+        let mut clauses: usize = 0;
+        let mut end = Position { line: 1, column: 1 };
+        for (line, text) in (1..).zip(input.lines()) {
+            end = Position {
+                line,
+                column: text.chars().count().saturating_add(1),
+            };
+            let mut tokens = tokens(text).map(|(offset, token)| {
+                let column = text
+                    .get(..offset)
+                    .map_or(0, |prefix| prefix.chars().count())
+                    .saturating_add(1);
+                (Position { line, column }, token)
+            });
+            match text.trim_start().chars().next() {
+                None => continue,
+                Some('%') => break,
+                Some('c') => {
+                    if let Some((position, var, (name_position, escaped))) =
+                        name_comment(&mut tokens)
+                    {
+                        let name = unescape(escaped).ok_or_else(|| {
+                            DimacsError::new(
+                                name_position,
+                                DimacsErrorKind::InvalidName(String::from(escaped)),
+                            )
+                        })?;
+                        names.push((position, var, name));
+                    }
+                    continue;
+                }
+                Some('p') => {
+                    let (position, _) = tokens.next().unwrap_or((end, ""));
+                    if header.is_some() {
+                        return Err(DimacsError::new(position, DimacsErrorKind::DuplicateHeader));
+                    }
+                    let (vars, count) = parse_header(tokens).ok_or_else(|| {
+                        DimacsError::new(position, DimacsErrorKind::InvalidHeader)
+                    })?;
+                    if vars > MAX_DIMACS_VARS {
+                        return Err(DimacsError::new(
+                            position,
+                            DimacsErrorKind::TooManyVariables(vars),
+                        ));
+                    }
+                    header = Some((position, vars, count));
+                    continue;
+                }
+                Some(_) => (),
+            }
+            for (position, token) in tokens {
+                let Some((_, vars, _)) = header else {
+                    return Err(DimacsError::new(position, DimacsErrorKind::MissingHeader));
+                };
+                let Ok(number) = token.parse::<i64>() else {
+                    return Err(DimacsError::new(
+                        position,
+                        DimacsErrorKind::InvalidLiteral(String::from(token)),
+                    ));
+                };
+                match Lit::from_dimacs(number) {
+                    Some(lit) if lit.var() >= vars => {
+                        return Err(DimacsError::new(
+                            position,
+                            DimacsErrorKind::VariableOutOfRange {
+                                var: lit.var().saturating_add(1),
+                                declared: vars,
+                            },
+                        ));
+                    }
+                    Some(lit) => clause.push(lit),
+                    None => {
+                        cnf.clauses.push(mem::take(&mut clause));
+                        clauses = clauses.saturating_add(1);
+                    }
+                }
+            }
+        }
+        let Some((position, vars, declared)) = header else {
+            return Err(DimacsError::new(end, DimacsErrorKind::MissingHeader));
+        };
+        if !clause.is_empty() {
+            return Err(DimacsError::new(end, DimacsErrorKind::UnterminatedClause));
+        }
+        if clauses != declared {
+            return Err(DimacsError::new(
+                position,
+                DimacsErrorKind::ClauseCountMismatch {
+                    declared,
+                    found: clauses,
+                },
+            ));
+        }
+        cnf.name_vars(names, vars)?;
+        Ok(cnf)
+    }
+
+    /// Creates the variables parsed by [`parse_dimacs`](Self::parse_dimacs) (of `declared`), up
+    /// to the greatest occurring in a clause or named, and names them: as given by `names`, if
+    /// any, otherwise each occurring in a clause by its DIMACS number.
+    fn name_vars(
+        &mut self,
+        names: Vec<(Position, usize, String)>,
+        declared: usize,
+    ) -> Result<(), DimacsError> {
+        if let Some(&(position, number, _)) =
+            names.iter().find(|&&(_, number, _)| number > declared)
+        {
+            return Err(DimacsError::new(
+                position,
+                DimacsErrorKind::VariableOutOfRange {
+                    var: number,
+                    declared,
+                },
+            ));
+        }
+        let vars = self
+            .clauses
+            .iter()
+            .flatten()
+            .map(|lit| lit.var().saturating_add(1))
+            .chain(names.iter().map(|&(_, number, _)| number))
+            .max()
+            .unwrap_or_default();
+        self.names.resize(vars, None);
+
+        if names.is_empty() {
+            for lit in self.clauses.iter().flatten() {
+                if let Some(slot) = self.names.get_mut(lit.var()).filter(|slot| slot.is_none()) {
+                    let name = alloc::format!("{}", lit.var().saturating_add(1));
+                    self.vars.insert(name.clone(), lit.var());
+                    *slot = Some(name);
+                }
+            }
+            return Ok(());
+        }
+        for (position, number, name) in names {
+            let var = number.saturating_sub(1);
+            let Some(slot) = self.names.get_mut(var) else {
+                continue;
+            };
+            if slot.is_some() {
+                return Err(DimacsError::new(
+                    position,
+                    DimacsErrorKind::VariableNamedTwice(number),
+                ));
+            }
+            if self.vars.contains_key(&name) {
+                return Err(DimacsError::new(
+                    position,
+                    DimacsErrorKind::DuplicateName(name),
+                ));
+            }
+            self.vars.insert(name.clone(), var);
+            *slot = Some(name);
+        }
+        Ok(())
+    }
+
+    /// ## Writes the formula in DIMACS CNF format, recording the name of each named variable in
+    /// a `c var <variable> <name>` comment.
+    ///
+    /// So that it is a single token, a name is written with `\` as `\\`, each whitespace or
+    /// control character as `\u{<hex>}`, and (if empty) as `\&`.  No comments are written if
+    /// [`parse_dimacs`](Self::parse_dimacs) would name the variables in the same way without them
+    /// (each variable occurring in a clause, and no other, being named by its DIMACS number).
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::{expr::Expr, sat::Cnf};
+    ///
+    /// let cnf = Cnf::from(&"admin || !beta".parse::<Expr>().unwrap());
+    /// let mut dimacs = String::new();
+    /// cnf.write_dimacs(&mut dimacs).unwrap();
+    ///
+    /// assert!(dimacs.starts_with("c var 1 admin\nc var 2 beta\np cnf 3 4\n"));
+    /// assert!(Cnf::parse_dimacs(&dimacs) == Ok(cnf));
+    ///
+    /// let mut cnf = Cnf::from(&(Expr::var("3") & Expr::var("x")));
+    /// cnf.var("two words\\");
+    /// let mut dimacs = String::new();
+    /// cnf.write_dimacs(&mut dimacs).unwrap();
+    ///
+    /// assert!(dimacs.starts_with("c var 1 3\nc var 2 x\nc var 4 two\\u{20}words\\\\\n"));
+    /// assert!(Cnf::parse_dimacs(&dimacs) == Ok(cnf));
+    /// ```
+    #[inline]
+    pub fn write_dimacs<W: Write>(&self, out: &mut W) -> FmtResult {
+        if !self.is_named_by_number() {
+            for (var, slot) in self.names.iter().enumerate() {
+                if let Some(name) = slot.as_ref() {
+                    write!(out, "c {NAME_COMMENT} {} ", var.saturating_add(1))?;
+                    write_name(out, name)?;
+                    writeln!(out)?;
+                }
+            }
+        }
+        writeln!(out, "p cnf {} {}", self.names.len(), self.clauses.len())?;
+        for clause in &self.clauses {
+            for lit in clause {
+                write!(out, "{} ", lit.to_dimacs())?;
+            }
+            writeln!(out, "0")?;
+        }
+        Ok(())
+    }
+}
+
+#[expect(
+    clippy::multiple_inherent_impl,
+    reason = "the helpers are kept apart from the public methods above"
+)]
+impl Cnf {
+    /// Transforms each variable occurring in a clause, and no other, being named by its DIMACS
+    /// number => `true`, otherwise `false`.
+    fn is_named_by_number(&self) -> bool {
+        let mut occurring = alloc::vec![false; self.names.len()];
+        for lit in self.clauses.iter().flatten() {
+            if let Some(occurs) = occurring.get_mut(lit.var()) {
+                *occurs = true;
+            }
+        }
+        self.names
+            .iter()
+            .zip(occurring)
+            .enumerate()
+            .all(|(var, (slot, occurs))| {
+                slot.as_ref().map_or(!occurs, |name| {
+                    occurs && *name == alloc::format!("{}", var.saturating_add(1))
+                })
+            })
+    }
+}
+
+/// Writes `name` as a single token (see [`Cnf::write_dimacs`]).
+fn write_name<W: Write>(out: &mut W, name: &str) -> FmtResult {
+    if name.is_empty() {
+        return out.write_str("\\&");
+    }
+    for ch in name.chars() {
+        match ch {
+            '\\' => out.write_str("\\\\")?,
+            _ if ch.is_whitespace() || ch.is_control() => {
+                write!(out, "\\u{{{:x}}}", u32::from(ch))?
+            }
+            _ => out.write_char(ch)?,
+        }
+    }
+    Ok(())
+}
+
+/// Returns the name written as the token `token` by [`write_name`], or `None` if it contains an
+/// invalid escape.
+fn unescape(token: &str) -> Option<String> {
+    let mut name = String::new();
+    let mut chars = token.chars();
+    while let Some(ch) = chars.next() {
+        if ch != '\\' {
+            name.push(ch);
+            continue;
+        }
+        match chars.next()? {
+            '\\' => name.push('\\'),
+            '&' => (),
+            'u' => {
+                let (code, rest) = chars.as_str().strip_prefix('{')?.split_once('}')?;
+                name.push(
+                    u32::from_str_radix(code, 16)
+                        .ok()
+                        .and_then(char::from_u32)?,
+                );
+                chars = rest.chars();
+            }
+            _ => return None,
+        }
+    }
+    Some(name)
+}
+
+/// Returns the whitespace-separated tokens of `text`, with their byte offsets.
+fn tokens(text: &str) -> impl Iterator<Item = (usize, &str)> {
+    let mut rest = text;
+    let mut offset: usize = 0;
+    iter::from_fn(move || {
+        let trimmed = rest.trim_start();
+        offset = offset.saturating_add(rest.len().saturating_sub(trimmed.len()));
+        let len = trimmed.find(char::is_whitespace).unwrap_or(trimmed.len());
+        let (token, remainder) = trimmed.split_at_checked(len)?;
+        let start = offset;
+        offset = offset.saturating_add(len);
+        rest = remainder;
+        (!token.is_empty()).then_some((start, token))
+    })
+}
+
+/// Parses the `cnf <variables> <clauses>` following a header's `p`.
+fn parse_header<'text>(
+    mut tokens: impl Iterator<Item = (Position, &'text str)>,
+) -> Option<(usize, usize)> {
+    let mut next = || tokens.next().map(|(_, token)| token);
+    let format = next()?;
+    let vars = next()?.parse().ok()?;
+    let clauses = next()?.parse().ok()?;
+    (format == "cnf" && next().is_none()).then_some((vars, clauses))
+}
+
+/// Parses the `var <variable> <name>` following a name comment's `c`, if it is one, with the
+/// positions of its variable and its (escaped) name.
+fn name_comment<'text>(
+    tokens: &mut impl Iterator<Item = (Position, &'text str)>,
+) -> Option<(Position, usize, (Position, &'text str))> {
+    tokens.next()?;
+    (tokens.next()?.1 == NAME_COMMENT).then_some(())?;
+    let (position, number) = tokens.next()?;
+    let var = number.parse().ok().filter(|&var| var > 0)?;
+    let name = tokens.next()?;
+    tokens.next().is_none().then_some((position, var, name))
+}
+
+/// A 1-based position in the input.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+struct Position {
+    /// The line.
+    line: usize,
+    /// The column, in characters.
+    column: usize,
+}
+
+/// Kind of [`DimacsError`].
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum DimacsErrorKind {
+    /// A clause, or the end of the input, precedes the `p cnf` header.
+    MissingHeader,
+    /// A second `p` line.
+    DuplicateHeader,
+    /// A `p` line not of the form `p cnf <variables> <clauses>`.
+    InvalidHeader,
+    /// A header declaring more than [`MAX_DIMACS_VARS`] variables.
+    TooManyVariables(usize),
+    /// A token which is not an integer.
+    InvalidLiteral(String),
+    /// A literal whose variable exceeds the number declared in the header.
+    VariableOutOfRange {
+        /// DIMACS number of the variable.
+        var: usize,
+        /// Number of variables declared in the header.
+        declared: usize,
+    },
+    /// The input ends within a clause (with no terminating `0`).
+    UnterminatedClause,
+    /// The number of clauses differs from the number declared in the header.
+    ClauseCountMismatch {
+        /// Number of clauses declared in the header.
+        declared: usize,
+        /// Number of clauses found.
+        found: usize,
+    },
+    /// A `c var` comment naming a variable already named by another.
+    VariableNamedTwice(usize),
+    /// A `c var` comment giving a name already given to another variable.
+    DuplicateName(String),
+    /// A `c var` comment giving a name containing an invalid escape.
+    InvalidName(String),
+}
+
+impl Display for DimacsErrorKind {
+    #[expect(
+        clippy::pattern_type_mismatch,
+        reason = "`ref_patterns` rejects the `ref` bindings of the alternative"
+    )]
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::MissingHeader => f.write_str("expected a `p cnf` header"),
+            Self::DuplicateHeader => f.write_str("duplicate `p cnf` header"),
+            Self::InvalidHeader => {
+                f.write_str("invalid header; expected `p cnf <variables> <clauses>`")
+            }
+            Self::TooManyVariables(vars) => write!(
+                f,
+                "header declares {vars} variables, more than the {MAX_DIMACS_VARS} supported"
+            ),
+            Self::InvalidLiteral(token) => write!(f, "invalid literal `{token}`"),
+            Self::VariableOutOfRange { var, declared } => write!(
+                f,
+                "variable {var} exceeds the {declared} declared in the header"
+            ),
+            Self::UnterminatedClause => f.write_str("clause not terminated by `0`"),
+            Self::ClauseCountMismatch { declared, found } => write!(
+                f,
+                "header declares {declared} clauses, but {found} were found"
+            ),
+            Self::VariableNamedTwice(var) => write!(f, "variable {var} is named twice"),
+            Self::DuplicateName(name) => write!(f, "name `{name}` is already in use"),
+            Self::InvalidName(name) => write!(f, "invalid escape in name `{name}`"),
+        }
+    }
+}
+
+/// Error produced when parsing DIMACS CNF fails, recording the 1-based line and column (in
+/// characters) at which it was detected.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct DimacsError {
+    /// Where the error was detected.
+    position: Position,
+    /// What went wrong.
+    kind: DimacsErrorKind,
+}
+
+impl DimacsError {
+    /// Creates an error of `kind` at `position`.
+    const fn new(position: Position, kind: DimacsErrorKind) -> Self {
+        Self { position, kind }
+    }
+
+    /// Line (from `1`) at which the error was detected.
+    #[must_use]
+    #[inline]
+    pub const fn line(&self) -> usize {
+        self.position.line
+    }
+
+    /// Column (from `1`, in characters) at which the error was detected.
+    #[must_use]
+    #[inline]
+    pub const fn column(&self) -> usize {
+        self.position.column
+    }
+
+    /// Kind of error.
+    #[must_use]
+    #[inline]
+    pub const fn kind(&self) -> &DimacsErrorKind {
+        &self.kind
+    }
+}
+
+impl Display for DimacsError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}:{}: {}", self.line(), self.column(), self.kind)
+    }
+}
+
+#[expect(
+    clippy::missing_trait_methods,
+    reason = "a malformed file has no underlying cause"
+)]
+impl Error for DimacsError {}
@@ -0,0 +1,1230 @@
+//! Boolean satisfiability.
+//!
+//! A [`Cnf`] is a formula in conjunctive normal form over numbered variables, some of which are
+//! named.  [`Cnf::assert_expr`] adds an [`Expr`] to a formula by the Tseitin encoding, which (unlike
+//! [`Expr::to_cnf`]) grows only linearly with the expression.  [`Cnf::solve`] decides the formula
+//! with a conflict-driven clause-learning (CDCL) solver, using two watched literals per clause,
+//! first-UIP clause learning, VSIDS branching, phase saving, Luby restarts and periodic deletion
+//! of learnt clauses by literal block distance.
+//! [`Cnf::parse_dimacs`] and [`Cnf::write_dimacs`] exchange formulas with other solvers in the
+//! DIMACS CNF format.
+//! ### Examples:
+//! ```
+//! use assert2::assert;
+//! use bool_ext::{expr::Expr, sat::Cnf};
+//!
+//! let rule = "(admin || premium) && !suspended && (premium -> verified) && !verified"
+//!     .parse::<Expr>()
+//!     .unwrap();
+//!
+//! let assignment = Cnf::from(&rule).solve().unwrap();
+//! assert!(assignment["admin"]);
+//! assert!(rule.eval(&assignment) == Ok(true));
+//! ```
+#![expect(
+    clippy::mod_module_files,
+    reason = "`self_named_module_files` rejects the alternative, `sat.rs`"
+)]
+#![expect(
+    clippy::pub_use,
+    reason = "`dimacs` is private, so `sat` is the path of its items"
+)]
+#![expect(
+    clippy::arbitrary_source_item_ordering,
+    reason = "the formula's types precede the solver's, whose methods follow the CDCL loop from propagation through conflict analysis to restarts"
+)]
+#![expect(
+    clippy::pattern_type_mismatch,
+    reason = "trees and clauses are matched by reference, and `ref_patterns` rejects the `ref` bindings of the alternative"
+)]
+#![expect(
+    clippy::question_mark_used,
+    reason = "lookups of unnamed or unassigned variables, and pops of an empty heap, end early"
+)]
+
+/// Reading and writing the DIMACS CNF format.
+mod dimacs;
+
+pub use dimacs::{DimacsError, DimacsErrorKind, MAX_DIMACS_VARS};
+
+use crate::expr::Expr;
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+use core::{
+    cmp::Reverse,
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    mem,
+    ops::Not,
+};
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+/// A satisfying assignment of a [`Cnf`]'s named variables, keyed by name.  This is a `HashMap`
+/// with the `std` feature, otherwise a `BTreeMap`; either way it is an [`Env`](crate::expr::Env).
+#[cfg(feature = "std")]
+pub type Assignment = HashMap<String, bool>;
+
+/// A satisfying assignment of a [`Cnf`]'s named variables, keyed by name.  This is a `HashMap`
+/// with the `std` feature, otherwise a `BTreeMap`; either way it is an [`Env`](crate::expr::Env).
+#[cfg(not(feature = "std"))]
+pub type Assignment = BTreeMap<String, bool>;
+
+/// Number of conflicts per unit of the Luby restart sequence.
+const RESTART_INTERVAL: usize = 100;
+
+/// Factor by which variable activities decay after each conflict.
+const ACTIVITY_DECAY: f64 = 0.95;
+
+/// Activity above which all activities are rescaled (by [`ACTIVITY_RESCALE`]).
+const ACTIVITY_LIMIT: f64 = 1e100;
+
+/// Factor by which all activities are rescaled once one exceeds [`ACTIVITY_LIMIT`].
+const ACTIVITY_RESCALE: f64 = 1e-100;
+
+/// Number of conflicts before the first deletion of learnt clauses.
+const REDUCE_INTERVAL: usize = 2000;
+
+/// Growth, per deletion, of the number of conflicts between deletions of learnt clauses.
+const REDUCE_INCREMENT: usize = 300;
+
+/// Greatest literal block distance of a learnt clause which is never deleted.
+const GLUE_LBD: usize = 2;
+
+/// A variable, or the negation of a variable.
+/// ### Examples:
+/// ```
+/// use assert2::assert;
+/// use bool_ext::sat::Lit;
+///
+/// let lit = Lit::negative(3);
+///
+/// assert!(lit.var() == 3);
+/// assert!(!lit.is_positive());
+/// assert!(!lit == Lit::positive(3));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Lit(usize);
+
+#[expect(
+    clippy::multiple_inherent_impl,
+    reason = "the DIMACS numbering of literals is kept in `dimacs`"
+)]
+impl Lit {
+    /// ## Constructs the literal `var` (if `positive`) or `!var`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::sat::Lit;
+    ///
+    /// assert!(Lit::new(0, false) == Lit::negative(0));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn new(var: usize, positive: bool) -> Self {
+        match positive {
+            true => Self::positive(var),
+            false => Self::negative(var),
+        }
+    }
+
+    /// ## Constructs the literal `var`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::sat::Lit;
+    ///
+    /// assert!(Lit::positive(2).is_positive());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn positive(var: usize) -> Self {
+        Self(var << 1)
+    }
+
+    /// ## Constructs the literal `!var`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::sat::Lit;
+    ///
+    /// assert!(!Lit::negative(2).is_positive());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn negative(var: usize) -> Self {
+        Self((var << 1) | 1)
+    }
+
+    /// ## Returns the literal's variable
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::sat::Lit;
+    ///
+    /// assert!(Lit::negative(7).var() == 7);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn var(self) -> usize {
+        self.0 >> 1
+    }
+
+    /// ## Transforms `var` => `true`, `!var` => `false`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::sat::Lit;
+    ///
+    /// assert!(Lit::positive(0).is_positive());
+    /// assert!(!Lit::negative(0).is_positive());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn is_positive(self) -> bool {
+        self.0 & 1 == 0
+    }
+
+    /// Dense index of the literal, for per-literal tables.
+    #[inline]
+    const fn index(self) -> usize {
+        self.0
+    }
+}
+
+impl Not for Lit {
+    type Output = Self;
+
+    #[inline]
+    fn not(self) -> Self {
+        Self(self.0 ^ 1)
+    }
+}
+
+/// Error produced when a [`Cnf`] has no satisfying assignment.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[expect(
+    clippy::exhaustive_structs,
+    reason = "a unit error, constructed and matched by name"
+)]
+pub struct Unsatisfiable;
+
+impl Display for Unsatisfiable {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("formula is unsatisfiable")
+    }
+}
+
+#[expect(
+    clippy::missing_trait_methods,
+    reason = "unsatisfiability has no underlying cause"
+)]
+impl Error for Unsatisfiable {}
+
+/// `Cnf` is a Boolean formula in conjunctive normal form: an `&&` of clauses, each an `||` of
+/// [`Lit`]s.
+///
+/// Variables are numbered densely from `0`.  Variables created by [`var`](Self::var) are named,
+/// and appear in the [`Assignment`] returned by [`solve`](Self::solve); those created by
+/// [`fresh_var`](Self::fresh_var) (including the Tseitin encoding's auxiliaries) are not.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Cnf {
+    /// Indexed by variable: its name, `None` for an unnamed variable.
+    names: Vec<Option<String>>,
+    /// The variable of each name.
+    vars: BTreeMap<String, usize>,
+    /// The clauses, each an `||` of literals.
+    clauses: Vec<Vec<Lit>>,
+    /// Variable constrained to be `true`, used to encode constants.
+    truth: Option<Lit>,
+}
+
+#[expect(
+    clippy::multiple_inherent_impl,
+    reason = "reading and writing DIMACS is kept in `dimacs`"
+)]
+impl Cnf {
+    /// ## Constructs an empty (and so trivially satisfiable) `Cnf`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::sat::Cnf;
+    ///
+    /// assert!(Cnf::new().solve().is_ok());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            names: Vec::new(),
+            vars: BTreeMap::new(),
+            clauses: Vec::new(),
+            truth: None,
+        }
+    }
+
+    /// ## Returns the variable named `name`, creating it if it does not exist
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::sat::Cnf;
+    ///
+    /// let mut cnf = Cnf::new();
+    /// let admin = cnf.var("admin");
+    ///
+    /// assert!(cnf.var("admin") == admin);
+    /// assert!(cnf.name(admin) == Some("admin"));
+    /// ```
+    #[inline]
+    pub fn var(&mut self, name: &str) -> usize {
+        if let Some(&var) = self.vars.get(name) {
+            return var;
+        }
+        let var = self.names.len();
+        self.names.push(Some(String::from(name)));
+        self.vars.insert(String::from(name), var);
+        var
+    }
+
+    /// ## Returns a new, unnamed variable
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::sat::Cnf;
+    ///
+    /// let mut cnf = Cnf::new();
+    /// let aux = cnf.fresh_var();
+    ///
+    /// assert!(cnf.name(aux) == None);
+    /// assert!(cnf.var_count() == 1);
+    /// ```
+    #[inline]
+    pub fn fresh_var(&mut self) -> usize {
+        self.names.push(None);
+        self.names.len().saturating_sub(1)
+    }
+
+    /// ## Returns the name of `var`, or `None` if it is unnamed (or does not exist)
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::sat::Cnf;
+    ///
+    /// let mut cnf = Cnf::new();
+    /// let beta = cnf.var("beta");
+    ///
+    /// assert!(cnf.name(beta) == Some("beta"));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn name(&self, var: usize) -> Option<&str> {
+        self.names.get(var)?.as_deref()
+    }
+
+    /// ## Returns the variable named `name`, or `None` if there is no such variable
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::{expr::Expr, sat::Cnf};
+    ///
+    /// let cnf = Cnf::from(&"admin || beta".parse::<Expr>().unwrap());
+    ///
+    /// assert!(cnf.find_var("beta") == Some(1));
+    /// assert!(cnf.find_var("gamma") == None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn find_var(&self, name: &str) -> Option<usize> {
+        self.vars.get(name).copied()
+    }
+
+    /// ## Returns the number of variables
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::sat::{Cnf, Lit};
+    ///
+    /// let mut cnf = Cnf::new();
+    /// cnf.add_clause([Lit::positive(4)]);
+    ///
+    /// assert!(cnf.var_count() == 5);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn var_count(&self) -> usize {
+        self.names.len()
+    }
+
+    /// ## Returns the clauses
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::sat::{Cnf, Lit};
+    ///
+    /// let mut cnf = Cnf::new();
+    /// cnf.add_clause([Lit::positive(0), Lit::negative(1)]);
+    ///
+    /// assert!(cnf.clauses() == [vec![Lit::positive(0), Lit::negative(1)]]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn clauses(&self) -> &[Vec<Lit>] {
+        &self.clauses
+    }
+
+    /// ## Adds the clause `clause` (the `||` of its literals), creating unnamed variables as
+    /// needed for any literals beyond [`var_count`](Self::var_count).
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::sat::{Cnf, Lit};
+    ///
+    /// let mut cnf = Cnf::new();
+    /// let a = cnf.var("a");
+    /// cnf.add_clause([Lit::positive(a)]);
+    /// cnf.add_clause([Lit::negative(a)]);
+    ///
+    /// assert!(cnf.solve().is_err());
+    /// ```
+    #[inline]
+    pub fn add_clause<I: IntoIterator<Item = Lit>>(&mut self, clause: I) {
+        let lits = clause.into_iter().collect::<Vec<_>>();
+        if let Some(max) = lits.iter().map(|lit| lit.var()).max() {
+            if max >= self.names.len() {
+                self.names.resize(max.saturating_add(1), None);
+            }
+        }
+        self.clauses.push(lits);
+    }
+
+    /// ## Adds clauses requiring `expr` to be `true`, by the Tseitin encoding
+    ///
+    /// Each variable of `expr` becomes a named variable of the same name; each operator adds one
+    /// unnamed auxiliary variable and at most four clauses.
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::{expr::Expr, sat::Cnf};
+    ///
+    /// let mut cnf = Cnf::new();
+    /// cnf.assert_expr(&"a ^ b".parse::<Expr>().unwrap());
+    /// cnf.assert_expr(&"a <-> b".parse::<Expr>().unwrap());
+    ///
+    /// assert!(cnf.solve().is_err());
+    /// ```
+    #[inline]
+    pub fn assert_expr(&mut self, expr: &Expr) {
+        let root = self.encode(expr);
+        self.add_clause([root]);
+    }
+
+    /// ## Returns an assignment of the named variables satisfying every clause, or
+    /// [`Unsatisfiable`].
+    ///
+    /// Unconstrained named variables are assigned `false`.
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::{expr::Expr, sat::Cnf};
+    ///
+    /// let rule = "(a || b) && (!a || c) && (!b || c) && !c".parse::<Expr>().unwrap();
+    ///
+    /// assert!(Cnf::from(&rule).solve().is_err());
+    /// ```
+    #[inline]
+    pub fn solve(&self) -> Result<Assignment, Unsatisfiable> {
+        let values = Solver::new(self).solve()?;
+        Ok(self
+            .names
+            .iter()
+            .zip(values)
+            .filter_map(|(name, value)| Some((name.clone()?, value)))
+            .collect())
+    }
+
+    /// Returns a literal equivalent to `expr`, adding the clauses defining it.
+    fn encode(&mut self, expr: &Expr) -> Lit {
+        match expr {
+            Expr::Var(name) => Lit::positive(self.var(name)),
+            Expr::Const(value) => {
+                let truth = self.truth();
+                match value {
+                    true => truth,
+                    false => !truth,
+                }
+            }
+            Expr::Not(operand) => !self.encode(operand),
+            Expr::And(lhs, rhs) => self.encode_binary(lhs, rhs, |cnf, out, left, right| {
+                cnf.define_and(out, left, right);
+            }),
+            Expr::Or(lhs, rhs) => self.encode_binary(lhs, rhs, |cnf, out, left, right| {
+                cnf.define_and(!out, !left, !right);
+            }),
+            // `a -> b` is `!a || b`
+            Expr::Implies(lhs, rhs) => self.encode_binary(lhs, rhs, |cnf, out, left, right| {
+                cnf.define_and(!out, left, !right);
+            }),
+            Expr::Xor(lhs, rhs) => self.encode_binary(lhs, rhs, |cnf, out, left, right| {
+                cnf.define_xor(out, left, right);
+            }),
+            // `a <-> b` is `!(a ^ b)`
+            Expr::Iff(lhs, rhs) => self.encode_binary(lhs, rhs, |cnf, out, left, right| {
+                cnf.define_xor(!out, left, right);
+            }),
+        }
+    }
+
+    /// Returns a fresh literal for a binary operator over `lhs` and `rhs`, once `define` has added
+    /// the clauses defining it from the operands' literals.
+    fn encode_binary(
+        &mut self,
+        lhs: &Expr,
+        rhs: &Expr,
+        define: fn(&mut Self, Lit, Lit, Lit),
+    ) -> Lit {
+        let left = self.encode(lhs);
+        let right = self.encode(rhs);
+        let out = Lit::positive(self.fresh_var());
+        define(self, out, left, right);
+        out
+    }
+
+    /// Adds clauses defining `out <-> lhs && rhs`.
+    fn define_and(&mut self, out: Lit, lhs: Lit, rhs: Lit) {
+        self.add_clause([!out, lhs]);
+        self.add_clause([!out, rhs]);
+        self.add_clause([out, !lhs, !rhs]);
+    }
+
+    /// Adds clauses defining `out <-> lhs ^ rhs`.
+    fn define_xor(&mut self, out: Lit, lhs: Lit, rhs: Lit) {
+        self.add_clause([!out, lhs, rhs]);
+        self.add_clause([!out, !lhs, !rhs]);
+        self.add_clause([out, !lhs, rhs]);
+        self.add_clause([out, lhs, !rhs]);
+    }
+
+    /// Returns a literal constrained to be `true`.
+    fn truth(&mut self) -> Lit {
+        if let Some(truth) = self.truth {
+            return truth;
+        }
+        let truth = Lit::positive(self.fresh_var());
+        self.add_clause([truth]);
+        self.truth = Some(truth);
+        truth
+    }
+}
+
+impl From<&Expr> for Cnf {
+    /// Constructs the Tseitin encoding of `expr`.  See [`Cnf::assert_expr`].
+    #[inline]
+    fn from(expr: &Expr) -> Self {
+        let mut cnf = Self::new();
+        cnf.assert_expr(expr);
+        cnf
+    }
+}
+
+/// Conflict-driven clause-learning solver state.
+struct Solver {
+    /// Each clause of two or more literals watches its first two; a clause which is the reason
+    /// for an assignment has the assigned literal first.
+    clauses: Vec<Vec<Lit>>,
+    /// Indexed by clause: the literal block distance of a learnt clause, `None` for an original
+    /// one.
+    lbds: Vec<Option<usize>>,
+    /// Indexed by literal: the clauses watching it.
+    watches: Vec<Vec<Watch>>,
+    /// Indexed by variable: its value, `None` while unassigned.
+    values: Vec<Option<bool>>,
+    /// Indexed by variable: the decision level at which it was assigned.
+    levels: Vec<usize>,
+    /// Indexed by variable: the clause which implied its value, `None` for a decision.
+    reasons: Vec<Option<usize>>,
+    /// Indexed by variable: its VSIDS activity.
+    activity: Vec<f64>,
+    /// Indexed by variable: the value it was last assigned, with which it is next decided.
+    phases: Vec<bool>,
+    /// Scratch space for conflict analysis; all `false` between conflicts.
+    seen: Vec<bool>,
+    /// Unassigned (and some assigned) variables, by activity.
+    order: VarOrder,
+    /// The assigned literals, in order of assignment.
+    trail: Vec<Lit>,
+    /// Length of the trail at the start of each decision level.
+    trail_limits: Vec<usize>,
+    /// Position in the trail of the next assignment to propagate.
+    head: usize,
+    /// Activity added by the next bump, which grows as activities decay.
+    increment: f64,
+    /// Unit clauses found while loading.
+    units: Vec<Lit>,
+    /// Whether an empty clause was found while loading.
+    empty: bool,
+}
+
+impl Solver {
+    /// Returns a solver for the clauses of `cnf`.
+    #[expect(
+        clippy::single_call_fn,
+        reason = "a solver is built afresh by each `Cnf::solve`"
+    )]
+    fn new(cnf: &Cnf) -> Self {
+        let vars = cnf.var_count();
+        let mut solver = Self {
+            clauses: Vec::new(),
+            lbds: Vec::new(),
+            watches: alloc::vec![Vec::new(); vars.saturating_mul(2)],
+            values: alloc::vec![None; vars],
+            levels: alloc::vec![0; vars],
+            reasons: alloc::vec![None; vars],
+            activity: alloc::vec![f64::default(); vars],
+            phases: alloc::vec![false; vars],
+            seen: alloc::vec![false; vars],
+            order: VarOrder::new(vars),
+            trail: Vec::with_capacity(vars),
+            trail_limits: Vec::new(),
+            head: 0,
+            increment: 1.0,
+            units: Vec::new(),
+            empty: false,
+        };
+        cnf.clauses
+            .iter()
+            .for_each(|clause| solver.load(clause.clone()));
+        solver
+    }
+
+    /// Adds an original clause, normalising away duplicate literals and tautologies.
+    fn load(&mut self, mut clause: Vec<Lit>) {
+        clause.sort_unstable();
+        clause.dedup();
+        // Complementary literals are adjacent once sorted
+        if clause.windows(2).any(|pair| match *pair {
+            [lhs, rhs] => lhs == !rhs,
+            _ => false,
+        }) {
+            return;
+        }
+        match *clause.as_slice() {
+            [] => self.empty = true,
+            [unit] => self.units.push(unit),
+            _ => {
+                self.add(clause, None);
+            }
+        }
+    }
+
+    /// Adds a clause of two or more literals (learnt, with literal block distance `lbd`, if
+    /// `lbd` is `Some`), watching its first two, and returns its index.
+    fn add(&mut self, clause: Vec<Lit>, lbd: Option<usize>) -> usize {
+        let index = self.clauses.len();
+        self.watch(index, &clause);
+        self.clauses.push(clause);
+        self.lbds.push(lbd);
+        index
+    }
+
+    /// Adds clause `index`, `clause`, to the watch lists of its first two literals.
+    fn watch(&mut self, index: usize, clause: &[Lit]) {
+        if let &[first, second, ..] = clause {
+            for (watched, blocker) in [(first, second), (second, first)] {
+                if let Some(watches) = self.watches.get_mut(watched.index()) {
+                    watches.push(Watch {
+                        clause: index,
+                        blocker,
+                    });
+                }
+            }
+        }
+    }
+
+    /// Returns the value of `lit`, `None` while its variable is unassigned.
+    fn value(&self, lit: Lit) -> Option<bool> {
+        let value = self.values.get(lit.var()).copied().flatten()?;
+        Some(value == lit.is_positive())
+    }
+
+    /// Returns the decision level at which `var` was assigned.
+    fn level(&self, var: usize) -> usize {
+        self.levels.get(var).copied().unwrap_or_default()
+    }
+
+    /// Returns the number of decisions on the trail.
+    const fn decision_level(&self) -> usize {
+        self.trail_limits.len()
+    }
+
+    /// Makes `lit` `true` at the current decision level, as implied by clause `reason` (if
+    /// `Some`) or decided.
+    fn assign(&mut self, lit: Lit, reason: Option<usize>) {
+        let var = lit.var();
+        let level = self.decision_level();
+        if let Some(value) = self.values.get_mut(var) {
+            *value = Some(lit.is_positive());
+        }
+        if let Some(slot) = self.levels.get_mut(var) {
+            *slot = level;
+        }
+        if let Some(slot) = self.reasons.get_mut(var) {
+            *slot = reason;
+        }
+        self.trail.push(lit);
+    }
+
+    /// Returns the value of each variable in a satisfying assignment, or `Unsatisfiable`.
+    fn solve(mut self) -> Result<Vec<bool>, Unsatisfiable> {
+        if self.empty {
+            return Err(Unsatisfiable);
+        }
+        for unit in mem::take(&mut self.units) {
+            match self.value(unit) {
+                Some(true) => (),
+                Some(false) => return Err(Unsatisfiable),
+                None => self.assign(unit, None),
+            }
+        }
+        let mut conflicts: usize = 0;
+        let mut restarts: usize = 0;
+        let mut restart_at = RESTART_INTERVAL;
+        let mut reduce_interval = REDUCE_INTERVAL;
+        let mut reduce_at = REDUCE_INTERVAL;
+        loop {
+            if let Some(conflict) = self.propagate() {
+                if self.decision_level() == 0 {
+                    return Err(Unsatisfiable);
+                }
+                let (learnt, level) = self.analyze(conflict);
+                self.backjump(level);
+                self.learn(learnt);
+                self.decay();
+                conflicts = conflicts.saturating_add(1);
+                if conflicts >= reduce_at {
+                    self.reduce();
+                    reduce_interval = reduce_interval.saturating_add(REDUCE_INCREMENT);
+                    reduce_at = conflicts.saturating_add(reduce_interval);
+                }
+                if conflicts >= restart_at {
+                    restarts = restarts.saturating_add(1);
+                    restart_at =
+                        conflicts.saturating_add(luby(restarts).saturating_mul(RESTART_INTERVAL));
+                    self.backjump(0);
+                }
+            } else {
+                match self.decide() {
+                    Some(lit) => {
+                        self.trail_limits.push(self.trail.len());
+                        self.assign(lit, None);
+                    }
+                    None => {
+                        return Ok(self
+                            .values
+                            .iter()
+                            .map(|value| *value == Some(true))
+                            .collect())
+                    }
+                }
+            }
+        }
+    }
+
+    /// Propagates all pending assignments, returning the index of a conflicting clause, if any.
+    fn propagate(&mut self) -> Option<usize> {
+        while let Some(&lit) = self.trail.get(self.head) {
+            self.head = self.head.saturating_add(1);
+            let falsified = !lit;
+            let mut watchers = mem::take(self.watches.get_mut(falsified.index())?);
+            let mut conflict = None;
+            let mut position = 0;
+            while let Some(watch) = watchers.get_mut(position) {
+                if self.value(watch.blocker) == Some(true) {
+                    position = position.saturating_add(1);
+                    continue;
+                }
+                let index = watch.clause;
+                match self.visit(index, falsified) {
+                    Visit::Keep(blocker) => {
+                        watch.blocker = blocker;
+                        position = position.saturating_add(1);
+                    }
+                    Visit::Moved => {
+                        watchers.swap_remove(position);
+                    }
+                    Visit::Conflict => {
+                        conflict = Some(index);
+                        break;
+                    }
+                }
+            }
+            if let Some(watches) = self.watches.get_mut(falsified.index()) {
+                *watches = watchers;
+            }
+            if conflict.is_some() {
+                return conflict;
+            }
+        }
+        None
+    }
+
+    /// Updates clause `index`, one of whose watched literals `falsified` has become false.
+    fn visit(&mut self, index: usize, falsified: Lit) -> Visit {
+        let Some(clause) = self.clauses.get(index) else {
+            return Visit::Moved;
+        };
+        // Ensure that the falsified literal is second
+        let (first, second) = match *clause.as_slice() {
+            [first, second, ..] if first == falsified => (second, first),
+            [first, second, ..] => (first, second),
+            _ => return Visit::Keep(falsified),
+        };
+        if self.value(first) == Some(true) {
+            return Visit::Keep(first);
+        }
+        let replacement = clause
+            .iter()
+            .skip(2)
+            .position(|&lit| self.value(lit) != Some(false))
+            .map(|position| position.saturating_add(2));
+        let Some(slots) = self.clauses.get_mut(index) else {
+            return Visit::Moved;
+        };
+        if let [slot_first, slot_second, ..] = slots.as_mut_slice() {
+            *slot_first = first;
+            *slot_second = second;
+        }
+        match replacement {
+            Some(position) => {
+                slots.swap(1, position);
+                let watched = slots.get(1).copied();
+                if let Some(watches) = watched.and_then(|lit| self.watches.get_mut(lit.index())) {
+                    watches.push(Watch {
+                        clause: index,
+                        blocker: first,
+                    });
+                }
+                Visit::Moved
+            }
+            None if self.value(first) == Some(false) => Visit::Conflict,
+            None => {
+                self.assign(first, Some(index));
+                Visit::Keep(first)
+            }
+        }
+    }
+
+    /// Derives the first-UIP clause learnt from the conflicting clause `conflict`, and the level
+    /// to which to backjump.  The learnt clause's asserting literal is first, and a literal of the
+    /// backjump level (if any) second.
+    fn analyze(&mut self, conflict: usize) -> (Vec<Lit>, usize) {
+        let level = self.decision_level();
+        // Placeholder for the asserting literal
+        let mut learnt = alloc::vec![Lit(0)];
+        let mut pending: usize = 0;
+        let mut position = self.trail.len();
+        let mut reason = Some(conflict);
+        let mut skip_first = false;
+        loop {
+            let len = reason
+                .and_then(|index| self.clauses.get(index))
+                .map_or(0, Vec::len);
+            for offset in usize::from(skip_first)..len {
+                let Some(lit) = reason
+                    .and_then(|index| self.clauses.get(index))
+                    .and_then(|clause| clause.get(offset).copied())
+                else {
+                    break;
+                };
+                let var = lit.var();
+                let lit_level = self.level(var);
+                if self.seen.get(var).copied().unwrap_or(true) || lit_level == 0 {
+                    continue;
+                }
+                if let Some(seen) = self.seen.get_mut(var) {
+                    *seen = true;
+                }
+                self.bump(var);
+                match lit_level == level {
+                    true => pending = pending.saturating_add(1),
+                    false => learnt.push(lit),
+                }
+            }
+            // Walk back along the trail to the next literal involved in the conflict
+            let lit = loop {
+                position = position.saturating_sub(1);
+                match self.trail.get(position) {
+                    Some(&lit) if self.seen.get(lit.var()).copied().unwrap_or_default() => {
+                        break lit
+                    }
+                    Some(_) => (),
+                    None => break Lit(0),
+                }
+            };
+            if let Some(seen) = self.seen.get_mut(lit.var()) {
+                *seen = false;
+            }
+            pending = pending.saturating_sub(1);
+            if pending == 0 {
+                if let Some(first) = learnt.first_mut() {
+                    *first = !lit;
+                }
+                break;
+            }
+            reason = self.reasons.get(lit.var()).copied().flatten();
+            skip_first = true;
+        }
+        // Drop each literal whose falsity follows from that of the others
+        let mut marked = learnt.clone();
+        let mut kept = 1;
+        while let Some(&lit) = learnt.get(kept) {
+            match self.is_redundant(lit, &mut marked) {
+                true => {
+                    learnt.swap_remove(kept);
+                }
+                false => kept = kept.saturating_add(1),
+            }
+        }
+        for lit in marked {
+            if let Some(seen) = self.seen.get_mut(lit.var()) {
+                *seen = false;
+            }
+        }
+        // Move a literal of the highest remaining level into the second position
+        let backjump = learnt
+            .iter()
+            .enumerate()
+            .skip(1)
+            .max_by_key(|&(_, lit)| self.level(lit.var()))
+            .map(|(index, lit)| (index, self.level(lit.var())));
+        match backjump {
+            Some((index, target)) => {
+                learnt.swap(1, index);
+                (learnt, target)
+            }
+            None => (learnt, 0),
+        }
+    }
+
+    /// Transforms the falsity of `lit`, a literal of the clause being learnt, following from that of
+    /// the clause's other literals (and those assigned at level `0`) => `true`, otherwise `false`.
+    /// Literals found to follow likewise are marked `seen` and appended to `marked`.
+    fn is_redundant(&mut self, lit: Lit, marked: &mut Vec<Lit>) -> bool {
+        let unmarked = marked.len();
+        let mut pending = alloc::vec![lit];
+        while let Some(implied) = pending.pop() {
+            let Some(reason) = self.reasons.get(implied.var()).copied().flatten() else {
+                // A decision (or a level `0` unit) implies nothing
+                marked.truncate(unmarked);
+                return false;
+            };
+            let len = self.clauses.get(reason).map_or(0, Vec::len);
+            for offset in 1..len {
+                let Some(antecedent) = self
+                    .clauses
+                    .get(reason)
+                    .and_then(|clause| clause.get(offset).copied())
+                else {
+                    break;
+                };
+                let var = antecedent.var();
+                if self.seen.get(var).copied().unwrap_or(true) || self.level(var) == 0 {
+                    continue;
+                }
+                if self.reasons.get(var).copied().flatten().is_none() {
+                    for stale in marked.drain(unmarked..) {
+                        if let Some(seen) = self.seen.get_mut(stale.var()) {
+                            *seen = false;
+                        }
+                    }
+                    return false;
+                }
+                if let Some(seen) = self.seen.get_mut(var) {
+                    *seen = true;
+                }
+                marked.push(antecedent);
+                pending.push(antecedent);
+            }
+        }
+        true
+    }
+
+    /// Adds the learnt clause `learnt` and assigns its asserting literal.
+    fn learn(&mut self, learnt: Vec<Lit>) {
+        match learnt.as_slice() {
+            &[unit] => self.assign(unit, None),
+            &[asserting, ..] => {
+                let mut levels = learnt
+                    .iter()
+                    .map(|lit| self.level(lit.var()))
+                    .collect::<Vec<_>>();
+                levels.sort_unstable();
+                levels.dedup();
+                let index = self.add(learnt, Some(levels.len()));
+                self.assign(asserting, Some(index));
+            }
+            [] => (),
+        }
+    }
+
+    /// Undoes all assignments above decision level `level`.
+    fn backjump(&mut self, level: usize) {
+        let Some(&limit) = self.trail_limits.get(level) else {
+            return;
+        };
+        for lit in self.trail.drain(limit..) {
+            if let Some(value) = self.values.get_mut(lit.var()) {
+                *value = None;
+            }
+            if let Some(phase) = self.phases.get_mut(lit.var()) {
+                *phase = lit.is_positive();
+            }
+            self.order.push(lit.var(), &self.activity);
+        }
+        self.trail_limits.truncate(level);
+        self.head = self.trail.len();
+    }
+
+    /// Increases the activity of `var`, rescaling all activities if they grow too large.
+    #[expect(
+        clippy::float_arithmetic,
+        reason = "activities are floating-point, so that they can grow geometrically"
+    )]
+    fn bump(&mut self, var: usize) {
+        if let Some(activity) = self.activity.get_mut(var) {
+            *activity += self.increment;
+            if *activity > ACTIVITY_LIMIT {
+                self.activity
+                    .iter_mut()
+                    .for_each(|scaled| *scaled *= ACTIVITY_RESCALE);
+                self.increment *= ACTIVITY_RESCALE;
+            }
+            self.order.increase(var, &self.activity);
+        }
+    }
+
+    /// Decays all activities, by increasing the amount by which later bumps increase them.
+    #[expect(
+        clippy::float_arithmetic,
+        reason = "activities are floating-point, so that they can grow geometrically"
+    )]
+    fn decay(&mut self) {
+        self.increment /= ACTIVITY_DECAY;
+    }
+
+    /// Returns the unassigned variable of highest activity, in its saved phase.
+    fn decide(&mut self) -> Option<Lit> {
+        while let Some(var) = self.order.pop(&self.activity) {
+            if self.values.get(var).copied().flatten().is_none() {
+                let phase = self.phases.get(var).copied().unwrap_or_default();
+                return Some(Lit::new(var, phase));
+            }
+        }
+        None
+    }
+
+    /// Deletes the half of the learnt clauses of greatest literal block distance, sparing those of
+    /// distance at most [`GLUE_LBD`] and those which are the reason for an assignment.
+    fn reduce(&mut self) {
+        let mut candidates = self
+            .lbds
+            .iter()
+            .enumerate()
+            .filter_map(|(index, lbd)| Some((index, lbd.filter(|&value| value > GLUE_LBD)?)))
+            .filter(|&(index, _)| !self.is_locked(index))
+            .collect::<Vec<_>>();
+        candidates.sort_unstable_by_key(|&(_, lbd)| Reverse(lbd));
+        let mut deleted = alloc::vec![false; self.clauses.len()];
+        for &(index, _) in candidates.iter().take(candidates.len().div_euclid(2)) {
+            if let Some(flag) = deleted.get_mut(index) {
+                *flag = true;
+            }
+        }
+
+        // Compact the surviving clauses, then renumber the reasons and watches referring to them
+        let mut renumbered = Vec::with_capacity(self.clauses.len());
+        let clauses = mem::take(&mut self.clauses);
+        let lbds = mem::take(&mut self.lbds);
+        for ((clause, lbd), is_deleted) in clauses.into_iter().zip(lbds).zip(deleted) {
+            if is_deleted {
+                renumbered.push(None);
+            } else {
+                renumbered.push(Some(self.clauses.len()));
+                self.clauses.push(clause);
+                self.lbds.push(lbd);
+            }
+        }
+        for reason in &mut self.reasons {
+            *reason = reason.and_then(|old| renumbered.get(old).copied().flatten());
+        }
+        self.watches.iter_mut().for_each(Vec::clear);
+        for (index, clause) in mem::take(&mut self.clauses).into_iter().enumerate() {
+            self.watch(index, &clause);
+            self.clauses.push(clause);
+        }
+    }
+
+    /// Transforms clause `index` being the reason for the current assignment of its first literal
+    /// => `true`, otherwise `false`.
+    fn is_locked(&self, index: usize) -> bool {
+        self.clauses
+            .get(index)
+            .and_then(|clause| clause.first())
+            .is_some_and(|lit| {
+                self.value(*lit) == Some(true)
+                    && self.reasons.get(lit.var()).copied().flatten() == Some(index)
+            })
+    }
+}
+
+/// Binary max-heap of variables ordered by activity, from which VSIDS branching takes the most
+/// active.  A variable is removed when taken, and reinserted when unassigned by a backjump.
+struct VarOrder {
+    /// The variables, each at least as active as its children.
+    heap: Vec<usize>,
+    /// Indexed by variable: its position in `heap`, if present.
+    positions: Vec<Option<usize>>,
+}
+
+impl VarOrder {
+    /// Constructs a heap of the variables `0..vars`, all of (equal) activity `0`.
+    #[expect(
+        clippy::single_call_fn,
+        reason = "the heap is built once, with its solver"
+    )]
+    fn new(vars: usize) -> Self {
+        Self {
+            heap: (0..vars).collect(),
+            positions: (0..vars).map(Some).collect(),
+        }
+    }
+
+    /// Inserts `var`, if absent.
+    fn push(&mut self, var: usize, activity: &[f64]) {
+        if self.positions.get(var).copied().flatten().is_some() {
+            return;
+        }
+        let position = self.heap.len();
+        self.heap.push(var);
+        self.set_position(var, Some(position));
+        self.sift_up(position, activity);
+    }
+
+    /// Removes and returns the variable of highest activity.
+    fn pop(&mut self, activity: &[f64]) -> Option<usize> {
+        let last = self.heap.pop()?;
+        let Some(top) = self.heap.first_mut().map(|top| mem::replace(top, last)) else {
+            self.set_position(last, None);
+            return Some(last);
+        };
+        self.set_position(top, None);
+        self.set_position(last, Some(0));
+        self.sift_down(0, activity);
+        Some(top)
+    }
+
+    /// Restores the heap order after the activity of `var` has increased.
+    fn increase(&mut self, var: usize, activity: &[f64]) {
+        if let Some(position) = self.positions.get(var).copied().flatten() {
+            self.sift_up(position, activity);
+        }
+    }
+
+    /// Returns the activity of the variable at `position` in the heap.
+    fn activity_at(&self, position: usize, activity: &[f64]) -> f64 {
+        self.heap
+            .get(position)
+            .and_then(|&var| activity.get(var))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Moves the variable at `position` towards the root until its parent is at least as active.
+    fn sift_up(&mut self, mut position: usize, activity: &[f64]) {
+        while position > 0 {
+            let parent = position.saturating_sub(1).div_euclid(2);
+            if self.activity_at(position, activity) <= self.activity_at(parent, activity) {
+                break;
+            }
+            self.swap(position, parent);
+            position = parent;
+        }
+    }
+
+    /// Moves the variable at `position` towards the leaves until it is at least as active as its
+    /// children.
+    fn sift_down(&mut self, mut position: usize, activity: &[f64]) {
+        loop {
+            let left = position.saturating_mul(2).saturating_add(1);
+            let largest = [left, left.saturating_add(1)]
+                .into_iter()
+                .filter(|&child| child < self.heap.len())
+                .fold(position, |largest, child| {
+                    match self.activity_at(child, activity) > self.activity_at(largest, activity) {
+                        true => child,
+                        false => largest,
+                    }
+                });
+            if largest == position {
+                break;
+            }
+            self.swap(position, largest);
+            position = largest;
+        }
+    }
+
+    /// Swaps the variables at positions `lhs` and `rhs` of the heap.
+    fn swap(&mut self, lhs: usize, rhs: usize) {
+        self.heap.swap(lhs, rhs);
+        for position in [lhs, rhs] {
+            if let Some(&var) = self.heap.get(position) {
+                self.set_position(var, Some(position));
+            }
+        }
+    }
+
+    /// Records `position` as that of `var` in the heap.
+    fn set_position(&mut self, var: usize, position: Option<usize>) {
+        if let Some(slot) = self.positions.get_mut(var) {
+            *slot = position;
+        }
+    }
+}
+
+/// A clause watching a literal, with another of its literals (the blocker) whose truth means that
+/// the clause need not be visited when the watched literal becomes false.
+#[derive(Clone, Copy)]
+struct Watch {
+    /// Index of the clause.
+    clause: usize,
+    /// Another literal of the clause.
+    blocker: Lit,
+}
+
+/// Outcome of updating a clause whose watched literal has become false.
+enum Visit {
+    /// The clause still watches the literal, and its other watched literal is the one given.
+    Keep(Lit),
+    /// The clause now watches another literal.
+    Moved,
+    /// Every literal of the clause is false.
+    Conflict,
+}
+
+/// Returns the `index`th (from `1`) term of the Luby sequence `1, 1, 2, 1, 1, 2, 4, 1, ...`.
+#[expect(
+    clippy::single_call_fn,
+    reason = "the Luby sequence is a function of its own, apart from the restart policy using it"
+)]
+const fn luby(mut index: usize) -> usize {
+    loop {
+        // Find the smallest `2^k - 1` at least `index`
+        let mut size: usize = 1;
+        while size < index {
+            size = size.saturating_mul(2).saturating_add(1);
+        }
+        if size == index {
+            return (size >> 1).saturating_add(1);
+        }
+        index = index.saturating_sub(size >> 1);
+    }
+}
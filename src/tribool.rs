@@ -0,0 +1,413 @@
+use core::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    ops::{BitAnd, BitOr, BitXor, Not},
+};
+
+/// `Tribool` is a three-valued Boolean (`True`, `False` or `Unknown`).
+///
+/// `Tribool` follows Kleene's strong logic of indeterminacy.  It models conditions whose value may
+/// not be known, such as unset configuration values or SQL `NULL` columns.
+///
+/// The logical operators (`!`, `&`, `|`, `^`) yield `Unknown` only when the result genuinely
+/// depends on an unknown operand; `False & Unknown` is `False` and `True | Unknown` is `True`.
+///
+/// The `and_*` methods act on `True` and the `or_*` methods on `False`.  `Unknown` is neither,
+/// so it never takes the branch a definite value would: it yields no value (`*_some`), performs
+/// no side-effect (`*_do`), and fails closed with the error (`*_err`).
+/// ### Examples:
+/// ```
+/// use assert2::assert;
+/// use bool_ext::Tribool;
+///
+/// assert!((Tribool::False & Tribool::Unknown) == Tribool::False);
+/// assert!((Tribool::True & Tribool::Unknown) == Tribool::Unknown);
+/// assert!((Tribool::True | Tribool::Unknown) == Tribool::True);
+/// assert!(!Tribool::Unknown == Tribool::Unknown);
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[expect(
+    clippy::exhaustive_enums,
+    clippy::arbitrary_source_item_ordering,
+    reason = "the three values are the whole of Kleene logic, listed as usual"
+)]
+pub enum Tribool {
+    /// Definitely `true`.
+    True,
+    /// Definitely `false`.
+    False,
+    /// Either `true` or `false`; which is not known.
+    #[default]
+    Unknown,
+}
+
+#[expect(
+    clippy::arbitrary_source_item_ordering,
+    reason = "the combinators are grouped as in `BoolExt`"
+)]
+impl Tribool {
+    /// ## Transforms `True` => `true`, `False` | `Unknown` => `false`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::Tribool;
+    ///
+    /// assert!(Tribool::True.definitely());
+    /// assert!(!Tribool::Unknown.definitely());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn definitely(self) -> bool {
+        matches!(self, Self::True)
+    }
+
+    /// ## Transforms `True` | `Unknown` => `true`, `False` => `false`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::Tribool;
+    ///
+    /// assert!(Tribool::Unknown.possibly());
+    /// assert!(!Tribool::False.possibly());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn possibly(self) -> bool {
+        !matches!(self, Self::False)
+    }
+
+    /// ## Transforms `Unknown` => `true`, `True` | `False` => `false`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::Tribool;
+    ///
+    /// assert!(Tribool::Unknown.is_unknown());
+    /// assert!(!Tribool::False.is_unknown());
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn is_unknown(self) -> bool {
+        matches!(self, Self::Unknown)
+    }
+
+    /// ## Transforms `True` => `Some(true)`, `False` => `Some(false)`, `Unknown` => `None`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::Tribool;
+    ///
+    /// assert!(Tribool::False.to_option() == Some(false));
+    /// assert!(Tribool::Unknown.to_option() == None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn to_option(self) -> Option<bool> {
+        match self {
+            Self::True => Some(true),
+            Self::False => Some(false),
+            Self::Unknown => None,
+        }
+    }
+
+    /// ## Transforms `True` => `Some(T)`, `False` | `Unknown` => `None`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::Tribool;
+    ///
+    /// assert!(Tribool::True.and_some(42) == Some(42));
+    /// assert!(Tribool::Unknown.and_some(42) == None);
+    /// ```
+    #[inline]
+    pub fn and_some<T>(self, some: T) -> Option<T> {
+        match self {
+            Self::True => Some(some),
+            Self::False | Self::Unknown => None,
+        }
+    }
+
+    /// ## Transforms `True` => `Some(T)`, `False` | `Unknown` => `None`, lazily evaluated
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::Tribool;
+    ///
+    /// assert!(Tribool::True.and_some_with(|| 42) == Some(42));
+    /// assert!(Tribool::False.and_some_with(|| 42) == None);
+    /// ```
+    #[inline]
+    pub fn and_some_with<F: FnOnce() -> T, T>(self, some: F) -> Option<T> {
+        match self {
+            Self::True => Some(some()),
+            Self::False | Self::Unknown => None,
+        }
+    }
+
+    /// ## Transforms `False` => `Some(T)`, `True` | `Unknown` => `None`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::Tribool;
+    ///
+    /// assert!(Tribool::False.or_some(42) == Some(42));
+    /// assert!(Tribool::Unknown.or_some(42) == None);
+    /// ```
+    #[inline]
+    pub fn or_some<T>(self, some: T) -> Option<T> {
+        match self {
+            Self::False => Some(some),
+            Self::True | Self::Unknown => None,
+        }
+    }
+
+    /// ## Transforms `False` => `Some(T)`, `True` | `Unknown` => `None`, lazily evaluated
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::Tribool;
+    ///
+    /// assert!(Tribool::False.or_some_with(|| 42) == Some(42));
+    /// assert!(Tribool::True.or_some_with(|| 42) == None);
+    /// ```
+    #[inline]
+    pub fn or_some_with<F: FnOnce() -> T, T>(self, some: F) -> Option<T> {
+        match self {
+            Self::False => Some(some()),
+            Self::True | Self::Unknown => None,
+        }
+    }
+
+    /// ## Transforms `False` => `Ok(())`, `True` | `Unknown` => `Err(E)`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::Tribool;
+    ///
+    /// assert!(Tribool::True.and_err("found") == Err("found"));
+    /// assert!(Tribool::Unknown.and_err("found") == Err("found"));
+    /// assert!(Tribool::False.and_err("found") == Ok(()));
+    /// ```
+    #[inline]
+    pub fn and_err<E>(self, err: E) -> Result<(), E> {
+        match self {
+            Self::False => Ok(()),
+            Self::True | Self::Unknown => Err(err),
+        }
+    }
+
+    /// ## Transforms `False` => `Ok(())`, `True` | `Unknown` => `Err(E)`, lazily evaluated
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::Tribool;
+    ///
+    /// assert!(Tribool::True.and_err_with(|| "found") == Err("found"));
+    /// assert!(Tribool::Unknown.and_err_with(|| "found") == Err("found"));
+    /// assert!(Tribool::False.and_err_with(|| "found") == Ok(()));
+    /// ```
+    #[inline]
+    pub fn and_err_with<F: FnOnce() -> E, E>(self, err: F) -> Result<(), E> {
+        match self {
+            Self::False => Ok(()),
+            Self::True | Self::Unknown => Err(err()),
+        }
+    }
+
+    /// ## Transforms `True` => `Ok(())`, `False` | `Unknown` => `Err(E)`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::Tribool;
+    ///
+    /// assert!(Tribool::True.or_err("not confirmed") == Ok(()));
+    /// assert!(Tribool::Unknown.or_err("not confirmed") == Err("not confirmed"));
+    /// ```
+    #[inline]
+    pub fn or_err<E>(self, err: E) -> Result<(), E> {
+        match self {
+            Self::True => Ok(()),
+            Self::False | Self::Unknown => Err(err),
+        }
+    }
+
+    /// ## Transforms `True` => `Ok(())`, `False` | `Unknown` => `Err(E)`, lazily evaluated
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::Tribool;
+    ///
+    /// assert!(Tribool::True.or_err_with(|| "not confirmed") == Ok(()));
+    /// assert!(Tribool::False.or_err_with(|| "not confirmed") == Err("not confirmed"));
+    /// ```
+    #[inline]
+    pub fn or_err_with<F: FnOnce() -> E, E>(self, err: F) -> Result<(), E> {
+        match self {
+            Self::True => Ok(()),
+            Self::False | Self::Unknown => Err(err()),
+        }
+    }
+
+    /// ## Transforms `True` => `T`, `False` => `T`, `Unknown` => `T`
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::Tribool;
+    ///
+    /// assert!(Tribool::True.map("no", "maybe", "yes") == "yes");
+    /// assert!(Tribool::Unknown.map("no", "maybe", "yes") == "maybe");
+    /// ```
+    #[inline]
+    pub fn map<T>(self, f: T, u: T, t: T) -> T {
+        match self {
+            Self::True => t,
+            Self::False => f,
+            Self::Unknown => u,
+        }
+    }
+
+    /// ## Transforms `True` => `T`, `False` => `T`, `Unknown` => `T`, lazily evaluated
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::Tribool;
+    ///
+    /// let setting = Tribool::Unknown;
+    ///
+    /// assert!(setting.map_or_else(|| "disabled", || "default", || "enabled") == "default");
+    /// ```
+    #[inline]
+    pub fn map_or_else<F: FnOnce() -> T, G: FnOnce() -> T, U: FnOnce() -> T, T>(
+        self,
+        f: G,
+        u: U,
+        t: F,
+    ) -> T {
+        match self {
+            Self::True => t(),
+            Self::False => f(),
+            Self::Unknown => u(),
+        }
+    }
+
+    /// ## Perform side-effect if `True`, otherwise do nothing
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::Tribool;
+    ///
+    /// let mut count = 0;
+    /// Tribool::True.and_do(|| count += 1);
+    /// Tribool::Unknown.and_do(|| count += 1);
+    /// assert!(count == 1);
+    /// ```
+    #[inline]
+    #[expect(
+        clippy::return_self_not_must_use,
+        reason = "called for its side-effect; chaining on the result is optional"
+    )]
+    pub fn and_do<F: FnOnce()>(self, t: F) -> Self {
+        match self {
+            Self::True => t(),
+            Self::False | Self::Unknown => (),
+        }
+        self
+    }
+
+    /// ## Perform side-effect if `False`, otherwise do nothing
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::Tribool;
+    ///
+    /// let mut count = 0;
+    /// Tribool::False.or_do(|| count += 1);
+    /// Tribool::Unknown.or_do(|| count += 1);
+    /// assert!(count == 1);
+    /// ```
+    #[inline]
+    #[expect(
+        clippy::return_self_not_must_use,
+        reason = "called for its side-effect; chaining on the result is optional"
+    )]
+    pub fn or_do<F: FnOnce()>(self, f: F) -> Self {
+        !(!self).and_do(f)
+    }
+}
+
+impl Not for Tribool {
+    type Output = Self;
+
+    #[inline]
+    fn not(self) -> Self {
+        match self {
+            Self::True => Self::False,
+            Self::False => Self::True,
+            Self::Unknown => Self::Unknown,
+        }
+    }
+}
+
+impl BitAnd for Tribool {
+    type Output = Self;
+
+    #[inline]
+    fn bitand(self, rhs: Self) -> Self {
+        match (self, rhs) {
+            (Self::False, _) | (_, Self::False) => Self::False,
+            (Self::True, Self::True) => Self::True,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+impl BitOr for Tribool {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, rhs: Self) -> Self {
+        !(!self & !rhs)
+    }
+}
+
+impl BitXor for Tribool {
+    type Output = Self;
+
+    #[inline]
+    fn bitxor(self, rhs: Self) -> Self {
+        match (self.to_option(), rhs.to_option()) {
+            (Some(left), Some(right)) => Self::from(left ^ right),
+            _ => Self::Unknown,
+        }
+    }
+}
+
+impl From<bool> for Tribool {
+    #[inline]
+    fn from(value: bool) -> Self {
+        match value {
+            true => Self::True,
+            false => Self::False,
+        }
+    }
+}
+
+impl From<Option<bool>> for Tribool {
+    #[inline]
+    fn from(value: Option<bool>) -> Self {
+        value.map_or(Self::Unknown, Self::from)
+    }
+}
+
+impl From<Tribool> for Option<bool> {
+    #[inline]
+    fn from(value: Tribool) -> Self {
+        value.to_option()
+    }
+}
+
+impl Display for Tribool {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(self.map("false", "unknown", "true"))
+    }
+}
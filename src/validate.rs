@@ -0,0 +1,119 @@
+#![expect(
+    clippy::missing_trait_methods,
+    reason = "the impls for references and containers forward `validate_into` alone, as the \
+              provided `validate` is built on it"
+)]
+
+use crate::{Checks, Failures};
+use alloc::{boxed::Box, format, vec::Vec};
+
+/// `Validate` is implemented by types whose values can be checked against a set of rules,
+/// reporting every broken rule (as [`Checks`] does) rather than only the first.
+///
+/// With the `derive` feature, `#[derive(Validate)]` generates the implementation from
+/// `#[check(...)]` attributes; see
+#[cfg_attr(
+    feature = "derive",
+    doc = "[`derive(Validate)`](macro@crate::Validate) for the rules available."
+)]
+#[cfg_attr(
+    not(feature = "derive"),
+    doc = "`derive(Validate)` for the rules available."
+)]
+///
+/// `Validate` is implemented for `Option<T>` (validating the value, if any), and for slices and
+/// `Vec<T>` (validating each element in a scope named by its index).
+/// ### Examples:
+/// ```
+/// use assert2::assert;
+/// use bool_ext::{Checks, Validate};
+///
+/// struct Range { start: u32, end: u32 }
+///
+/// impl Validate for Range {
+///     fn validate_into(&self, checks: &mut Checks) {
+///         checks.check(self.start <= self.end, "`start` must not exceed `end`");
+///     }
+/// }
+///
+/// let ranges = vec![Range { start: 1, end: 2 }, Range { start: 4, end: 3 }];
+/// let failures = ranges.validate().unwrap_err();
+///
+/// assert!(failures.iter().map(|failure| failure.path()).eq(["1"]));
+/// ```
+#[expect(
+    clippy::arbitrary_source_item_ordering,
+    reason = "the required method comes first"
+)]
+pub trait Validate {
+    /// ## Checks every rule against `self`, recording failures in `checks`
+    ///
+    /// Implementations record failures relative to the current scope of `checks`, so that a
+    /// value nested in another is validated in a scope named after the field holding it.
+    fn validate_into(&self, checks: &mut Checks);
+
+    /// ## Transforms all rules satisfied => `Ok(())`, otherwise `Err(Failures)` listing every
+    /// broken rule.
+    /// ### Examples:
+    /// ```
+    /// use assert2::assert;
+    /// use bool_ext::Validate;
+    ///
+    /// struct Even(u32);
+    ///
+    /// impl Validate for Even {
+    ///     fn validate_into(&self, checks: &mut bool_ext::Checks) {
+    ///         checks.check_with(self.0 % 2 == 0, || format!("{} is odd", self.0));
+    ///     }
+    /// }
+    ///
+    /// assert!(Even(2).validate().is_ok());
+    /// assert!(Some(Even(3)).validate().unwrap_err().as_slice()[0].message() == "3 is odd");
+    /// assert!(None::<Even>.validate().is_ok());
+    /// ```
+    #[inline]
+    fn validate(&self) -> Result<(), Failures> {
+        let mut checks = Checks::new();
+        self.validate_into(&mut checks);
+        checks.finish()
+    }
+}
+
+impl<T: Validate + ?Sized> Validate for &T {
+    #[inline]
+    fn validate_into(&self, checks: &mut Checks) {
+        (**self).validate_into(checks);
+    }
+}
+
+impl<T: Validate + ?Sized> Validate for Box<T> {
+    #[inline]
+    fn validate_into(&self, checks: &mut Checks) {
+        (**self).validate_into(checks);
+    }
+}
+
+impl<T: Validate> Validate for Option<T> {
+    #[inline]
+    fn validate_into(&self, checks: &mut Checks) {
+        if let Some(value) = self.as_ref() {
+            value.validate_into(checks);
+        }
+    }
+}
+
+impl<T: Validate> Validate for [T] {
+    #[inline]
+    fn validate_into(&self, checks: &mut Checks) {
+        for (index, value) in self.iter().enumerate() {
+            value.validate_into(&mut checks.scope(&format!("{index}")));
+        }
+    }
+}
+
+impl<T: Validate> Validate for Vec<T> {
+    #[inline]
+    fn validate_into(&self, checks: &mut Checks) {
+        self.as_slice().validate_into(checks);
+    }
+}
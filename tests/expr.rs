@@ -0,0 +1,112 @@
+//! Checks the `Expr` transformations against evaluation, exhaustively over small expressions.
+#![cfg(feature = "alloc")]
+
+use bool_ext::expr::{Expr, MAX_DEPTH};
+
+const VARIABLES: [&str; 3] = ["a", "b", "c"];
+
+/// Every expression of at most `height` levels (a variable or constant being one level) over
+/// `VARIABLES`, `true` and `false`.
+fn expressions(height: usize) -> Vec<Expr> {
+    let leaves = VARIABLES
+        .into_iter()
+        .map(Expr::var)
+        .chain([Expr::Const(true), Expr::Const(false)]);
+    match height {
+        0 => Vec::new(),
+        1 => leaves.collect(),
+        _ => {
+            let operands = expressions(height - 1);
+            let mut exprs = leaves.collect::<Vec<_>>();
+            exprs.extend(operands.iter().map(|operand| !operand.clone()));
+            for lhs in &operands {
+                for rhs in &operands {
+                    exprs.extend([
+                        lhs.clone() & rhs.clone(),
+                        lhs.clone() | rhs.clone(),
+                        lhs.clone() ^ rhs.clone(),
+                        lhs.clone().implies(rhs.clone()),
+                        lhs.clone().iff(rhs.clone()),
+                    ]);
+                }
+            }
+            exprs
+        }
+    }
+}
+
+#[test]
+fn transformations_agree_with_eval_on_every_assignment() {
+    let exprs = expressions(3);
+    assert_eq!(exprs.len(), 5 + 135 + 5 * 135 * 135);
+
+    for expr in &exprs {
+        let transformed = [
+            ("simplify", expr.simplify()),
+            ("to_nnf", expr.to_nnf()),
+            ("to_cnf", expr.to_cnf()),
+            ("to_dnf", expr.to_dnf()),
+        ];
+        for assignment in 0_u8..1 << VARIABLES.len() {
+            let env = |name: &str| {
+                VARIABLES
+                    .iter()
+                    .position(|variable| *variable == name)
+                    .map(|index| assignment & 1 << index != 0)
+            };
+            let expected = expr.eval(&env);
+            for (name, transformed) in &transformed {
+                assert_eq!(
+                    transformed.eval(&env),
+                    expected,
+                    "`{expr}`.{name}() = `{transformed}` differs at assignment {assignment:03b}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn parser_limits_the_depth_of_binary_chains() {
+    for op in ["&&", "||", "^", "->", "<->"] {
+        let chain = |operators: usize| vec!["a"; operators + 1].join(&format!(" {op} "));
+
+        let deepest = chain(MAX_DEPTH).parse::<Expr>().unwrap();
+        let env = |_: &str| Some(true);
+        assert!(deepest.eval(&env).is_ok());
+        assert_eq!(deepest.to_string().parse::<Expr>(), Ok(deepest.clone()));
+        assert_eq!(deepest.simplify().eval(&env), deepest.eval(&env));
+        assert_eq!(
+            deepest
+                .eval_explained(&env)
+                .map(|explanation| explanation.value()),
+            deepest.eval(&env)
+        );
+        assert_eq!(deepest.compile().eval(&[true]), deepest.eval(&env));
+
+        for operators in [MAX_DEPTH + 1, 10_000] {
+            let err = chain(operators).parse::<Expr>().unwrap_err();
+            assert!(err.is_too_deep(), "`{op}` chain of {operators}: {err}");
+        }
+    }
+}
+
+#[test]
+fn parser_limits_the_depth_of_negations() {
+    let chain = vec!["a"; MAX_DEPTH].join(" && ");
+
+    // Negating a chain of `MAX_DEPTH - 1` operators leaves room for one `!`, whether the chain is
+    // negated as a whole or through its first operand
+    assert!(format!("!({chain})").parse::<Expr>().is_ok());
+    assert!(format!("!{chain}").parse::<Expr>().is_ok());
+
+    let err = format!("!!({chain})").parse::<Expr>().unwrap_err();
+    assert!(err.is_too_deep(), "{err}");
+    assert_eq!(err.span(), 0..1);
+
+    for negations in [2, MAX_DEPTH - 1] {
+        let input = format!("{}{chain}", "!".repeat(negations));
+        let err = input.parse::<Expr>().unwrap_err();
+        assert!(err.is_too_deep(), "{negations} negations: {err}");
+    }
+}
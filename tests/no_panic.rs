@@ -0,0 +1,44 @@
+//! Checks that, with the `no-panic` feature, code built on `bool_ext` links no panic paths.
+//!
+//! Builds `tests/no_panic_probe` (a `no_std` `cdylib` exercising every public `no_std` item, with
+//! `panic = "abort"` and fat LTO, so only reachable code survives) and searches the library for
+//! `core::panicking` symbols.  Any call which may panic reaches one of them.
+#![cfg(not(windows))]
+
+use std::{
+    env::consts::{DLL_PREFIX, DLL_SUFFIX},
+    fs,
+    path::Path,
+    process::Command,
+};
+
+/// Fragment common to the legacy and v0 manglings of every `core::panicking` symbol.
+const PANICKING: &[u8] = b"4core9panicking";
+
+#[test]
+fn probe_links_no_panic_paths() {
+    let probe = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/no_panic_probe");
+    let target_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("no_panic_probe");
+
+    let status = Command::new(env!("CARGO"))
+        .args(["build", "--release", "--quiet", "--manifest-path"])
+        .arg(probe.join("Cargo.toml"))
+        .arg("--target-dir")
+        .arg(&target_dir)
+        .status()
+        .expect("cargo should run");
+    assert!(status.success(), "probe should build");
+
+    let library = target_dir
+        .join("release")
+        .join(format!("{DLL_PREFIX}no_panic_probe{DLL_SUFFIX}"));
+    let bytes = fs::read(&library).expect("probe library should exist");
+
+    assert!(
+        !bytes
+            .windows(PANICKING.len())
+            .any(|window| window == PANICKING),
+        "{} contains a panic path",
+        library.display()
+    );
+}
@@ -0,0 +1,356 @@
+//! Exports functions exercising the whole panic-free surface of `bool_ext` (built without `alloc`
+//! and with `no-panic`), so that `tests/no_panic.rs` can check the linked code for panic paths.
+#![no_std]
+
+use bool_ext::{
+    bits::{self, BoolArray},
+    iter::{BoolIterExt, Runs, TrueIndices},
+    predicate::{And, Implies, Nand, Nor, Not, Or, Predicate, PredicateExt, Xor},
+    BoolExt, FalseError, OptionBoolExt, ResultBoolExt, Tribool, TrueError,
+};
+use core::{
+    fmt::{self, Write},
+    panic::PanicInfo,
+};
+
+/// Counts the bytes written to it, so that formatting is exercised without storing anything.
+struct Sink(usize);
+
+impl Write for Sink {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 = self.0.wrapping_add(s.len());
+        Ok(())
+    }
+}
+
+/// Number of bytes `value` formats to with `Display` and `Debug`.
+fn formatted_len(value: &(impl fmt::Display + fmt::Debug)) -> usize {
+    let mut sink = Sink(0);
+    let _ = write!(sink, "{value}{value:?}");
+    sink.0
+}
+
+/// Folds `results` into a bitmask, one bit per result being `Ok`.
+fn mask<E>(results: impl IntoIterator<Item = Result<(), E>>) -> u32 {
+    results
+        .into_iter()
+        .enumerate()
+        .fold(0, |mask, (i, result)| {
+            mask | u32::from(result.is_ok()) << (i % 32)
+        })
+}
+
+#[no_mangle]
+pub extern "C" fn probe_bool_ext(value: bool, counter: &mut u32) -> u32 {
+    let options = [
+        value.to_option().is_some(),
+        value.some(1).is_some(),
+        value.some_with(|| 1).is_some(),
+        value.and_some(1).is_some(),
+        value.and_some_with(|| 1).is_some(),
+        value.or_some(1).is_some(),
+        value.or_some_with(|| 1).is_some(),
+    ];
+    let results = [
+        value.to_result().map_err(|_| ()),
+        value.and_ok(()).map_err(|_| ()),
+        value.and_ok_with(|| ()).map_err(|_| ()),
+        value.or_ok(()).map_err(|_| ()),
+        value.or_ok_with(|| ()).map_err(|_| ()),
+        value.and_err(()),
+        value.and_err_with(|| ()),
+        value.or_err(()),
+        value.or_err_with(|| ()),
+        value.ok_or_err((), ()),
+        value.ok_or_err_with(|| (), || ()),
+        value.must().map_err(|_| ()),
+        value.must_not().map_err(|_| ()),
+    ];
+    value
+        .and_do(|| *counter = counter.wrapping_add(1))
+        .or_do(|| *counter = counter.wrapping_sub(1));
+    let tried = [
+        value.and_try_do(|| (*counter > 2).or_err(())),
+        value.or_try_do(|| (*counter > 3).or_err(())),
+    ];
+    let maps = value
+        .map(1_u32, 2)
+        .wrapping_add(value.map_or(3, || 4))
+        .wrapping_add(value.map_or_default(|| 5))
+        .wrapping_add(value.map_or_else(|| 6, || 7));
+    mask(results)
+        .wrapping_add(mask(tried.map(|result| result.map(|_| ()))))
+        .wrapping_add(u32::from(options.into_iter().all_true()))
+        .wrapping_add(maps)
+}
+
+#[no_mangle]
+pub extern "C" fn probe_option_bool_ext(value: bool, some: bool, counter: &mut u32) -> u32 {
+    let value = some.and_some(value);
+    let options = [
+        value.to_option().is_some(),
+        value.some(1).is_some(),
+        value.some_with(|| 1).is_some(),
+        value.and_some(1).is_some(),
+        value.and_some_with(|| 1).is_some(),
+        value.or_some(1).is_some(),
+        value.or_some_with(|| 1).is_some(),
+    ];
+    let results = [
+        value.to_result().unwrap_or(Ok(())).map_err(|_| ()),
+        value.and_ok(()).unwrap_or(Ok(())).map_err(|_| ()),
+        value.and_ok_with(|| ()).unwrap_or(Ok(())).map_err(|_| ()),
+        value.or_ok(()).unwrap_or(Ok(())).map_err(|_| ()),
+        value.or_ok_with(|| ()).unwrap_or(Ok(())).map_err(|_| ()),
+        value.and_err(()).unwrap_or(Ok(())),
+        value.and_err_with(|| ()).unwrap_or(Ok(())),
+        value.or_err(()).unwrap_or(Ok(())),
+        value.or_err_with(|| ()).unwrap_or(Ok(())),
+        value.ok_or_err((), ()).unwrap_or(Ok(())),
+        value.ok_or_err_with(|| (), || ()).unwrap_or(Ok(())),
+        value.must().unwrap_or(Ok(())).map_err(|_| ()),
+        value.must_not().unwrap_or(Ok(())).map_err(|_| ()),
+    ];
+    value
+        .and_do(|| *counter = counter.wrapping_add(1))
+        .or_do(|| *counter = counter.wrapping_sub(1));
+    let tried = [
+        value.and_try_do(|| (*counter > 2).or_err(())),
+        value.or_try_do(|| (*counter > 3).or_err(())),
+    ];
+    let maps = [
+        value.bool_map(1_u32, 2),
+        value.bool_map_or(3, || 4),
+        OptionBoolExt::bool_map_or_default(value, || 5),
+        value.bool_map_or_else(|| 6, || 7),
+    ];
+    mask(results)
+        .wrapping_add(mask(
+            tried.map(|tried| tried.unwrap_or(Ok(true)).map(|_| ())),
+        ))
+        .wrapping_add(u32::from(options.into_iter().any_true()))
+        .wrapping_add(maps.into_iter().flatten().fold(0, u32::wrapping_add))
+}
+
+#[no_mangle]
+pub extern "C" fn probe_result_bool_ext(value: bool, ok: bool, counter: &mut u32) -> u32 {
+    let value = ok.and_ok(value).map_err(|_| TrueError::new());
+    let options = [
+        value.to_option().ok().flatten().is_some(),
+        value.some(1).ok().flatten().is_some(),
+        value.some_with(|| 1).ok().flatten().is_some(),
+        value.and_some(1).ok().flatten().is_some(),
+        value.and_some_with(|| 1).ok().flatten().is_some(),
+        value.or_some(1).ok().flatten().is_some(),
+        value.or_some_with(|| 1).ok().flatten().is_some(),
+    ];
+    let value = value.map_err(|_| ());
+    let results = [
+        value.to_result(),
+        value.and_ok(()),
+        value.and_ok_with(|| ()),
+        value.or_ok(()),
+        value.or_ok_with(|| ()),
+        value.and_err(()),
+        value.and_err_with(|| ()),
+        value.or_err(()),
+        value.or_err_with(|| ()),
+        value.ok_or_err((), ()),
+        value.ok_or_err_with(|| (), || ()),
+        value.must(),
+        value.must_not(),
+    ];
+    let done = value
+        .and_do(|| *counter = counter.wrapping_add(1))
+        .or_do(|| *counter = counter.wrapping_sub(1));
+    let tried = [
+        done.and_try_do(|| (*counter > 2).or_err(())),
+        done.or_try_do(|| (*counter > 3).or_err(())),
+    ];
+    let maps = [
+        value.bool_map(1_u32, 2),
+        value.bool_map_or(3, || 4),
+        ResultBoolExt::bool_map_or_default(value, || 5),
+        value.bool_map_or_else(|| 6, || 7),
+    ];
+    mask(results)
+        .wrapping_add(mask(tried.map(|tried| tried.map(|_| ()))))
+        .wrapping_add(u32::from(options.into_iter().none_true()))
+        .wrapping_add(maps.into_iter().flatten().fold(0, u32::wrapping_add))
+}
+
+#[no_mangle]
+pub extern "C" fn probe_errors(value: bool) -> usize {
+    let false_error = FalseError::new();
+    let true_error = TrueError::default();
+    let located = usize::from(false_error.location().is_some() || true_error.location().is_some());
+    let () = false_error.into();
+    formatted_len(&FalseError::default())
+        .wrapping_add(formatted_len(&true_error))
+        .wrapping_add(usize::from(value.must() == Err(false_error)))
+        .wrapping_add(located)
+}
+
+#[no_mangle]
+pub extern "C" fn probe_iter(values: &[bool; 8], n: usize) -> usize {
+    let iter = || values.iter().copied();
+    let reductions = [
+        iter().all_true(),
+        iter().any_true(),
+        iter().none_true(),
+        iter().exactly_one(),
+        iter().at_most(n),
+        iter().parity(),
+        iter().majority(),
+    ];
+    let results = [
+        iter().to_result().map_err(|_| ()),
+        iter().or_err_with(|_| ()),
+        iter().and_err_with(|_| ()),
+    ];
+    let true_indices: TrueIndices<_> = iter().true_indices();
+    let runs: Runs<_> = iter().runs();
+    iter()
+        .count_true()
+        .wrapping_add(iter().first_true().unwrap_or(n))
+        .wrapping_add(iter().first_false().unwrap_or(n))
+        .wrapping_add(reductions.into_iter().count_true())
+        .wrapping_add(mask(results) as usize)
+        .wrapping_add(true_indices.fold(0, usize::wrapping_add))
+        .wrapping_add(
+            runs.map(|(value, range)| usize::from(value) ^ range.end)
+                .sum::<usize>(),
+        )
+}
+
+#[no_mangle]
+pub extern "C" fn probe_predicates(value: i32) -> u32 {
+    let positive = |n: &i32| *n > 0;
+    let even = |n: &i32| n % 2 == 0;
+    let and: And<_, _> = positive.and(even);
+    let or: Or<_, _> = positive.or(even);
+    let xor: Xor<_, _> = positive.xor(even);
+    let not: Not<_> = positive.not();
+    let implies: Implies<_, _> = positive.implies(even);
+    let nand: Nand<_, _> = positive.nand(even);
+    let nor: Nor<_, _> = positive.nor(even);
+    let tests = [
+        and.test(&value),
+        or.test(&value),
+        xor.test(&value),
+        not.test(&value),
+        implies.test(&value),
+        nand.test(&value),
+        nor.test(&value),
+        positive.into_fn()(&value),
+    ];
+    let results = [
+        positive.and_err_with(|_| ())(&value),
+        positive.or_err_with(|_| ())(&value),
+    ];
+    u32::try_from(tests.into_iter().count_true())
+        .unwrap_or(0)
+        .wrapping_add(mask(results))
+        .wrapping_add(positive.and_some_with(|n| n.unsigned_abs())(&value).unwrap_or(0))
+        .wrapping_add(even.or_some_with(|n| n.unsigned_abs())(&value).unwrap_or(0))
+}
+
+#[no_mangle]
+pub extern "C" fn probe_tribool(value: u8, other: Option<&bool>, counter: &mut u32) -> u32 {
+    let value = match value {
+        0 => Tribool::False,
+        1 => Tribool::True,
+        _ => Tribool::default(),
+    };
+    let other = Tribool::from(other.copied());
+    let combined = !(value & other) | (value ^ Tribool::from(true));
+    let options = [
+        value.and_some(1).is_some(),
+        value.and_some_with(|| 1).is_some(),
+        value.or_some(1).is_some(),
+        value.or_some_with(|| 1).is_some(),
+        Option::<bool>::from(combined).is_some(),
+        combined.to_option().is_some(),
+        value.definitely(),
+        value.possibly(),
+        value.is_unknown(),
+    ];
+    let results = [
+        value.and_err(()),
+        value.and_err_with(|| ()),
+        value.or_err(()),
+        value.or_err_with(|| ()),
+    ];
+    value
+        .and_do(|| *counter = counter.wrapping_add(1))
+        .or_do(|| *counter = counter.wrapping_sub(1));
+    mask(results)
+        .wrapping_add(u32::try_from(options.into_iter().count_true()).unwrap_or(0))
+        .wrapping_add(value.map(1, 2, 3))
+        .wrapping_add(other.map_or_else(|| 4, || 5, || 6))
+        .wrapping_add(u32::try_from(formatted_len(&combined)).unwrap_or(0))
+        .wrapping_add(u32::from(value == other))
+}
+
+#[no_mangle]
+pub extern "C" fn probe_bool_array(words: &[u64; 2], index: usize, counter: &mut u32) -> usize {
+    let mut flags = BoolArray::<100, 2>::from_words(*words);
+    let set = flags.try_set(index, true).is_some();
+    let mut other: BoolArray<100, 2> = flags.iter().skip(1).collect();
+    other.fill(index % 2 == 0);
+    other ^= &flags;
+    other &= !flags;
+    other |= flags & &other;
+    other = (other | &flags) ^ flags;
+    let iter: bits::Iter<'_> = other.iter();
+    let true_indices: bits::TrueIndices<'_> = flags.true_indices();
+    let done = flags.all_do(|| *counter = counter.wrapping_add(1))
+        || flags.any_do(|| *counter = counter.wrapping_sub(1));
+    let tests = [
+        set,
+        done,
+        flags.get(index).unwrap_or(false),
+        flags.all(),
+        flags.any(),
+        flags.none(),
+        flags.is_empty(),
+        flags == BoolArray::default(),
+        (&flags).into_iter().eq(BoolArray::<100, 2>::new().iter()),
+    ];
+    flags
+        .count_ones()
+        .wrapping_add(flags.count_zeros())
+        .wrapping_add(flags.first_true().unwrap_or(index))
+        .wrapping_add(flags.len())
+        .wrapping_add(BoolArray::<100, 2>::LEN)
+        .wrapping_add(
+            flags
+                .words()
+                .iter()
+                .fold(0, |sum, &word| sum ^ word as usize),
+        )
+        .wrapping_add(iter.len())
+        .wrapping_add(true_indices.fold(0, usize::wrapping_add))
+        .wrapping_add(tests.into_iter().count_true())
+        .wrapping_add(formatted_len(&DebugOnly(other)))
+}
+
+/// Formats a `BoolArray` (which implements only `Debug`) for [`formatted_len`].
+struct DebugOnly(BoolArray<100, 2>);
+
+impl fmt::Display for DebugOnly {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self.0)
+    }
+}
+
+impl fmt::Debug for DebugOnly {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[panic_handler]
+fn panic(_info: &PanicInfo<'_>) -> ! {
+    loop {}
+}
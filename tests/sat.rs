@@ -0,0 +1,111 @@
+//! Checks the SAT solver against exhaustive search, and its models against every clause, over
+//! random 3-SAT formulas, and that DIMACS output round-trips.
+#![cfg(feature = "alloc")]
+
+use bool_ext::{
+    expr::Expr,
+    sat::{Cnf, Lit},
+};
+
+/// Xorshift generator, so that the formulas are the same on every run.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self, bound: usize) -> usize {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 % bound as u64) as usize
+    }
+}
+
+/// A random formula of `clauses` clauses of three literals over `vars` named variables.
+fn random_3sat(rng: &mut Rng, vars: usize, clauses: usize) -> Cnf {
+    let mut cnf = Cnf::new();
+    for var in 0..vars {
+        cnf.var(&format!("v{var}"));
+    }
+    for _ in 0..clauses {
+        cnf.add_clause((0..3).map(|_| Lit::new(rng.next(vars), rng.next(2) == 0)));
+    }
+    cnf
+}
+
+/// Transforms `cnf` being satisfiable => `true`, otherwise `false`, checking that any model found
+/// satisfies every clause.
+fn solve_and_check(cnf: &Cnf) -> bool {
+    let Ok(assignment) = cnf.solve() else {
+        return false;
+    };
+    for clause in cnf.clauses() {
+        assert!(
+            clause
+                .iter()
+                .any(|lit| { assignment[cnf.name(lit.var()).unwrap()] == lit.is_positive() }),
+            "model violates {clause:?}"
+        );
+    }
+    true
+}
+
+#[test]
+fn solver_agrees_with_exhaustive_search() {
+    let mut rng = Rng(0x0123_4567_89ab_cdef);
+    for _ in 0..2_000 {
+        let vars = 1 + rng.next(10);
+        let clauses = rng.next(50);
+        let cnf = random_3sat(&mut rng, vars, clauses);
+        let satisfiable = (0_u32..1 << vars).any(|assignment| {
+            cnf.clauses().iter().all(|clause| {
+                clause
+                    .iter()
+                    .any(|lit| (assignment >> lit.var() & 1 == 1) == lit.is_positive())
+            })
+        });
+        assert_eq!(solve_and_check(&cnf), satisfiable, "{:?}", cnf.clauses());
+    }
+}
+
+#[test]
+fn solver_finds_models_of_hard_formulas() {
+    // At the satisfiability threshold (4.26 clauses per variable), enough conflicts arise for
+    // learnt clauses to be deleted
+    let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+    let results = (0..8)
+        .map(|_| solve_and_check(&random_3sat(&mut rng, 150, 639)))
+        .collect::<Vec<_>>();
+    assert!(results.contains(&true) && results.contains(&false));
+}
+
+#[test]
+fn dimacs_round_trips_tseitin_output() {
+    let names = [
+        "x",
+        "3",
+        "1",
+        "two words",
+        "line\nbreak",
+        "tab\t",
+        "back\\slash",
+        "\\&",
+        "",
+        "ü",
+    ];
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+    for _ in 0..200 {
+        let mut expr = Expr::var(names[rng.next(names.len())]);
+        for _ in 0..rng.next(8) {
+            let other = Expr::var(names[rng.next(names.len())]);
+            expr = match rng.next(4) {
+                0 => expr & other,
+                1 => expr | other,
+                2 => expr ^ !other,
+                _ => expr.iff(other),
+            };
+        }
+        let cnf = Cnf::from(&expr);
+        let mut dimacs = String::new();
+        cnf.write_dimacs(&mut dimacs).unwrap();
+        assert_eq!(Cnf::parse_dimacs(&dimacs), Ok(cnf), "{dimacs}");
+    }
+}